@@ -13,15 +13,22 @@ pub trait Algo {
     fn output_tww_str(&self) -> String;
 }
 
-/// Holds a graph and its contraction squence.
-/// In the beginning the contraction sequence is empty.
-/// Each contraction on the graph will be stored in the contraction sequence in the occuring order.
-/// The max red degree will be stored as well
-
-fn get_all_combinations(nodes: Vec<u32>) -> TupleCombinations<std::vec::IntoIter<u32>, (u32, u32)> {
+pub(crate) fn get_all_combinations(
+    nodes: Vec<u32>,
+) -> TupleCombinations<std::vec::IntoIter<u32>, (u32, u32)> {
     let result: TupleCombinations<std::vec::IntoIter<u32>, (u32, u32)> =
         nodes.into_iter().tuple_combinations::<(u32, u32)>();
     result
 }
 
+pub mod beam_search;
+pub mod branch_and_bound;
+pub mod classes;
+pub mod components;
 pub mod greedy;
+pub mod greedy_v2;
+pub mod preprocessed;
+pub mod randomized_greedy;
+pub mod sat;
+pub mod score;
+pub mod solver;
@@ -0,0 +1,256 @@
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+
+use crate::algo::{
+    get_all_combinations,
+    score::Score,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{contract_rest, Anytime, BestSoFar, CancellationToken};
+use crate::cograph::recognize_cograph;
+use crate::graph::{Graph, Trigraph};
+use crate::sequence::ContractionSequence;
+
+/// Settings for `BeamSearch`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BeamConfig {
+    /// Number of states which are kept after every step
+    pub beam_width: usize,
+    /// Number of the best pairs of every state which are contracted
+    pub candidates: usize,
+    /// The score which selects the candidates and breaks ties between states
+    pub score: Score,
+}
+
+impl Default for BeamConfig {
+    fn default() -> Self {
+        BeamConfig {
+            beam_width: 10,
+            candidates: 5,
+            score: Score::default(),
+        }
+    }
+}
+
+/// Beam search over contraction sequences.
+///
+/// Every step contracts the best `candidates` pairs of every state of the beam and keeps the
+/// best `beam_width` of the new states. States are ranked by the width of their sequence so far,
+/// then by the current max red degree of their trigraph and then by the score of their last
+/// contraction. The trigraph only depends on the partition of the original vertices, so of all
+/// states with the same partition only the best one is kept.
+/// With a beam width and a number of candidates of 1 this is `Greedy` with the same score.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::algo::{Algo, beam_search::{BeamConfig, BeamSearch}};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+/// let config = BeamConfig { beam_width: 4, candidates: 3, ..BeamConfig::default() };
+/// let mut search = BeamSearch::new_with_config(graph, config);
+/// let (_, twin_width) = search.solve();
+/// assert_eq!(twin_width, 2);
+/// ```
+pub struct BeamSearch {
+    graph: Graph,
+    config: BeamConfig,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+}
+
+/// A partial contraction sequence in the beam
+#[derive(Clone)]
+struct State {
+    trigraph: Trigraph,
+    sequence: Vec<(u32, u32)>,
+    width: usize,
+    /// The trigraph node which contains every original vertex, in sorted order of the vertices
+    owners: Vec<u32>,
+    /// Width, current max red degree and the score of the last contraction
+    rank: (usize, usize, Vec<usize>),
+}
+
+impl Algo for BeamSearch {
+    /// Creates a new `BeamSearch` instance with the default configuration
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    ///
+    /// # Returns
+    /// * New BeamSearch instance with a graph and empty contraction sequence
+    fn new_with_graph(graph: Graph) -> Self {
+        Self::new_with_config(graph, BeamConfig::default())
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Performs the search
+    ///
+    /// # Returns
+    /// * The best contraction sequence in the final beam and its width
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.solve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl Anytime for BeamSearch {
+    /// Performs the search until the token is cancelled, then the remaining nodes of the best
+    /// state are contracted into one. Only the complete sequence is published.
+    fn solve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        //Cographs are contracted along their cotree without any red edge
+        if let Some(sequence) = recognize_cograph(&self.graph) {
+            self.contraction_squence = sequence.into();
+            self.twin_width = 0;
+            best_so_far.publish(&self.contraction_squence, self.twin_width);
+            return (self.contraction_squence.clone(), self.twin_width);
+        }
+
+        let mut originals = self.graph.get_all_nodes();
+        originals.sort();
+        let mut beam = vec![State {
+            trigraph: Trigraph::from(&self.graph),
+            sequence: Vec::new(),
+            width: 0,
+            owners: originals,
+            rank: (0, 0, Vec::new()),
+        }];
+
+        while beam[0].trigraph.get_node_count() > 1 {
+            if token.is_cancelled() {
+                //The beam is sorted, so its first state is the best one
+                beam.truncate(1);
+                let best = &mut beam[0];
+                let red_degree = contract_rest(&mut best.trigraph, &mut best.sequence);
+                best.width = cmp::max(best.width, red_degree);
+                break;
+            }
+            let mut children: Vec<State> =
+                beam.iter().flat_map(|state| self.expand(state)).collect();
+            children.sort_by(|a, b| a.rank.cmp(&b.rank));
+
+            let mut seen: HashSet<Vec<u32>> = HashSet::new();
+            beam = children
+                .into_iter()
+                .filter(|state| seen.insert(partition_key(&state.owners)))
+                .take(self.config.beam_width)
+                .collect();
+        }
+
+        let best = beam.swap_remove(0);
+        self.contraction_squence = best.sequence;
+        self.twin_width = best.width;
+        best_so_far.publish(&self.contraction_squence, self.twin_width);
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+}
+
+impl BeamSearch {
+    /// Creates a new `BeamSearch` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    /// * config: The size of the beam and the number of candidates
+    ///
+    /// # Panics
+    /// If the beam width or the number of candidates is 0
+    pub fn new_with_config(graph: Graph, config: BeamConfig) -> Self {
+        if config.beam_width == 0 || config.candidates == 0 {
+            panic!("Beam width and candidates have to be positive");
+        }
+        BeamSearch {
+            graph,
+            config,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+        }
+    }
+
+    /// Contracts the best candidate pairs of a state, ties are broken by the sorted order
+    fn expand(&self, state: &State) -> Vec<State> {
+        let mut all_nodes = state.trigraph.get_all_nodes();
+        all_nodes.sort();
+
+        let mut pairs: Vec<(Vec<usize>, (u32, u32))> = get_all_combinations(all_nodes)
+            .map(|(node_a, node_b)| {
+                let value = self.config.score.evaluate(&state.trigraph, node_a, node_b);
+                (value, (node_a, node_b))
+            })
+            .collect();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        pairs
+            .into_iter()
+            .take(self.config.candidates)
+            .map(|(value, (node_a, node_b))| {
+                let mut child = state.clone();
+                let red_degree = child.trigraph.contract(node_a, node_b);
+                child.sequence.push((node_a, node_b));
+                child.width = cmp::max(child.width, red_degree);
+                child
+                    .owners
+                    .iter_mut()
+                    .filter(|owner| **owner == node_b)
+                    .for_each(|owner| *owner = node_a);
+                child.rank = (child.width, child.trigraph.get_max_red_degree(), value);
+                child
+            })
+            .collect()
+    }
+}
+
+impl Solver for BeamConfig {
+    fn name(&self) -> String {
+        "beam".to_string()
+    }
+
+    /// Runs until the search is done or the token is cancelled
+    ///
+    /// # Panics
+    /// If the beam width or the number of candidates is 0
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let mut search = BeamSearch::new_with_config(graph.clone(), self.clone());
+        let (sequence, _) = search.solve_anytime(best_so_far, token);
+        SolveResult::new(&graph, &sequence, None, start, None)
+    }
+}
+
+/// Names every part of a partition by the position of its first original vertex,
+/// so equal partitions get equal keys regardless of the names of the trigraph nodes
+fn partition_key(owners: &[u32]) -> Vec<u32> {
+    let mut names: HashMap<u32, u32> = HashMap::new();
+    owners
+        .iter()
+        .enumerate()
+        .map(|(index, owner)| *names.entry(*owner).or_insert(index as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
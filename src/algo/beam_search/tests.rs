@@ -0,0 +1,130 @@
+use crate::{
+    algo::{greedy::Greedy, score::Score, Algo},
+    anytime::{Anytime, BestSoFar, CancellationToken},
+    graph::Graph,
+    verify::verify,
+};
+
+use super::{partition_key, BeamConfig, BeamSearch};
+
+/// The 5x5 grid, where `Greedy` finds width 4
+fn grid() -> Graph {
+    let mut edges = Vec::new();
+    for node in 1..=25 {
+        if node % 5 != 0 {
+            edges.push((node, node + 1));
+        }
+        if node <= 20 {
+            edges.push((node, node + 5));
+        }
+    }
+    Graph::from_edges(edges)
+}
+
+fn petersen() -> Graph {
+    Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 1),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 10),
+        (6, 8),
+        (8, 10),
+        (10, 7),
+        (7, 9),
+        (9, 6),
+    ])
+}
+
+#[test]
+fn test_partition_key() {
+    //{1, 3}, {2} and {4, 5} named by different trigraph nodes
+    assert_eq!(partition_key(&[1, 2, 1, 4, 4]), vec![0, 1, 0, 3, 3]);
+    assert_eq!(partition_key(&[3, 2, 3, 5, 5]), vec![0, 1, 0, 3, 3]);
+    assert_ne!(partition_key(&[1, 1, 3, 4, 4]), vec![0, 1, 0, 3, 3]);
+}
+
+#[test]
+fn test_solve_single_beam_is_greedy() {
+    let config = BeamConfig {
+        beam_width: 1,
+        candidates: 1,
+        score: Score::default(),
+    };
+
+    for graph in [grid(), petersen()] {
+        let mut search = BeamSearch::new_with_config(graph.clone(), config.clone());
+
+        assert_eq!(search.solve(), Greedy::new_with_graph(graph).solve());
+    }
+}
+
+#[test]
+fn test_solve_wider_beam() {
+    let graph = grid();
+    let config = BeamConfig {
+        beam_width: 8,
+        candidates: 4,
+        score: Score::Lexicographic(vec![Score::MaxRedDegree, Score::SymmetricDifference]),
+    };
+    let mut search = BeamSearch::new_with_config(graph.clone(), config);
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(sequence.len(), 24);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert!(twin_width <= Greedy::new_with_graph(graph).solve().1);
+}
+
+#[test]
+fn test_solve_petersen_graph() {
+    let graph = petersen();
+    let mut search = BeamSearch::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(twin_width, 4);
+}
+
+#[test]
+fn test_solve_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1), (4, 5), (2, 5)]);
+    let mut search = BeamSearch::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(twin_width, 0);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+#[should_panic(expected = "Beam width and candidates have to be positive")]
+fn test_new_empty_beam() {
+    let config = BeamConfig {
+        beam_width: 0,
+        ..BeamConfig::default()
+    };
+    BeamSearch::new_with_config(grid(), config);
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let token = CancellationToken::new();
+    token.cancel();
+    let best_so_far = BestSoFar::new();
+
+    let (sequence, twin_width) =
+        BeamSearch::new_with_graph(graph.clone()).solve_anytime(&best_so_far, &token);
+
+    //The remaining nodes are contracted into the smallest one
+    assert_eq!(sequence, vec![(1, 2), (1, 3), (1, 4), (1, 5), (1, 6)]);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(best_so_far.get(), Some((sequence.into(), twin_width)));
+}
@@ -0,0 +1,331 @@
+use std::{
+    cmp,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::algo::{
+    get_all_combinations,
+    greedy::Greedy,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{Anytime, BestSoFar, CancellationToken};
+use crate::bounds::first_contraction_bound;
+use crate::graph::{Graph, Trigraph};
+use crate::sequence::ContractionSequence;
+
+/// Limits for the search of `BranchAndBound`. `None` means unlimited.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Budget {
+    /// Maximum number of search nodes to explore
+    pub nodes: Option<u64>,
+    /// Maximum time to search
+    pub time: Option<Duration>,
+}
+
+/// Exact algorithm which searches all contraction sequences.
+///
+/// The search starts with the sequence of `Greedy` as upper bound and only follows contractions
+/// which keep the max red degree below the best width found so far.
+/// Since the trigraph only depends on the partition of the original vertices, partitions which
+/// were already reached with a smaller or equal width are not searched again.
+/// If the budget is exhausted or the token of `solve_anytime` is cancelled, the best sequence
+/// found so far is returned and `is_optimal` is false.
+pub struct BranchAndBound {
+    original: Graph,
+    graph: Trigraph,
+    budget: Budget,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    lower_bound: usize,
+    optimal: bool,
+    explored_nodes: u64,
+    /// Smallest width with which a partition was reached
+    seen: HashMap<Vec<u32>, usize>,
+    /// Smallest original vertex of the part of every original vertex
+    partition: HashMap<u32, u32>,
+    path: Vec<(u32, u32)>,
+    start: Instant,
+    best_so_far: BestSoFar,
+    token: CancellationToken,
+}
+
+impl Algo for BranchAndBound {
+    /// Creates a new `BranchAndBound` instance without a budget
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    ///
+    /// # Returns
+    /// * New BranchAndBound instance with a graph and empty contraction sequence
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, branch_and_bound::BranchAndBound};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let search = BranchAndBound::new_with_graph(graph);
+    /// ```
+    fn new_with_graph(graph: Graph) -> Self {
+        Self::new_with_budget(graph, Budget::default())
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Performs the search
+    ///
+    /// # Returns
+    /// * The best contraction sequence found and its width
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, branch_and_bound::BranchAndBound};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    /// let mut search = BranchAndBound::new_with_graph(graph);
+    /// let (_, twin_width) = search.solve();
+    /// assert_eq!(twin_width, 2);
+    /// assert!(search.is_optimal());
+    /// ```
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.solve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl Anytime for BranchAndBound {
+    /// Searches like `Algo::solve`, but also stops once the token is cancelled. The sequence of
+    /// `Greedy` and every improvement of it are published.
+    fn solve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        self.start = Instant::now();
+        self.best_so_far = best_so_far.clone();
+        self.token = token.clone();
+
+        let mut greedy = Greedy::new_with_graph(self.original.clone());
+        let (sequence, width) = greedy.solve_anytime(best_so_far, token);
+        self.contraction_squence = sequence;
+        self.twin_width = width;
+
+        //Greedy solves cographs along their cotree, nothing can beat width 0
+        if self.twin_width == 0 {
+            self.lower_bound = 0;
+            self.optimal = true;
+            return (self.contraction_squence.clone(), self.twin_width);
+        }
+
+        self.lower_bound = first_contraction_bound(&self.original);
+
+        self.optimal = self.search(0);
+
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+}
+
+impl BranchAndBound {
+    /// Creates a new `BranchAndBound` instance with a budget
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    /// * budget: The limits of the search
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::branch_and_bound::{BranchAndBound, Budget};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let budget = Budget { nodes: Some(10_000), time: Some(Duration::from_secs(1)) };
+    /// let search = BranchAndBound::new_with_budget(graph, budget);
+    /// ```
+    pub fn new_with_budget(graph: Graph, budget: Budget) -> Self {
+        let partition = graph
+            .get_all_nodes()
+            .into_iter()
+            .map(|node| (node, node))
+            .collect();
+        BranchAndBound {
+            graph: Trigraph::from(&graph),
+            original: graph,
+            budget,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            lower_bound: 0,
+            optimal: false,
+            explored_nodes: 0,
+            seen: HashMap::new(),
+            partition,
+            path: Vec::new(),
+            start: Instant::now(),
+            best_so_far: BestSoFar::new(),
+            token: CancellationToken::new(),
+        }
+    }
+
+    /// Whether the width of the last `solve` is proven to be optimal
+    pub fn is_optimal(&self) -> bool {
+        self.optimal
+    }
+
+    /// Gets the lower bound of the last `solve`, which is its width if it is optimal
+    pub fn get_lower_bound(&self) -> usize {
+        if self.optimal {
+            self.twin_width
+        } else {
+            self.lower_bound
+        }
+    }
+
+    /// Gets the number of search nodes explored by the last `solve`
+    pub fn get_explored_nodes(&self) -> u64 {
+        self.explored_nodes
+    }
+
+    /// Searches all completions of the current path.
+    ///
+    /// # Parameters
+    /// * width: The max red degree of the current path
+    ///
+    /// # Returns
+    /// * false if the budget was exhausted or the token was cancelled, true if the subtree was
+    ///   searched completely
+    fn search(&mut self, width: usize) -> bool {
+        if self.twin_width <= cmp::max(width, self.lower_bound) {
+            return true;
+        }
+        if self.budget_exhausted() {
+            return false;
+        }
+        self.explored_nodes += 1;
+
+        //With k nodes no red degree can exceed k - 1, so any completion keeps the width
+        let node_count = self.graph.get_node_count();
+        if node_count <= width + 1 {
+            let mut nodes = self.graph.get_all_nodes();
+            nodes.sort();
+            self.twin_width = width;
+            self.contraction_squence = self.path.clone();
+            self.contraction_squence
+                .extend(nodes[1..].iter().map(|node| (nodes[0], *node)));
+            self.best_so_far
+                .publish(&self.contraction_squence, self.twin_width);
+            return true;
+        }
+
+        let mut nodes = self.graph.get_all_nodes();
+        nodes.sort();
+        let mut candidates: Vec<(usize, u32, u32)> = get_all_combinations(nodes)
+            .map(|(node_a, node_b)| {
+                let red_degree = self.graph.get_max_red_degree_after(node_a, node_b);
+                (cmp::max(width, red_degree), node_a, node_b)
+            })
+            .filter(|(new_width, _, _)| *new_width < self.twin_width)
+            .collect();
+        candidates.sort();
+
+        for (new_width, node_a, node_b) in candidates {
+            if new_width >= self.twin_width {
+                break;
+            }
+
+            let record = self.graph.contract_with_undo(node_a, node_b);
+            let moved = self.merge_parts(node_a, node_b);
+            self.path.push((node_a, node_b));
+
+            let key = self.partition_key();
+            let complete = match self.seen.get(&key) {
+                Some(seen_width) if *seen_width <= new_width => true,
+                _ => {
+                    self.seen.insert(key, new_width);
+                    self.search(new_width)
+                }
+            };
+
+            self.path.pop();
+            moved.into_iter().for_each(|(node, part)| {
+                self.partition.insert(node, part);
+            });
+            self.graph.undo(record);
+
+            if !complete {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Merges the parts of two nodes and returns the previous part of every changed original vertex
+    fn merge_parts(&mut self, node_a: u32, node_b: u32) -> Vec<(u32, u32)> {
+        let part_a = self.partition[&node_a];
+        let part_b = self.partition[&node_b];
+        let merged = cmp::min(part_a, part_b);
+
+        let mut moved = Vec::new();
+        for (node, part) in self.partition.iter_mut() {
+            if (*part == part_a || *part == part_b) && *part != merged {
+                moved.push((*node, *part));
+                *part = merged;
+            }
+        }
+        moved
+    }
+
+    fn partition_key(&self) -> Vec<u32> {
+        let mut key: Vec<(u32, u32)> = self.partition.iter().map(|(n, p)| (*n, *p)).collect();
+        key.sort_unstable();
+        key.into_iter().map(|(_, part)| part).collect()
+    }
+
+    fn budget_exhausted(&self) -> bool {
+        self.token.is_cancelled()
+            || self
+                .budget
+                .nodes
+                .is_some_and(|nodes| self.explored_nodes >= nodes)
+            || self
+                .budget
+                .time
+                .is_some_and(|time| self.start.elapsed() >= time)
+    }
+}
+
+/// The budget is the configuration of `BranchAndBound`
+impl Solver for Budget {
+    fn name(&self) -> String {
+        "branch-and-bound".to_string()
+    }
+
+    /// Searches until the search is complete, the budget is exhausted or the token is cancelled
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let mut search = BranchAndBound::new_with_budget(graph.clone(), *self);
+        let (sequence, _) = search.solve_anytime(best_so_far, token);
+        let lower_bound = Some(search.get_lower_bound());
+        let explored_nodes = Some(search.get_explored_nodes());
+        SolveResult::new(&graph, &sequence, lower_bound, start, explored_nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    algo::Algo,
+    anytime::{Anytime, BestSoFar, CancellationToken},
+    generate::Family,
+    graph::Graph,
+    verify::verify,
+};
+
+use super::{BranchAndBound, Budget};
+
+#[test]
+fn test_solve_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+    let mut search = BranchAndBound::new_with_graph(graph);
+
+    assert_eq!(search.solve(), (Vec::new(), 0));
+    assert!(search.is_optimal());
+}
+
+#[test]
+fn test_solve_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    let mut search = BranchAndBound::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(twin_width, 0);
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+fn test_solve_path() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+    let mut search = BranchAndBound::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(twin_width, 1);
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(1));
+}
+
+#[test]
+fn test_solve_cycle() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 1)]);
+    let mut search = BranchAndBound::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(twin_width, 2);
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(2));
+}
+
+#[test]
+fn test_solve_petersen_graph() {
+    let graph = Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 1),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 10),
+        (6, 8),
+        (8, 10),
+        (10, 7),
+        (7, 9),
+        (9, 6),
+    ]);
+    let mut search = BranchAndBound::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(twin_width, 4);
+}
+
+#[test]
+fn test_solve_exhausted_budget() {
+    let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("pacechallenge/tiny-set/tiny005.gr");
+    let graph = Graph::from_gr(&std::fs::read_to_string(path).unwrap());
+    let budget = Budget {
+        nodes: Some(1),
+        time: None,
+    };
+    let mut search = BranchAndBound::new_with_budget(graph.clone(), budget);
+
+    let (sequence, twin_width) = search.solve();
+
+    //The greedy sequence is still a valid result
+    assert!(!search.is_optimal());
+    assert_eq!(search.get_explored_nodes(), 1);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = Family::Random(30, 0.3).generate(1);
+    let start = Instant::now();
+    let token = CancellationToken::new().with_deadline(start + Duration::from_millis(100));
+    let best_so_far = BestSoFar::new();
+    let mut search = BranchAndBound::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve_anytime(&best_so_far, &token);
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(!search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(best_so_far.get_twin_width(), Some(twin_width));
+}
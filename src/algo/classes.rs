@@ -0,0 +1,252 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    marker::PhantomData,
+};
+
+use crate::algo::Algo;
+use crate::graph::{Graph, GraphClass};
+use crate::sequence::ContractionSequence;
+
+/// Wrapper which builds optimal contraction sequences for graph classes with known twin-width
+/// and runs another algorithm on all other graphs.
+///
+/// * Complete bipartite graphs have twin-width 0.
+/// * Paths have twin-width 1 from four nodes on.
+/// * Cycles have twin-width 2 from five nodes on.
+/// * Trees have twin-width at most 2. Caterpillars, the trees whose inner nodes form a path,
+///   have twin-width 1, all other trees contain the subdivided claw and have twin-width 2.
+/// * Grids with two rows have twin-width 2 from four columns on. Larger grids have no sequence
+///   here which is known to be optimal, so they are solved by the other algorithm like graphs
+///   in no class.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::algo::{Algo, greedy::Greedy, classes::Classes};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)]);
+/// let mut algo: Classes<Greedy> = Classes::new_with_graph(graph);
+/// let (_, twin_width) = algo.solve();
+/// assert_eq!(twin_width, 2);
+/// assert!(algo.is_optimal());
+/// ```
+pub struct Classes<A: Algo> {
+    graph: Graph,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    optimal: bool,
+    phantom: PhantomData<A>,
+}
+
+impl<A: Algo> Algo for Classes<A> {
+    /// Creates a new `Classes` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the algorithm should be performed.
+    ///
+    /// # Returns
+    /// * New Classes instance with a graph and empty contraction sequence
+    fn new_with_graph(graph: Graph) -> Self {
+        Classes {
+            graph,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            optimal: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Builds the sequence of the class of the graph or runs the inner algorithm
+    ///
+    /// # Returns
+    /// * The contraction sequence and its width
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        let optimal_sequence = match self.graph.get_class() {
+            Some(GraphClass::CompleteBipartite(side_a, side_b)) => {
+                Some(complete_bipartite_sequence(&side_a, &side_b))
+            }
+            Some(GraphClass::Path(order)) => Some(path_sequence(&order)),
+            Some(GraphClass::Cycle(order)) => Some(cycle_sequence(&order)),
+            Some(GraphClass::Tree) => Some(tree_sequence(&self.graph)),
+            Some(GraphClass::Grid(rows)) => grid_sequence(&rows),
+            None => None,
+        };
+        self.optimal = optimal_sequence.is_some();
+        (self.contraction_squence, self.twin_width) = match optimal_sequence {
+            Some(solution) => solution,
+            None => A::new_with_graph(self.graph.clone()).solve(),
+        };
+
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl<A: Algo> Classes<A> {
+    /// Whether the width of the last `solve` is proven to be optimal, which is the case if the
+    /// graph is in a class and the sequence of the class is optimal
+    pub fn is_optimal(&self) -> bool {
+        self.optimal
+    }
+}
+
+/// Contracts both sides into one node each, all of them are twins
+fn complete_bipartite_sequence(side_a: &[u32], side_b: &[u32]) -> (Vec<(u32, u32)>, usize) {
+    let mut sequence: Vec<(u32, u32)> = side_a[1..].iter().map(|node| (side_a[0], *node)).collect();
+    sequence.extend(side_b[1..].iter().map(|node| (side_b[0], *node)));
+    sequence.push((side_a[0], side_b[0]));
+    (sequence, 0)
+}
+
+/// Contracts the path from one end, so only the edge to the next node is red
+fn path_sequence(order: &[u32]) -> (Vec<(u32, u32)>, usize) {
+    if order.len() == 3 {
+        return (vec![(order[0], order[2]), (order[0], order[1])], 0);
+    }
+    let sequence = order.windows(2).map(|pair| (pair[1], pair[0])).collect();
+    (sequence, if order.len() >= 4 { 1 } else { 0 })
+}
+
+/// Contracts the cycle along its order, so the contracted part has a red edge to both sides
+fn cycle_sequence(order: &[u32]) -> (Vec<(u32, u32)>, usize) {
+    if order.len() == 4 {
+        let sequence = vec![
+            (order[0], order[2]),
+            (order[1], order[3]),
+            (order[0], order[1]),
+        ];
+        return (sequence, 0);
+    }
+    let sequence = order.windows(2).map(|pair| (pair[1], pair[0])).collect();
+    (sequence, if order.len() >= 5 { 2 } else { 0 })
+}
+
+/// Contracts the tree from the deepest nodes up to the root.
+///
+/// Every node is contracted with its only remaining child, which makes the edge to its parent
+/// red, and then with another remaining child of its parent, if there is one. So every node has
+/// at most two red children at once. The root is a neighbour of an end of a longest path. In a
+/// caterpillar every node has at most one child which is not a leaf then, so only one child can
+/// be red.
+fn tree_sequence(graph: &Graph) -> (Vec<(u32, u32)>, usize) {
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort();
+    let farthest = |distances: &HashMap<u32, usize>| {
+        nodes
+            .iter()
+            .copied()
+            .max_by_key(|node| (distances[node], std::cmp::Reverse(*node)))
+            .unwrap()
+    };
+    let end = farthest(&graph.get_distances(nodes[0]));
+    let diameter = graph.get_distances(end)[&farthest(&graph.get_distances(end))];
+    let Some(root) = graph.get_neighbours(end).iter().next().copied() else {
+        return (Vec::new(), 0);
+    };
+
+    let mut order = vec![root];
+    let mut parents: HashMap<u32, u32> = HashMap::new();
+    let mut queue = VecDeque::from([root]);
+    while let Some(node) = queue.pop_front() {
+        let mut children: Vec<u32> = graph
+            .get_neighbours(node)
+            .iter()
+            .filter(|child| **child != root && !parents.contains_key(child))
+            .copied()
+            .collect();
+        children.sort();
+        for child in children {
+            parents.insert(child, node);
+            order.push(child);
+            queue.push_back(child);
+        }
+    }
+
+    let mut sequence = Vec::new();
+    let mut remaining: HashMap<u32, u32> = HashMap::new();
+    for node in order[1..].iter().rev() {
+        if let Some(child) = remaining.remove(node) {
+            sequence.push((*node, child));
+        }
+        match remaining.entry(parents[node]) {
+            Entry::Occupied(sibling) => sequence.push((*sibling.get(), *node)),
+            Entry::Vacant(entry) => {
+                entry.insert(*node);
+            }
+        }
+    }
+    if let Some(child) = remaining.remove(&root) {
+        sequence.push((root, child));
+    }
+
+    let is_leaf = |node: &u32| graph.get_neighbours(*node).len() == 1;
+    let is_caterpillar = nodes.iter().all(|node| {
+        is_leaf(node)
+            || graph
+                .get_neighbours(*node)
+                .iter()
+                .filter(|n| !is_leaf(n))
+                .count()
+                <= 2
+    });
+    let width = match diameter {
+        0..=2 => 0,
+        _ if is_caterpillar => 1,
+        _ => 2,
+    };
+    (sequence, width)
+}
+
+/// Contracts grids with two rows column by column into one part, which has red edges to the
+/// next column only. Larger grids have no optimal sequence here, so they get None.
+fn grid_sequence(rows: &[Vec<u32>]) -> Option<(Vec<(u32, u32)>, usize)> {
+    //Two rows and many columns, or the other way around
+    let rows: Vec<Vec<u32>> = if rows.len() > rows[0].len() {
+        (0..rows[0].len())
+            .map(|column| rows.iter().map(|row| row[column]).collect())
+            .collect()
+    } else {
+        rows.to_vec()
+    };
+    let first = rows[0][0];
+
+    let solution = match (rows.len(), rows[0].len()) {
+        (1, _) => path_sequence(&rows[0]),
+        (2, 2) => cycle_sequence(&[rows[0][0], rows[0][1], rows[1][1], rows[1][0]]),
+        (2, 3) => {
+            //Contracting the diagonals of the first square leaves a path
+            let sequence = vec![
+                (first, rows[1][1]),
+                (rows[0][1], rows[1][2]),
+                (first, rows[1][0]),
+                (first, rows[0][1]),
+                (first, rows[0][2]),
+            ];
+            (sequence, 1)
+        }
+        (2, columns) => {
+            let sequence = (0..columns)
+                .flat_map(|column| [(first, rows[0][column]), (first, rows[1][column])])
+                .skip(1)
+                .collect();
+            (sequence, 2)
+        }
+        _ => return None,
+    };
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,133 @@
+use crate::{
+    algo::{branch_and_bound::BranchAndBound, greedy::Greedy, Algo},
+    graph::Graph,
+    random::Rng,
+    verify::verify,
+};
+
+use super::Classes;
+
+/// Solves the graph with `Classes` and checks the width against the verifier and, for small
+/// graphs, against the exact search
+fn check(graph: &Graph) -> usize {
+    let mut algo: Classes<Greedy> = Classes::new_with_graph(graph.clone());
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(verify(graph, &sequence), Ok(twin_width));
+    if algo.is_optimal() && graph.get_all_nodes().len() <= 10 {
+        let mut search = BranchAndBound::new_with_graph(graph.clone());
+        assert_eq!(search.solve().1, twin_width);
+        assert!(search.is_optimal());
+    }
+    twin_width
+}
+
+fn grid(rows: u32, columns: u32) -> Graph {
+    let mut edges = Vec::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let node = row * columns + column + 1;
+            if column + 1 < columns {
+                edges.push((node, node + 1));
+            }
+            if row + 1 < rows {
+                edges.push((node, node + columns));
+            }
+        }
+    }
+    Graph::from_edges(edges)
+}
+
+#[test]
+fn test_solve_complete_bipartite() {
+    let graph = Graph::from_edges(vec![(1, 4), (1, 5), (2, 4), (2, 5), (3, 4), (3, 5)]);
+
+    assert_eq!(check(&graph), 0);
+}
+
+#[test]
+fn test_solve_paths() {
+    for len in 1..=10 {
+        let mut graph = Graph::from_edges((1..len).map(|node| (node, node + 1)).collect());
+        graph.add_node(1);
+
+        assert_eq!(check(&graph), if len >= 4 { 1 } else { 0 });
+    }
+}
+
+#[test]
+fn test_solve_cycles() {
+    for len in 3..=10 {
+        let graph = Graph::from_edges((0..len).map(|i| (i + 1, (i + 1) % len + 1)).collect());
+
+        assert_eq!(check(&graph), if len >= 5 { 2 } else { 0 });
+    }
+}
+
+#[test]
+fn test_solve_trees() {
+    //A caterpillar and the subdivided claw
+    let caterpillar = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (2, 5), (3, 6), (3, 7)]);
+    let claw = Graph::from_edges(vec![(1, 2), (2, 3), (1, 4), (4, 5), (1, 6), (6, 7)]);
+
+    assert_eq!(check(&caterpillar), 1);
+    assert_eq!(check(&claw), 2);
+}
+
+#[test]
+fn test_solve_random_trees() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for nodes in [5, 8, 10, 40, 200] {
+        for _ in 0..5 {
+            let edges = (2..=nodes)
+                .map(|node| (rng.below(node as usize - 1) as u32 + 1, node))
+                .collect();
+            let graph = Graph::from_edges(edges);
+
+            assert!(check(&graph) <= 2);
+        }
+    }
+}
+
+#[test]
+fn test_solve_grids() {
+    for (rows, columns, width) in [(2, 2, 0), (2, 3, 1), (3, 2, 1), (2, 5, 2), (6, 2, 2)] {
+        assert_eq!(check(&grid(rows, columns)), width);
+    }
+    //Larger grids are solved by the inner algorithm
+    for (rows, columns) in [(3, 3), (3, 4), (3, 6), (5, 5), (7, 4)] {
+        let graph = grid(rows, columns);
+        let mut algo: Classes<Greedy> = Classes::new_with_graph(graph.clone());
+        let (sequence, twin_width) = algo.solve();
+
+        assert!(!algo.is_optimal());
+        assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+        assert_eq!(
+            (sequence, twin_width),
+            Greedy::new_with_graph(graph).solve()
+        );
+    }
+}
+
+#[test]
+fn test_solve_grid_3x3() {
+    let mut algo: Classes<Greedy> = Classes::new_with_graph(grid(3, 3));
+
+    assert_eq!(algo.solve().1, 2);
+}
+
+#[test]
+fn test_solve_fallback() {
+    //Two triangles joined by an edge are in no class
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 6), (6, 4)]);
+    let mut algo: Classes<Greedy> = Classes::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert!(!algo.is_optimal());
+    assert_eq!(
+        (sequence, twin_width),
+        Greedy::new_with_graph(graph).solve()
+    );
+}
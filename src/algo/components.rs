@@ -0,0 +1,141 @@
+use std::{cmp, collections::HashSet, marker::PhantomData};
+
+use crate::algo::Algo;
+use crate::cograph::recognize_cograph;
+use crate::graph::Graph;
+use crate::sequence::ContractionSequence;
+
+/// Wrapper which solves components and co-components separately with another algorithm.
+///
+/// If the graph is disconnected, no contraction inside a component changes another component.
+/// If the complement is disconnected, the graph is the join of its co-components and every
+/// vertex of one co-component is adjacent to all vertices of the others, so contractions inside
+/// a co-component do not create red edges to the others either.
+/// In both cases every part is contracted to a single vertex on its own, which is done
+/// recursively, and the remaining vertices are pairwise twins and contracted at width 0.
+/// So the width is the max of the widths of the parts. Parts which are neither disconnected
+/// nor co-disconnected are solved with the inner algorithm.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::algo::{Algo, greedy::Greedy, components::Components};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (5, 6), (6, 7), (7, 8), (8, 5)]);
+/// let mut algo: Components<Greedy> = Components::new_with_graph(graph);
+/// let (_, twin_width) = algo.solve();
+/// assert_eq!(twin_width, 1);
+/// ```
+pub struct Components<A: Algo> {
+    graph: Graph,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    phantom: PhantomData<A>,
+}
+
+/// A pending step of the decomposition
+enum Step {
+    /// Split these nodes further or solve them
+    Visit(Vec<u32>),
+    /// Contract the last `count` representatives into one
+    Join(usize),
+}
+
+impl<A: Algo> Algo for Components<A> {
+    /// Creates a new `Components` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the algorithm should be performed.
+    ///
+    /// # Returns
+    /// * New Components instance with a graph and empty contraction sequence
+    fn new_with_graph(graph: Graph) -> Self {
+        Components {
+            graph,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Decomposes the graph and solves the parts
+    ///
+    /// # Returns
+    /// * The sequences of the parts, each followed by the contractions joining the parts
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.contraction_squence = Vec::new();
+        self.twin_width = 0;
+
+        //Cographs decompose down to single vertices, the cotree does this in linear time
+        if let Some(sequence) = recognize_cograph(&self.graph) {
+            self.contraction_squence = sequence.into();
+            return (self.contraction_squence.clone(), self.twin_width);
+        }
+
+        //The decomposition tree can be as deep as the graph has nodes, so it is walked with a stack
+        let mut steps = vec![Step::Visit(self.graph.get_all_nodes())];
+        let mut representatives: Vec<u32> = Vec::new();
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Visit(nodes) => {
+                    let subgraph = self.graph.induced_subgraph(&nodes);
+                    let mut parts = subgraph.get_components();
+                    if parts.len() == 1 {
+                        parts = subgraph.get_co_components();
+                    }
+
+                    if parts.len() > 1 {
+                        steps.push(Step::Join(parts.len()));
+                        steps.extend(parts.into_iter().rev().map(Step::Visit));
+                    } else if let Some(representative) = self.solve_part(subgraph) {
+                        representatives.push(representative);
+                    }
+                }
+                Step::Join(count) => {
+                    let joined = representatives.split_off(representatives.len() - count);
+                    self.contraction_squence
+                        .extend(joined[1..].iter().map(|node| (joined[0], *node)));
+                    representatives.push(joined[0]);
+                }
+            }
+        }
+
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl<A: Algo> Components<A> {
+    /// Solves a part with the inner algorithm
+    ///
+    /// # Returns
+    /// * The node which is left after the contractions, None for an empty part
+    fn solve_part(&mut self, part: Graph) -> Option<u32> {
+        let mut nodes = part.get_all_nodes();
+        if nodes.len() <= 1 {
+            return nodes.pop();
+        }
+
+        let (sequence, width) = A::new_with_graph(part).solve();
+        let removed: HashSet<u32> = sequence.iter().map(|(_, node_b)| *node_b).collect();
+        self.contraction_squence.extend(sequence);
+        self.twin_width = cmp::max(self.twin_width, width);
+
+        nodes.into_iter().find(|node| !removed.contains(node))
+    }
+}
+
+#[cfg(test)]
+mod tests;
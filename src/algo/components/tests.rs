@@ -0,0 +1,89 @@
+use crate::{
+    algo::{greedy::Greedy, Algo},
+    graph::Graph,
+    random::Rng,
+    verify::verify,
+};
+
+use super::Components;
+
+/// Cycle on the nodes first..first + len
+fn cycle(first: u32, len: u32) -> Vec<(u32, u32)> {
+    (0..len)
+        .map(|i| (first + i, first + (i + 1) % len))
+        .collect()
+}
+
+#[test]
+fn test_solve_disjoint_union() {
+    let mut edges = cycle(1, 5);
+    edges.extend([(6, 7), (7, 8), (8, 9)]);
+    let graph = Graph::from_edges(edges);
+    let mut algo: Components<Greedy> = Components::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 2);
+    assert_eq!(verify(&graph, &sequence), Ok(2));
+}
+
+#[test]
+fn test_solve_join() {
+    let mut graph = Graph::from_edges([cycle(1, 5), cycle(6, 5)].concat());
+    for node_a in 1..=5 {
+        for node_b in 6..=10 {
+            graph.add_edge(node_a, node_b);
+        }
+    }
+    let mut algo: Components<Greedy> = Components::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 2);
+    assert_eq!(verify(&graph, &sequence), Ok(2));
+}
+
+#[test]
+fn test_solve_threshold_graph() {
+    //Every node is either isolated or dominating when it is added, so the graph is a cograph
+    //whose decomposition is as deep as the graph has nodes
+    let mut graph = Graph::new();
+    for node in 1..=60 {
+        graph.add_node(node);
+        if node % 2 == 0 {
+            for other in 1..node {
+                graph.add_edge(node, other);
+            }
+        }
+    }
+    let mut algo: Components<Greedy> = Components::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 0);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+fn test_solve_random_graphs() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for density in [2, 3, 5, 8] {
+        for _ in 0..5 {
+            let mut graph = Graph::new();
+            (1..=15).for_each(|node| graph.add_node(node));
+            for node_a in 1..=15 {
+                for node_b in node_a + 1..=15 {
+                    if rng.below(density) == 0 {
+                        graph.add_edge(node_a, node_b);
+                    }
+                }
+            }
+            let mut algo: Components<Greedy> = Components::new_with_graph(graph.clone());
+
+            let (sequence, twin_width) = algo.solve();
+
+            assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+        }
+    }
+}
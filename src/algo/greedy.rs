@@ -1,17 +1,37 @@
-use std::{cmp, collections::HashSet, fmt::Write};
-
-use crate::algo::{get_all_combinations, Algo};
-use crate::graph::Graph;
+use std::{cmp, time::Instant};
+
+use crate::algo::{
+    get_all_combinations,
+    score::Score,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{contract_rest, Anytime, BestSoFar, CancellationToken};
+use crate::cograph::recognize_cograph;
+use crate::graph::{Graph, Trigraph};
+use crate::sequence::ContractionSequence;
+
+/// Settings for `Greedy`
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct GreedyConfig {
+    /// The score of a contraction, smaller is better
+    pub score: Score,
+}
 
 /// Holds a graph and its contraction squence.
 /// In the beginning the contraction sequence is empty.
 /// Each contraction on the graph will be stored in the contraction sequence in the occuring order.
 /// The max red degree will be stored as well
+///
+/// In every step the pair with the smallest score is contracted, ties are broken by the sorted
+/// order of the nodes. By default the score is the max red degree after the contraction.
 pub struct Greedy {
-    graph: Graph,
-    global_red_edges: Graph,
+    graph: Trigraph,
+    score: Score,
     contraction_squence: Vec<(u32, u32)>,
     twin_width: usize,
+    /// The zero-width sequence, if the graph is a cograph
+    cograph: Option<ContractionSequence>,
 }
 
 impl Algo for Greedy {
@@ -32,8 +52,9 @@ impl Algo for Greedy {
     /// ```
     fn new_with_graph(graph: Graph) -> Self {
         Greedy {
-            graph,
-            global_red_edges: Graph::new(),
+            graph: Trigraph::from(&graph),
+            score: Score::default(),
+            cograph: recognize_cograph(&graph),
             contraction_squence: Vec::new(),
             twin_width: 0,
         }
@@ -58,71 +79,69 @@ impl Algo for Greedy {
     /// let contraction_sequence = greedy.solve();
     /// ```
     fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
-        while self.graph.get_all_nodes().len() > 1 {
-            //TODO: Make this Option or smart in another way.
-            let mut local_red_degree: usize = 100000;
-            let mut contraction: (u32, u32) = (100000, 100000);
-            let mut red_edges: Graph = Graph::new();
+        self.solve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl Anytime for Greedy {
+    /// Performs the greedy algorithm until the token is cancelled, then the remaining nodes are
+    /// contracted into one. Only the complete sequence is published.
+    fn solve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        //Cographs are contracted along their cotree without any red edge
+        if let Some(sequence) = self.cograph.take() {
+            for &(node_a, node_b) in sequence.iter() {
+                self.graph.contract(node_a, node_b);
+                self.contraction_squence.push((node_a, node_b));
+            }
+        }
+
+        while self.graph.get_node_count() > 1 {
+            if token.is_cancelled() {
+                let red_degree = contract_rest(&mut self.graph, &mut self.contraction_squence);
+                self.twin_width = cmp::max(self.twin_width, red_degree);
+                break;
+            }
+            let mut best: Option<(Vec<usize>, (u32, u32))> = None;
 
             let mut all_nodes = self.graph.get_all_nodes();
             //The use of Hashmap/Hashset implementation has no order, which indeed has effects on the result.
             all_nodes.sort();
 
             for (node_a, node_b) in get_all_combinations(all_nodes) {
-                //prepare Graph for local red edges
-                let mut local_red_edges = self.global_red_edges.clone();
-                local_red_edges.add_node(node_a);
-                local_red_edges.add_node(node_b);
-
-                //get the neighbourhoods of the two edges and evalute the difference
-                let neighbours_a = self.graph.get_neighbours(node_a);
-                let neighbours_b = self.graph.get_neighbours(node_b);
-
                 //TODO: PERFORMANCE! This is bad
                 // -----------------------------------------------------------------------
                 /*
-                   This leads to many insert operations into the HashMap/HashSet Datastructure.
-                   For sure it is bad idea to do it this way in a nCr2 for-loop embedded in a n while-loop
+                   Every pair is evaluated in a nCr2 for-loop embedded in a n while-loop
                    resulting in n * (n! / 2! * (n-2)!) iterations. 1000 Nodes => 1000 * 499.500 = 499.500.000
-                   As one can see this may lead to billions of inserts really quick.
-
-                   * Nevertheless, it would be interesting to see how far the data structures could be optimized. *
 
                    The diff will not change for most nodes over the n iterations. Therefore it is not neccesarry to calculate
                    all combinations every iteration and instead just update/recalculate the combinations wich might have changed.
                    GreedyV2 will go for this approach.
                 */
-                let diff: HashSet<_> = neighbours_a
-                    .symmetric_difference(neighbours_b)
-                    .filter(|item| item != &&node_a && item != &&node_b)
-                    .collect();
-
-                //The difference would create new red edges. Add them to the local red edges
-                neighbours_a
-                    .iter()
-                    .filter(|x| diff.contains(x))
-                    .for_each(|node| {
-                        local_red_edges.add_node(*node);
-                        local_red_edges.add_edge(node_a, *node);
-                    });
-                neighbours_b
-                    .iter()
-                    .filter(|x| diff.contains(x))
-                    .for_each(|node| {
-                        local_red_edges.add_node(*node);
-                        local_red_edges.add_edge(node_b, *node);
-                    });
+                let value = self.score.evaluate(&self.graph, node_a, node_b);
                 //-------------------------------------------------------------------------
 
-                //Contract the nodes on the local red edges
-                local_red_edges.contract_nodes(node_a, node_b);
-
-                //Evalute the max degree of the local red edges and save preliminary result
-                if local_red_degree > local_red_edges.get_max_degree() {
-                    local_red_degree = local_red_edges.get_max_degree();
-                    contraction = (node_a, node_b);
-                    red_edges = local_red_edges;
-                    if local_red_degree == 0 {
+                //Evalute the score of the contraction and save preliminary result
+                if best
+                    .as_ref()
+                    .is_none_or(|(best_value, _)| value < *best_value)
+                {
+                    let unbeatable = value.iter().all(|criterion| *criterion == 0);
+                    best = Some((value, (node_a, node_b)));
+                    if unbeatable {
                         //We take the first best solution. And with 0 there cannot be some better
                         break;
                     }
@@ -130,28 +149,57 @@ impl Algo for Greedy {
             }
 
             //Update Algo internals after each iteration
-            self.global_red_edges = red_edges;
-            self.twin_width = cmp::max(self.twin_width, local_red_degree);
+            let (_, contraction) = best.expect("A graph with two nodes has a pair");
+            let red_degree = self.graph.contract(contraction.0, contraction.1);
+            self.twin_width = cmp::max(self.twin_width, red_degree);
             self.contraction_squence.push(contraction);
-            self.graph.contract_nodes(contraction.0, contraction.1);
         }
 
+        best_so_far.publish(&self.contraction_squence, self.twin_width);
         (self.contraction_squence.clone(), self.twin_width)
     }
+}
 
-    /// Constructs an string with resepect to the .tww format defined by the pace challenge
-    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+impl Greedy {
+    /// Creates a new `Greedy` instance which ranks the contractions by the given score
     ///
-    /// # Returns
-    /// A new string with respect to the tww format
-    fn output_tww_str(&self) -> String {
-        let mut tww = String::new();
-        self.contraction_squence
-            .iter()
-            .for_each(|(node_a, node_b)| {
-                writeln!(&mut tww, "{} {}", node_a, node_b).unwrap();
-            });
-        tww
+    /// # Parameters
+    /// * graph: The graph on wich the greedy algorithm should be performed.
+    /// * score: The score of a contraction, smaller is better
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, greedy::Greedy, score::Score};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    /// let mut greedy = Greedy::new_with_score(graph, Score::SymmetricDifference);
+    /// let (_, twin_width) = greedy.solve();
+    /// assert_eq!(twin_width, 2);
+    /// ```
+    pub fn new_with_score(graph: Graph, score: Score) -> Self {
+        Greedy {
+            score,
+            ..Self::new_with_graph(graph)
+        }
+    }
+}
+
+impl Solver for GreedyConfig {
+    fn name(&self) -> String {
+        "greedy".to_string()
+    }
+
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let mut greedy = Greedy::new_with_score(graph.clone(), self.score.clone());
+        let (sequence, _) = greedy.solve_anytime(best_so_far, token);
+        SolveResult::new(&graph, &sequence, None, start, None)
     }
 }
 
@@ -1,4 +1,9 @@
-use crate::{algo::Algo, graph::Graph};
+use crate::{
+    algo::{score::Score, Algo},
+    anytime::{Anytime, BestSoFar, CancellationToken},
+    graph::{Graph, Trigraph},
+    verify::verify,
+};
 
 use super::Greedy;
 
@@ -8,7 +13,10 @@ fn test_new_greedy_with_graph() {
     let greedy = Greedy::new_with_graph(graph);
 
     assert_eq!(greedy.contraction_squence, Vec::new());
-    assert_eq!(greedy.graph, Graph::from_edges(vec![(1, 2), (2, 3)]));
+    assert_eq!(
+        greedy.graph,
+        Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3)]))
+    );
 }
 
 #[test]
@@ -38,7 +46,7 @@ fn test_solve_x_nodes_cograph() {
     assert_eq!(
         greedy
             .graph
-            .get_neighbours(greedy.graph.get_all_nodes()[0])
+            .get_black_neighbours(greedy.graph.get_all_nodes()[0])
             .len(),
         0
     );
@@ -57,9 +65,117 @@ fn test_solve_x_nodes_no_cograph() {
     assert_eq!(
         greedy
             .graph
-            .get_neighbours(greedy.graph.get_all_nodes()[0])
+            .get_black_neighbours(greedy.graph.get_all_nodes()[0])
             .len(),
         0
     );
     assert_eq!(greedy.twin_width, 1);
 }
+
+#[test]
+fn test_solve_sequence_is_verified() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let mut greedy = Greedy::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = greedy.solve();
+
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_isolated_nodes() {
+    let graph = Graph::from_gr("p tww 6 3\n1 2\n2 3\n3 4\n");
+    let mut greedy = Greedy::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = greedy.solve();
+
+    assert_eq!(sequence.len(), 5);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_large_cograph() {
+    //The complete bipartite graph K_{150,150} is solved along its cotree
+    let graph = Graph::from_edges(
+        (1..=150)
+            .flat_map(|node_a| (151..=300).map(move |node_b| (node_a, node_b)))
+            .collect(),
+    );
+    let mut greedy = Greedy::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = greedy.solve();
+
+    assert_eq!(twin_width, 0);
+    assert_eq!(greedy.graph.get_node_count(), 1);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+fn test_solve_with_scores() {
+    let graph = Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 1),
+        (1, 6),
+        (6, 7),
+        (7, 3),
+        (5, 8),
+    ]);
+    let scores = [
+        Score::MaxRedDegree,
+        Score::MergedRedDegree,
+        Score::SymmetricDifference,
+        Score::NewRedEdges,
+        Score::Lexicographic(vec![Score::NewRedEdges, Score::MaxRedDegree]),
+    ];
+
+    for score in scores {
+        let mut greedy = Greedy::new_with_score(graph.clone(), score);
+
+        let (sequence, twin_width) = greedy.solve();
+
+        assert_eq!(sequence.len(), 7);
+        assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    }
+}
+
+#[test]
+fn test_solve_default_score() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+
+    assert_eq!(
+        Greedy::new_with_graph(graph.clone()).solve(),
+        Greedy::new_with_score(graph, Score::MaxRedDegree).solve()
+    );
+}
+
+#[test]
+fn test_solve_anytime_publishes() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let best_so_far = BestSoFar::new();
+
+    let (sequence, twin_width) = Greedy::new_with_graph(graph.clone())
+        .solve_anytime(&best_so_far, &CancellationToken::new());
+
+    assert_eq!(
+        (sequence.clone(), twin_width),
+        Greedy::new_with_graph(graph).solve()
+    );
+    assert_eq!(best_so_far.get(), Some((sequence.into(), twin_width)));
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let (sequence, twin_width) =
+        Greedy::new_with_graph(graph.clone()).solve_anytime(&BestSoFar::new(), &token);
+
+    //The remaining nodes are contracted into the smallest one
+    assert_eq!(sequence, vec![(1, 2), (1, 3), (1, 4), (1, 5), (1, 6)]);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
@@ -0,0 +1,316 @@
+use std::{
+    cmp,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    time::Instant,
+};
+
+use crate::algo::{
+    get_all_combinations,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{contract_rest, Anytime, BestSoFar, CancellationToken};
+use crate::cograph::recognize_cograph;
+use crate::graph::{Graph, Trigraph};
+use crate::sequence::ContractionSequence;
+
+/// Settings for `GreedyV2`, which always ranks by the max red degree and has no options
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct GreedyV2Config;
+
+/// Greedy algorithm which caches the score of every pair between the contractions.
+///
+/// It contracts the same pairs as `Greedy`, i.e. the first pair in sorted order which minimises
+/// the max red degree of the whole trigraph after the contraction.
+/// Instead of evaluating all pairs in every round, the local part of the score is cached in a
+/// priority structure. After a contraction only pairs with a node within distance two of the
+/// contracted nodes are updated, because no other pair can see a changed neighbourhood or red degree.
+pub struct GreedyV2 {
+    graph: Trigraph,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    /// The zero-width sequence, if the graph is a cograph
+    cograph: Option<ContractionSequence>,
+    /// Local score of every pair (node_a < node_b)
+    scores: HashMap<(u32, u32), usize>,
+    /// The same scores ordered by (score, node_a, node_b)
+    queue: BTreeSet<(usize, u32, u32)>,
+}
+
+impl Algo for GreedyV2 {
+    /// Creates a new `GreedyV2` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the greedy algorithm should be performed.
+    ///
+    /// # Returns
+    /// * New GreedyV2 instance with a graph and empty contraction sequence
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, greedy_v2::GreedyV2};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let greedy = GreedyV2::new_with_graph(graph);
+    /// ```
+    fn new_with_graph(graph: Graph) -> Self {
+        GreedyV2 {
+            graph: Trigraph::from(&graph),
+            cograph: recognize_cograph(&graph),
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            scores: HashMap::new(),
+            queue: BTreeSet::new(),
+        }
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Performs the greedy algorithm with cached pair scores
+    ///
+    /// # Returns
+    /// * The contraction sequence after completly working through the given graph.
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, greedy_v2::GreedyV2};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let mut greedy = GreedyV2::new_with_graph(graph);
+    /// let contraction_sequence = greedy.solve();
+    /// ```
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.solve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl Anytime for GreedyV2 {
+    /// Performs the greedy algorithm until the token is cancelled, then the remaining nodes are
+    /// contracted into one. Only the complete sequence is published.
+    fn solve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        //Cographs are contracted along their cotree without any red edge
+        if let Some(sequence) = self.cograph.take() {
+            for &(node_a, node_b) in sequence.iter() {
+                self.graph.contract(node_a, node_b);
+                self.contraction_squence.push((node_a, node_b));
+            }
+        }
+
+        let mut all_nodes = self.graph.get_all_nodes();
+        all_nodes.sort();
+        for (node_a, node_b) in get_all_combinations(all_nodes) {
+            self.update_score(node_a, node_b);
+        }
+
+        while self.graph.get_node_count() > 1 {
+            if token.is_cancelled() {
+                let red_degree = contract_rest(&mut self.graph, &mut self.contraction_squence);
+                self.twin_width = cmp::max(self.twin_width, red_degree);
+                break;
+            }
+            let (red_degree, node_a, node_b) = self.best_contraction();
+
+            let affected = self.nodes_within_distance_two(node_a, node_b);
+            self.graph.contract(node_a, node_b);
+            self.remove_scores(node_b);
+
+            //Update every pair containing an affected node
+            let mut all_nodes = self.graph.get_all_nodes();
+            all_nodes.sort();
+            for &node in &affected {
+                for &other in &all_nodes {
+                    if other != node && !(affected.contains(&other) && other < node) {
+                        self.update_score(cmp::min(node, other), cmp::max(node, other));
+                    }
+                }
+            }
+
+            self.twin_width = cmp::max(self.twin_width, red_degree);
+            self.contraction_squence.push((node_a, node_b));
+        }
+
+        best_so_far.publish(&self.contraction_squence, self.twin_width);
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+}
+
+impl GreedyV2 {
+    /// Finds the pair with the smallest max red degree after its contraction.
+    ///
+    /// The cached local score is a lower bound of the real score, so the queue is walked in order
+    /// until no remaining pair can beat the best one found so far.
+    ///
+    /// # Returns
+    /// * (max red degree after the contraction, node_a, node_b)
+    fn best_contraction(&self) -> (usize, u32, u32) {
+        let histogram = self.red_degree_histogram();
+        let mut best: Option<(usize, u32, u32)> = None;
+
+        for &(local_score, node_a, node_b) in &self.queue {
+            if best.is_some_and(|best| (local_score, node_a, node_b) > best) {
+                break;
+            }
+            let score = self.global_score(node_a, node_b, local_score, &histogram);
+            if best.is_none_or(|best| (score, node_a, node_b) < best) {
+                best = Some((score, node_a, node_b));
+            }
+        }
+
+        best.expect("A trigraph with more than one node has pairs")
+    }
+
+    /// Computes the max red degree of the trigraph after contracting two nodes, given their local score.
+    ///
+    /// Nodes which are not red neighbours of the merged node keep their red degree.
+    /// Their maximum is found in the histogram of red degrees by skipping the excluded nodes.
+    /// Every visited degree is either the result or only held by excluded nodes,
+    /// so at most one more degree than excluded nodes is visited.
+    fn global_score(
+        &self,
+        node_a: u32,
+        node_b: u32,
+        local_score: usize,
+        histogram: &BTreeMap<usize, usize>,
+    ) -> usize {
+        let mut excluded: HashMap<usize, usize> = HashMap::new();
+        for node in self
+            .merged_red_neighbours(node_a, node_b)
+            .iter()
+            .chain([&node_a, &node_b])
+        {
+            *excluded
+                .entry(self.graph.get_red_degree(*node))
+                .or_default() += 1;
+        }
+
+        histogram
+            .range(local_score + 1..)
+            .rev()
+            .find(|(degree, count)| **count > excluded.get(degree).copied().unwrap_or(0))
+            .map_or(local_score, |(degree, _)| *degree)
+    }
+
+    /// Computes the max red degree among the merged node and its red neighbours after a contraction
+    fn local_score(&self, node_a: u32, node_b: u32) -> usize {
+        let red_a = self.graph.get_red_neighbours(node_a);
+        let red_b = self.graph.get_red_neighbours(node_b);
+        let new_red = self.merged_red_neighbours(node_a, node_b);
+
+        new_red
+            .iter()
+            .map(|node| {
+                self.graph.get_red_degree(*node) + 1
+                    - usize::from(red_a.contains(node))
+                    - usize::from(red_b.contains(node))
+            })
+            .fold(new_red.len(), cmp::max)
+    }
+
+    fn merged_red_neighbours(&self, node_a: u32, node_b: u32) -> HashSet<u32> {
+        let mut new_red: HashSet<u32> = self
+            .graph
+            .get_red_neighbours(node_a)
+            .union(self.graph.get_red_neighbours(node_b))
+            .copied()
+            .collect();
+        new_red.extend(
+            self.graph
+                .get_black_neighbours(node_a)
+                .symmetric_difference(self.graph.get_black_neighbours(node_b)),
+        );
+        new_red.remove(&node_a);
+        new_red.remove(&node_b);
+        new_red
+    }
+
+    fn update_score(&mut self, node_a: u32, node_b: u32) {
+        let score = self.local_score(node_a, node_b);
+        if let Some(old) = self.scores.insert((node_a, node_b), score) {
+            self.queue.remove(&(old, node_a, node_b));
+        }
+        self.queue.insert((score, node_a, node_b));
+    }
+
+    fn remove_scores(&mut self, removed: u32) {
+        for node in self.graph.get_all_nodes() {
+            let key = (cmp::min(node, removed), cmp::max(node, removed));
+            if let Some(old) = self.scores.remove(&key) {
+                self.queue.remove(&(old, key.0, key.1));
+            }
+        }
+    }
+
+    /// Collects node_a and all nodes within distance two of node_a or node_b, without node_b
+    fn nodes_within_distance_two(&self, node_a: u32, node_b: u32) -> HashSet<u32> {
+        let neighbours: HashSet<u32> = [node_a, node_b]
+            .iter()
+            .flat_map(|node| self.all_neighbours(*node))
+            .collect();
+
+        let mut affected: HashSet<u32> = neighbours
+            .iter()
+            .flat_map(|node| self.all_neighbours(*node))
+            .collect();
+        affected.extend(neighbours);
+        affected.insert(node_a);
+        affected.remove(&node_b);
+        affected
+    }
+
+    fn all_neighbours(&self, node: u32) -> impl Iterator<Item = u32> + '_ {
+        self.graph
+            .get_black_neighbours(node)
+            .iter()
+            .chain(self.graph.get_red_neighbours(node))
+            .copied()
+    }
+
+    fn red_degree_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for node in self.graph.get_all_nodes() {
+            *histogram
+                .entry(self.graph.get_red_degree(node))
+                .or_default() += 1;
+        }
+        histogram
+    }
+}
+
+impl Solver for GreedyV2Config {
+    fn name(&self) -> String {
+        "greedy-v2".to_string()
+    }
+
+    /// Runs until the algorithm is done or the token is cancelled
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let (sequence, _) =
+            GreedyV2::new_with_graph(graph.clone()).solve_anytime(best_so_far, token);
+        SolveResult::new(&graph, &sequence, None, start, None)
+    }
+}
+
+#[cfg(test)]
+mod tests;
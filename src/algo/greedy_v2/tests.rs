@@ -0,0 +1,84 @@
+use crate::{
+    algo::{greedy::Greedy, Algo},
+    anytime::{Anytime, BestSoFar, CancellationToken},
+    graph::Graph,
+    random::Rng,
+    verify::verify,
+};
+
+use super::GreedyV2;
+
+#[test]
+fn test_new_greedy_v2_with_graph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    let greedy = GreedyV2::new_with_graph(graph);
+
+    assert_eq!(greedy.contraction_squence, Vec::new());
+    assert!(greedy.queue.is_empty());
+}
+
+#[test]
+fn test_solve_two_nodes() {
+    let graph = Graph::from_edges(vec![(1, 2)]);
+    let mut greedy = GreedyV2::new_with_graph(graph);
+
+    let (sequence, twin_width) = greedy.solve();
+
+    assert_eq!(sequence, vec![(1, 2)]);
+    assert_eq!(twin_width, 0);
+    assert!(greedy.queue.is_empty());
+}
+
+#[test]
+fn test_solve_x_nodes_no_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1), (5, 2)]);
+    let mut greedy = GreedyV2::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = greedy.solve();
+
+    assert_eq!(sequence.len(), 4);
+    assert_eq!(twin_width, 1);
+    assert_eq!(verify(&graph, &sequence), Ok(1));
+}
+
+/// Small xorshift generator, so the tests are reproducible without further dependencies
+#[test]
+fn test_solve_same_as_greedy() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for density in [2, 3, 5] {
+        for _ in 0..5 {
+            let mut graph = Graph::new();
+            (1..=15).for_each(|node| graph.add_node(node));
+            for node_a in 1..=15 {
+                for node_b in node_a + 1..=15 {
+                    if rng.below(density) == 0 {
+                        graph.add_edge(node_a, node_b);
+                    }
+                }
+            }
+
+            let expected = Greedy::new_with_graph(graph.clone()).solve();
+            let result = GreedyV2::new_with_graph(graph.clone()).solve();
+
+            assert_eq!(result, expected);
+            assert_eq!(verify(&graph, &result.0), Ok(result.1));
+        }
+    }
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let token = CancellationToken::new();
+    token.cancel();
+    let best_so_far = BestSoFar::new();
+
+    let (sequence, twin_width) =
+        GreedyV2::new_with_graph(graph.clone()).solve_anytime(&best_so_far, &token);
+
+    //The remaining nodes are contracted into the smallest one
+    assert_eq!(sequence, vec![(1, 2), (1, 3), (1, 4), (1, 5), (1, 6)]);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(best_so_far.get(), Some((sequence.into(), twin_width)));
+}
@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+use crate::algo::Algo;
+use crate::graph::Graph;
+use crate::preprocessing::collapse_twins;
+use crate::sequence::ContractionSequence;
+
+/// Wrapper which collapses twins before running another algorithm.
+///
+/// The contractions of the twins come first in the sequence, followed by the sequence the inner
+/// algorithm finds for the reduced graph. Since the twin contractions have width 0, the width
+/// is the width of the inner algorithm.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::algo::{Algo, greedy::Greedy, preprocessed::Preprocessed};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// let mut algo: Preprocessed<Greedy> = Preprocessed::new_with_graph(graph);
+/// let (_, twin_width) = algo.solve();
+/// assert_eq!(twin_width, 2);
+/// ```
+pub struct Preprocessed<A: Algo> {
+    graph: Graph,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    prefix_len: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<A: Algo> Algo for Preprocessed<A> {
+    /// Creates a new `Preprocessed` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the algorithm should be performed.
+    ///
+    /// # Returns
+    /// * New Preprocessed instance with a graph and empty contraction sequence
+    fn new_with_graph(graph: Graph) -> Self {
+        Preprocessed {
+            graph,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            prefix_len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Collapses the twins and solves the reduced graph with the inner algorithm
+    ///
+    /// # Returns
+    /// * The contraction sequence of the twins followed by the one of the inner algorithm
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        let (reduced, prefix) = collapse_twins(&self.graph);
+        let (sequence, width) = A::new_with_graph(reduced).solve();
+
+        self.prefix_len = prefix.len();
+        self.contraction_squence = prefix.into();
+        self.contraction_squence.extend(sequence);
+        self.twin_width = width;
+
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl<A: Algo> Preprocessed<A> {
+    /// Gets the number of twin contractions at the start of the last sequence
+    pub fn get_prefix_len(&self) -> usize {
+        self.prefix_len
+    }
+}
+
+#[cfg(test)]
+mod tests;
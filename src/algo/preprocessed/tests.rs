@@ -0,0 +1,45 @@
+use crate::{
+    algo::{greedy::Greedy, Algo},
+    graph::Graph,
+    verify::verify,
+};
+
+use super::Preprocessed;
+
+#[test]
+fn test_solve_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    let mut algo: Preprocessed<Greedy> = Preprocessed::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 0);
+    assert_eq!(algo.get_prefix_len(), 3);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+fn test_solve_with_twins() {
+    //A cycle of length 6 where every vertex has a false twin
+    let mut edges = Vec::new();
+    for node in 0..6 {
+        let next = (node + 1) % 6;
+        for (a, b) in [
+            (node, next),
+            (node + 6, next),
+            (node, next + 6),
+            (node + 6, next + 6),
+        ] {
+            edges.push((a + 1, b + 1));
+        }
+    }
+    let graph = Graph::from_edges(edges);
+    let mut algo: Preprocessed<Greedy> = Preprocessed::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(algo.get_prefix_len(), 6);
+    assert_eq!(twin_width, 2);
+    assert_eq!(verify(&graph, &sequence), Ok(2));
+    assert_eq!(algo.output_tww_str().lines().count(), 11);
+}
@@ -0,0 +1,280 @@
+use std::{
+    cmp,
+    time::{Duration, Instant},
+};
+
+use crate::algo::{
+    get_all_combinations,
+    score::Score,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{contract_rest, Anytime, BestSoFar, CancellationToken};
+use crate::bounds::first_contraction_bound;
+use crate::cograph::recognize_cograph;
+use crate::graph::{Graph, Trigraph};
+use crate::random::Rng;
+use crate::sequence::ContractionSequence;
+
+/// Settings for `RandomizedGreedy`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RandomizedConfig {
+    /// Seed of the first restart, restart `i` uses `seed + i`
+    pub seed: u64,
+    /// Number of the best pairs among which the contraction is sampled. Pairs with the same
+    /// score as the last of them are sampled as well, so 1 breaks ties randomly.
+    pub top_k: usize,
+    /// Maximum number of restarts, `None` means unlimited
+    pub restarts: Option<usize>,
+    /// Maximum time for all restarts, `None` means unlimited. The first restart always finishes.
+    pub time: Option<Duration>,
+    /// The score of a contraction, smaller is better
+    pub score: Score,
+}
+
+impl Default for RandomizedConfig {
+    fn default() -> Self {
+        RandomizedConfig {
+            seed: 0,
+            top_k: 1,
+            restarts: Some(10),
+            time: Some(Duration::from_secs(10)),
+            score: Score::default(),
+        }
+    }
+}
+
+/// Greedy algorithm which samples every contraction among the best pairs and restarts
+/// with new seeds.
+///
+/// `Greedy` always takes the first best pair in sorted order, so it takes the same bad decision
+/// on every run. Here every restart samples with its own seed, and the best sequence of all
+/// restarts is kept together with its seed. Running again with this seed and a single restart
+/// gives the same sequence.
+/// A restart is abandoned as soon as it reaches the width of the best sequence, and the search
+/// stops when the best sequence reaches the first contraction bound.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::algo::{Algo, randomized_greedy::{RandomizedConfig, RandomizedGreedy}};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+/// let config = RandomizedConfig { seed: 7, ..RandomizedConfig::default() };
+/// let mut algo = RandomizedGreedy::new_with_config(graph.clone(), config.clone());
+/// let (sequence, twin_width) = algo.solve();
+///
+/// let single = RandomizedConfig { seed: algo.get_seed(), restarts: Some(1), ..config };
+/// let mut again = RandomizedGreedy::new_with_config(graph, single);
+/// assert_eq!(again.solve(), (sequence, twin_width));
+/// ```
+pub struct RandomizedGreedy {
+    graph: Graph,
+    config: RandomizedConfig,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    seed: u64,
+    restarts: usize,
+}
+
+impl Algo for RandomizedGreedy {
+    /// Creates a new `RandomizedGreedy` instance with the default configuration
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the algorithm should be performed.
+    ///
+    /// # Returns
+    /// * New RandomizedGreedy instance with a graph and empty contraction sequence
+    fn new_with_graph(graph: Graph) -> Self {
+        Self::new_with_config(graph, RandomizedConfig::default())
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Performs the restarts until a limit of the configuration is reached
+    ///
+    /// # Returns
+    /// * The best contraction sequence of all restarts and its width
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.solve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl Anytime for RandomizedGreedy {
+    /// Performs the restarts until a limit of the configuration is reached or the token is
+    /// cancelled, and publishes the sequence of every restart which improves on the best one.
+    /// If the token is cancelled during the first restart, its remaining nodes are contracted
+    /// into one.
+    fn solve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        let start = Instant::now();
+        self.seed = self.config.seed;
+        self.restarts = 0;
+
+        //Cographs are contracted along their cotree without any red edge
+        if let Some(sequence) = recognize_cograph(&self.graph) {
+            self.contraction_squence = sequence.into();
+            self.twin_width = 0;
+            best_so_far.publish(&self.contraction_squence, self.twin_width);
+            return (self.contraction_squence.clone(), self.twin_width);
+        }
+
+        let lower_bound = first_contraction_bound(&self.graph);
+        let mut best: Option<(Vec<(u32, u32)>, usize)> = None;
+        loop {
+            let seed = self.config.seed.wrapping_add(self.restarts as u64);
+            let limit = best.as_ref().map(|(_, width)| *width);
+            if let Some((sequence, twin_width)) = self.run_once(seed, limit, token) {
+                best_so_far.publish(&sequence, twin_width);
+                self.seed = seed;
+                best = Some((sequence, twin_width));
+            }
+            self.restarts += 1;
+
+            let reached_bound = best
+                .as_ref()
+                .is_some_and(|(_, width)| *width <= lower_bound);
+            let out_of_restarts = self
+                .config
+                .restarts
+                .is_some_and(|restarts| self.restarts >= restarts);
+            let out_of_time = self.config.time.is_some_and(|time| start.elapsed() >= time);
+            if reached_bound || out_of_restarts || out_of_time || token.is_cancelled() {
+                break;
+            }
+        }
+
+        (self.contraction_squence, self.twin_width) =
+            best.expect("The first restart always finishes");
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+}
+
+impl RandomizedGreedy {
+    /// Creates a new `RandomizedGreedy` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the algorithm should be performed.
+    /// * config: The sampling and the limits of the restarts
+    ///
+    /// # Panics
+    /// If `top_k` is 0
+    pub fn new_with_config(graph: Graph, config: RandomizedConfig) -> Self {
+        if config.top_k == 0 {
+            panic!("At least one pair has to be sampled");
+        }
+        RandomizedGreedy {
+            graph,
+            seed: config.seed,
+            config,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            restarts: 0,
+        }
+    }
+
+    /// Gets the seed of the restart which found the sequence of the last `solve`
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Gets the number of restarts of the last `solve`
+    pub fn get_restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Contracts the graph once with the given seed
+    ///
+    /// # Returns
+    /// * The sequence and its width, None if the width reached the limit or the token was
+    ///   cancelled while there is a limit
+    fn run_once(
+        &self,
+        seed: u64,
+        limit: Option<usize>,
+        token: &CancellationToken,
+    ) -> Option<(Vec<(u32, u32)>, usize)> {
+        let mut rng = Rng::new(seed);
+        let mut trigraph = Trigraph::from(&self.graph);
+        let mut sequence = Vec::new();
+        let mut twin_width = 0;
+
+        while trigraph.get_node_count() > 1 {
+            if token.is_cancelled() {
+                if limit.is_some() {
+                    return None;
+                }
+                twin_width = cmp::max(twin_width, contract_rest(&mut trigraph, &mut sequence));
+                break;
+            }
+            let mut all_nodes = trigraph.get_all_nodes();
+            all_nodes.sort();
+
+            let mut candidates: Vec<(Vec<usize>, (u32, u32))> = get_all_combinations(all_nodes)
+                .map(|(node_a, node_b)| {
+                    let value = self.config.score.evaluate(&trigraph, node_a, node_b);
+                    (value, (node_a, node_b))
+                })
+                .collect();
+            candidates.sort_by(|a, b| a.0.cmp(&b.0));
+
+            //The k best pairs and all pairs which are as good as the k-th
+            let last = cmp::min(self.config.top_k, candidates.len()) - 1;
+            let count = candidates
+                .iter()
+                .take_while(|(value, _)| *value <= candidates[last].0)
+                .count();
+            let (_, (node_a, node_b)) = candidates[rng.below(count)];
+
+            twin_width = cmp::max(twin_width, trigraph.contract(node_a, node_b));
+            sequence.push((node_a, node_b));
+            if limit.is_some_and(|limit| twin_width >= limit) {
+                return None;
+            }
+        }
+
+        Some((sequence, twin_width))
+    }
+}
+
+impl Solver for RandomizedConfig {
+    fn name(&self) -> String {
+        "randomized".to_string()
+    }
+
+    /// # Panics
+    /// If `top_k` is 0
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let mut algo = RandomizedGreedy::new_with_config(graph.clone(), self.clone());
+        let (sequence, _) = algo.solve_anytime(best_so_far, token);
+        //The restarts stop at this bound, so it is cheap compared to the search
+        let lower_bound = first_contraction_bound(&graph);
+        let restarts = Some(algo.get_restarts() as u64);
+        SolveResult::new(&graph, &sequence, Some(lower_bound), start, restarts)
+            .map(|result| result.with_seed(algo.get_seed()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
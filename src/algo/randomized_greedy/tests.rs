@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use crate::{
+    algo::{greedy::Greedy, score::Score, solver::Solver, Algo},
+    anytime::{Anytime, BestSoFar, CancellationToken},
+    graph::Graph,
+    verify::verify,
+};
+
+use super::{RandomizedConfig, RandomizedGreedy};
+
+/// The 5x5 grid, where `Greedy` finds width 4
+fn grid() -> Graph {
+    let mut edges = Vec::new();
+    for node in 1..=25 {
+        if node % 5 != 0 {
+            edges.push((node, node + 1));
+        }
+        if node <= 20 {
+            edges.push((node, node + 5));
+        }
+    }
+    Graph::from_edges(edges)
+}
+
+fn config(seed: u64, restarts: usize) -> RandomizedConfig {
+    RandomizedConfig {
+        seed,
+        restarts: Some(restarts),
+        time: None,
+        ..RandomizedConfig::default()
+    }
+}
+
+#[test]
+fn test_solve_is_verified() {
+    let graph = grid();
+    let mut algo = RandomizedGreedy::new_with_config(graph.clone(), config(1, 3));
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(sequence.len(), 24);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(algo.get_restarts(), 3);
+}
+
+#[test]
+fn test_solve_is_reproducible() {
+    let graph = grid();
+    let mut first = RandomizedGreedy::new_with_config(graph.clone(), config(5, 4));
+    let mut second = RandomizedGreedy::new_with_config(graph.clone(), config(5, 4));
+
+    let result = first.solve();
+
+    assert_eq!(second.solve(), result);
+    assert_eq!(second.get_seed(), first.get_seed());
+
+    let mut single = RandomizedGreedy::new_with_config(graph, config(first.get_seed(), 1));
+    assert_eq!(single.solve(), result);
+}
+
+#[test]
+fn test_solver_reports_seed() {
+    let graph = grid();
+
+    let result = config(5, 4).solve(graph.clone()).unwrap();
+
+    let seed = result.stats.seed.unwrap();
+    let single = config(seed, 1).solve(graph).unwrap();
+    assert_eq!(single.sequence, result.sequence);
+    assert_eq!(single.stats.seed, Some(seed));
+}
+
+#[test]
+fn test_solve_restarts_do_not_get_worse() {
+    let graph = grid();
+    let (_, single_width) = RandomizedGreedy::new_with_config(graph.clone(), config(9, 1)).solve();
+    let (_, greedy_width) = Greedy::new_with_graph(graph.clone()).solve();
+
+    let mut algo = RandomizedGreedy::new_with_config(graph, config(9, 10));
+    let (_, twin_width) = algo.solve();
+
+    assert!(twin_width <= single_width);
+    assert!(twin_width <= greedy_width);
+}
+
+#[test]
+fn test_solve_stops_at_lower_bound() {
+    //Every first contraction of a cycle creates two red edges, which every sequence reaches
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 1)]);
+    let mut algo = RandomizedGreedy::new_with_config(graph, config(0, 100));
+
+    let (_, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 2);
+    assert_eq!(algo.get_restarts(), 1);
+}
+
+#[test]
+fn test_solve_top_k_and_score() {
+    let graph = grid();
+    let config = RandomizedConfig {
+        top_k: 5,
+        score: Score::SymmetricDifference,
+        ..config(2, 3)
+    };
+    let mut algo = RandomizedGreedy::new_with_config(graph.clone(), config);
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_time_limit() {
+    let config = RandomizedConfig {
+        restarts: None,
+        time: Some(Duration::ZERO),
+        ..RandomizedConfig::default()
+    };
+    let mut algo = RandomizedGreedy::new_with_config(grid(), config);
+
+    algo.solve();
+
+    assert_eq!(algo.get_restarts(), 1);
+}
+
+#[test]
+fn test_solve_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    let mut algo = RandomizedGreedy::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = algo.solve();
+
+    assert_eq!(twin_width, 0);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
+
+#[test]
+#[should_panic(expected = "At least one pair has to be sampled")]
+fn test_new_without_pairs() {
+    let config = RandomizedConfig {
+        top_k: 0,
+        ..RandomizedConfig::default()
+    };
+    RandomizedGreedy::new_with_config(grid(), config);
+}
+
+#[test]
+fn test_solve_anytime_publishes_best() {
+    let graph = grid();
+    let best_so_far = BestSoFar::new();
+    let mut algo = RandomizedGreedy::new_with_config(graph.clone(), config(0, 5));
+
+    let (sequence, twin_width) = algo.solve_anytime(&best_so_far, &CancellationToken::new());
+
+    assert_eq!(best_so_far.get(), Some((sequence.into(), twin_width)));
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = grid();
+    let token = CancellationToken::new();
+    token.cancel();
+    let unlimited = RandomizedConfig {
+        restarts: None,
+        ..config(0, 1)
+    };
+    let mut algo = RandomizedGreedy::new_with_config(graph.clone(), unlimited);
+
+    let (sequence, twin_width) = algo.solve_anytime(&BestSoFar::new(), &token);
+
+    assert_eq!(algo.get_restarts(), 1);
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
@@ -0,0 +1,271 @@
+use std::{
+    cmp,
+    time::{Duration, Instant},
+};
+
+use crate::algo::{
+    greedy::Greedy,
+    solver::{check_graph, SolveError, SolveResult, Solver},
+    Algo,
+};
+use crate::anytime::{Anytime, BestSoFar, CancellationToken};
+use crate::bounds::first_contraction_bound;
+use crate::graph::Graph;
+use crate::sat::{Cdcl, Encoding, ExternalSolver, SatError, SatResult, SatSolver};
+use crate::sequence::ContractionSequence;
+use crate::verify::verify;
+
+/// Settings for `Sat`
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SatConfig {
+    /// An external solver like kissat, None uses the built-in `Cdcl`
+    pub external: Option<ExternalSolver>,
+    /// Maximum time to search
+    pub time: Option<Duration>,
+}
+
+/// Exact algorithm which binary-searches the twin-width with a SAT solver.
+///
+/// The search starts between `first_contraction_bound` as lower bound and the width of `Greedy`
+/// as upper bound. Every satisfiable formula is decoded and verified, and its real width becomes
+/// the new upper bound. If the solver gives up or fails, answers with an invalid model, or the
+/// token of `search_anytime` is cancelled, the best sequence found so far is returned and
+/// `is_optimal` is false.
+pub struct Sat<S: SatSolver> {
+    graph: Graph,
+    solver: S,
+    contraction_squence: Vec<(u32, u32)>,
+    twin_width: usize,
+    lower_bound: usize,
+    optimal: bool,
+    error: Option<SatError>,
+}
+
+impl<S: SatSolver + Default> Algo for Sat<S> {
+    /// Creates a new `Sat` instance with the default solver of its type
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    ///
+    /// # Returns
+    /// * New Sat instance with a graph and empty contraction sequence
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, sat::Sat};
+    /// use twinwidth::sat::Cdcl;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let search: Sat<Cdcl> = Sat::new_with_graph(graph);
+    /// ```
+    fn new_with_graph(graph: Graph) -> Self {
+        Self::new_with_solver(graph, S::default())
+    }
+
+    /// Gets the max red degree
+    fn get_max_red_degree(&self) -> usize {
+        self.twin_width
+    }
+
+    /// Performs the search
+    ///
+    /// # Returns
+    /// * The best contraction sequence found and its width
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::{Algo, sat::Sat};
+    /// use twinwidth::sat::Cdcl;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    /// let mut search: Sat<Cdcl> = Sat::new_with_graph(graph);
+    /// let (_, twin_width) = search.solve();
+    /// assert_eq!(twin_width, 2);
+    /// assert!(search.is_optimal());
+    /// ```
+    fn solve(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.search()
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Returns
+    /// A new string with respect to the tww format
+    fn output_tww_str(&self) -> String {
+        ContractionSequence::from(self.contraction_squence.clone()).to_tww()
+    }
+}
+
+impl<S: SatSolver> Sat<S> {
+    /// Creates a new `Sat` instance with a given solver
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    /// * solver: The backend which decides the formulas
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::algo::sat::Sat;
+    /// use twinwidth::sat::ExternalSolver;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// let solver = ExternalSolver::new("kissat", vec!["-q".to_string()]);
+    /// let search = Sat::new_with_solver(graph, solver);
+    /// ```
+    pub fn new_with_solver(graph: Graph, solver: S) -> Self {
+        Sat {
+            graph,
+            solver,
+            contraction_squence: Vec::new(),
+            twin_width: 0,
+            lower_bound: 0,
+            optimal: false,
+            error: None,
+        }
+    }
+
+    /// Whether the width of the last `solve` is proven to be optimal
+    pub fn is_optimal(&self) -> bool {
+        self.optimal
+    }
+
+    /// Gets the lower bound of the last `solve`, which is its width if it is optimal
+    pub fn get_lower_bound(&self) -> usize {
+        self.lower_bound
+    }
+
+    /// Gets the error of the solver which stopped the last `solve`, if any
+    pub fn get_error(&self) -> Option<&SatError> {
+        self.error.as_ref()
+    }
+
+    /// Performs the search like `Algo::solve`, also for solvers without a default instance
+    ///
+    /// # Returns
+    /// * The best contraction sequence found and its width
+    pub fn search(&mut self) -> (Vec<(u32, u32)>, usize) {
+        self.search_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Performs the search like `search`, but publishes the sequence of `Greedy` and every
+    /// improvement of it, and stops before the next formula once the token is cancelled.
+    /// The token should also be given to the solver, so it can give up on a formula.
+    ///
+    /// # Returns
+    /// * The best contraction sequence found and its width
+    pub fn search_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize) {
+        self.error = None;
+        let mut greedy = Greedy::new_with_graph(self.graph.clone());
+        let (sequence, width) = greedy.solve_anytime(best_so_far, token);
+        self.contraction_squence = sequence;
+        self.twin_width = width;
+
+        //Greedy solves cographs along their cotree, so they need no formula at all
+        let mut lower_bound = if self.twin_width == 0 {
+            0
+        } else {
+            first_contraction_bound(&self.graph)
+        };
+
+        while lower_bound < self.twin_width && !token.is_cancelled() {
+            let width = (lower_bound + self.twin_width) / 2;
+            let encoding = Encoding::new(&self.graph, width);
+            match self.solver.solve(encoding.get_cnf()) {
+                Ok(SatResult::Satisfiable(model)) => {
+                    match self.check_model(&encoding, &model, width) {
+                        Ok((sequence, real_width)) => {
+                            self.contraction_squence = sequence.into();
+                            self.twin_width = real_width;
+                            best_so_far.publish(&self.contraction_squence, self.twin_width);
+                        }
+                        Err(err) => {
+                            self.error = Some(err);
+                            break;
+                        }
+                    }
+                }
+                Ok(SatResult::Unsatisfiable) => lower_bound = width + 1,
+                Ok(SatResult::Unknown) => break,
+                Err(err) => {
+                    self.error = Some(err);
+                    break;
+                }
+            }
+        }
+        self.lower_bound = cmp::min(lower_bound, self.twin_width);
+        self.optimal = lower_bound >= self.twin_width;
+
+        (self.contraction_squence.clone(), self.twin_width)
+    }
+
+    /// Decodes and verifies a model, which an external solver may have got wrong
+    ///
+    /// # Returns
+    /// * The sequence of the model and its width
+    /// * `SatError::InvalidOutput` if the model is no valid sequence of at most the given width
+    fn check_model(
+        &self,
+        encoding: &Encoding,
+        model: &[bool],
+        width: usize,
+    ) -> Result<(ContractionSequence, usize), SatError> {
+        let sequence = encoding.decode(model)?;
+        let real_width = verify(&self.graph, &sequence).map_err(|err| {
+            SatError::InvalidOutput(format!("model is no valid contraction sequence: {err}"))
+        })?;
+        if real_width > width {
+            return Err(SatError::InvalidOutput(format!(
+                "model has width {real_width} instead of at most {width}"
+            )));
+        }
+        Ok((sequence, real_width))
+    }
+}
+
+impl Solver for SatConfig {
+    fn name(&self) -> String {
+        "sat".to_string()
+    }
+
+    /// Searches until the width is optimal, the solver gives up or the token is cancelled
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        check_graph(&graph)?;
+        let start = Instant::now();
+        let token = &match self.time {
+            Some(time) => token.clone().with_deadline(start + time),
+            None => token.clone(),
+        };
+        let (sequence, lower_bound) = match &self.external {
+            Some(solver) => {
+                let solver = solver.clone().with_token(token.clone());
+                let mut search = Sat::new_with_solver(graph.clone(), solver);
+                (
+                    search.search_anytime(best_so_far, token).0,
+                    search.get_lower_bound(),
+                )
+            }
+            None => {
+                let solver = Cdcl::with_token(token.clone());
+                let mut search = Sat::new_with_solver(graph.clone(), solver);
+                (
+                    search.search_anytime(best_so_far, token).0,
+                    search.get_lower_bound(),
+                )
+            }
+        };
+        SolveResult::new(&graph, &sequence, Some(lower_bound), start, None)
+    }
+}
+
+#[cfg(test)]
+mod tests;
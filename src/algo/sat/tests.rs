@@ -0,0 +1,159 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    algo::{branch_and_bound::BranchAndBound, solver::Solver, Algo},
+    anytime::{BestSoFar, CancellationToken},
+    generate::Family,
+    graph::Graph,
+    sat::{Cdcl, Cnf, ExternalSolver, SatError, SatResult, SatSolver},
+    verify::verify,
+};
+
+use super::{Sat, SatConfig};
+
+fn petersen_graph() -> Graph {
+    Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 1),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 10),
+        (6, 8),
+        (8, 10),
+        (10, 7),
+        (7, 9),
+        (9, 6),
+    ])
+}
+
+#[test]
+fn test_solve_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+    let mut search: Sat<Cdcl> = Sat::new_with_graph(graph);
+
+    assert_eq!(search.solve(), (Vec::new(), 0));
+    assert!(search.is_optimal());
+}
+
+#[test]
+fn test_solve_path() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+    let mut search: Sat<Cdcl> = Sat::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert_eq!(twin_width, 1);
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(1));
+}
+
+#[test]
+fn test_solve_petersen_graph() {
+    let graph = petersen_graph();
+    let mut search: Sat<Cdcl> = Sat::new_with_graph(graph.clone());
+
+    let (sequence, twin_width) = search.solve();
+
+    assert!(search.is_optimal());
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+    assert_eq!(twin_width, 4);
+}
+
+/// Greedy is not optimal on this graph, so the solver is always asked
+fn tiny005() -> Graph {
+    let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("pacechallenge/tiny-set/tiny005.gr");
+    Graph::from_gr(&std::fs::read_to_string(path).unwrap())
+}
+
+#[test]
+fn test_solve_conflict_limit() {
+    let graph = tiny005();
+    let mut search = Sat::new_with_solver(graph.clone(), Cdcl::with_conflict_limit(1));
+
+    let (sequence, twin_width) = search.solve();
+
+    //The greedy sequence is still a valid result
+    assert!(!search.is_optimal());
+    assert!(search.get_error().is_none());
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_solver_error() {
+    let graph = tiny005();
+    let solver = ExternalSolver::new("this-sat-solver-does-not-exist", Vec::new());
+    let mut search = Sat::new_with_solver(graph.clone(), solver);
+
+    let (sequence, twin_width) = search.search();
+
+    assert!(!search.is_optimal());
+    assert!(matches!(search.get_error(), Some(SatError::Io(_))));
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+/// Claims that every formula is satisfiable, with a model which sets every variable to false
+struct Liar;
+
+impl SatSolver for Liar {
+    fn solve(&mut self, cnf: &Cnf) -> Result<SatResult, SatError> {
+        Ok(SatResult::Satisfiable(vec![
+            false;
+            cnf.get_variable_count()
+        ]))
+    }
+}
+
+#[test]
+fn test_solve_invalid_model() {
+    let graph = tiny005();
+    let mut search = Sat::new_with_solver(graph.clone(), Liar);
+
+    let (sequence, twin_width) = search.search();
+
+    assert!(!search.is_optimal());
+    assert!(matches!(
+        search.get_error(),
+        Some(SatError::InvalidOutput(_))
+    ));
+    assert_eq!(verify(&graph, &sequence), Ok(twin_width));
+}
+
+#[test]
+fn test_solve_same_as_branch_and_bound() {
+    //Both algorithms are exact, so every unsatisfiable answer of the embedded solver is checked
+    //against an independent proof of the lower bound
+    for seed in 0..12 {
+        let graph = Family::Random(9, 0.4).generate(seed);
+        let mut search: Sat<Cdcl> = Sat::new_with_graph(graph.clone());
+        let mut exact = BranchAndBound::new_with_graph(graph.clone());
+
+        let (_, twin_width) = search.solve();
+
+        assert!(search.is_optimal(), "Seed: {seed}");
+        assert_eq!(twin_width, exact.solve().1, "Seed: {seed}");
+        assert!(exact.is_optimal(), "Seed: {seed}");
+    }
+}
+
+#[test]
+fn test_solve_anytime_cancelled() {
+    let graph = Family::Random(30, 0.3).generate(1);
+    let start = Instant::now();
+    let token = CancellationToken::new().with_deadline(start + Duration::from_millis(100));
+    let best_so_far = BestSoFar::new();
+
+    let result = SatConfig::default()
+        .solve_anytime(graph, &best_so_far, &token)
+        .unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(!result.optimal);
+    assert_eq!(best_so_far.get_twin_width(), Some(result.width));
+}
@@ -0,0 +1,159 @@
+use std::{collections::HashSet, error::Error, fmt, str::FromStr};
+
+use crate::graph::Trigraph;
+
+/// A criterion to rank the contraction of two nodes, smaller values are better.
+///
+/// All scores are non-negative, so a contraction which scores 0 in every criterion can not be
+/// beaten and the search for a better one can stop.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::{Graph, Trigraph};
+/// use twinwidth::algo::score::Score;
+/// let trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5)]));
+/// let score = Score::Lexicographic(vec![Score::MaxRedDegree, Score::SymmetricDifference]);
+/// assert_eq!(score.evaluate(&trigraph, 1, 3), vec![1, 1]);
+/// assert_eq!(score.evaluate(&trigraph, 2, 4), vec![2, 2]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub enum Score {
+    /// The max red degree of the whole trigraph after the contraction
+    #[default]
+    MaxRedDegree,
+    /// The red degree of the merged node
+    MergedRedDegree,
+    /// The size of the symmetric difference of the neighbourhoods of both nodes,
+    /// without the nodes themselves
+    SymmetricDifference,
+    /// The number of red edges of the merged node which are neither red edges of the first
+    /// node nor of the second node before the contraction
+    NewRedEdges,
+    /// Compares by the first score, ties are broken by the following ones
+    Lexicographic(Vec<Score>),
+}
+
+impl Score {
+    /// Evaluates the contraction of two nodes
+    ///
+    /// # Parameters
+    /// * trigraph: The trigraph before the contraction
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Returns
+    /// * The values of all criteria, which are compared lexicographically
+    ///
+    /// # Panics
+    /// If one node does not exist
+    pub fn evaluate(&self, trigraph: &Trigraph, node_a: u32, node_b: u32) -> Vec<usize> {
+        let mut values = Vec::new();
+        self.evaluate_into(trigraph, node_a, node_b, &mut values);
+        values
+    }
+
+    fn evaluate_into(
+        &self,
+        trigraph: &Trigraph,
+        node_a: u32,
+        node_b: u32,
+        values: &mut Vec<usize>,
+    ) {
+        match self {
+            Score::MaxRedDegree => values.push(trigraph.get_max_red_degree_after(node_a, node_b)),
+            Score::MergedRedDegree => {
+                values.push(trigraph.get_red_neighbours_after(node_a, node_b).len())
+            }
+            Score::SymmetricDifference => {
+                let neighbours = |node: u32| {
+                    trigraph
+                        .get_black_neighbours(node)
+                        .union(trigraph.get_red_neighbours(node))
+                        .copied()
+                        .collect::<HashSet<u32>>()
+                };
+                let difference = neighbours(node_a)
+                    .symmetric_difference(&neighbours(node_b))
+                    .filter(|node| **node != node_a && **node != node_b)
+                    .count();
+                values.push(difference);
+            }
+            Score::NewRedEdges => {
+                let red_a = trigraph.get_red_neighbours(node_a);
+                let red_b = trigraph.get_red_neighbours(node_b);
+                let new_red = trigraph
+                    .get_red_neighbours_after(node_a, node_b)
+                    .iter()
+                    .filter(|node| !red_a.contains(node) && !red_b.contains(node))
+                    .count();
+                values.push(new_red);
+            }
+            Score::Lexicographic(scores) => scores
+                .iter()
+                .for_each(|score| score.evaluate_into(trigraph, node_a, node_b, values)),
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::MaxRedDegree => write!(f, "max-red-degree"),
+            Score::MergedRedDegree => write!(f, "merged-red-degree"),
+            Score::SymmetricDifference => write!(f, "symmetric-difference"),
+            Score::NewRedEdges => write!(f, "new-red-edges"),
+            Score::Lexicographic(scores) => {
+                let names: Vec<String> = scores.iter().map(|score| score.to_string()).collect();
+                write!(f, "{}", names.join("+"))
+            }
+        }
+    }
+}
+
+/// The name is not the name of a score
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParseScoreError {
+    name: String,
+}
+
+impl fmt::Display for ParseScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a score", self.name)
+    }
+}
+
+impl Error for ParseScoreError {}
+
+impl FromStr for Score {
+    type Err = ParseScoreError;
+
+    /// Reads a score from the names of `Display`, several names joined with `+` are lexicographic
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::algo::score::Score;
+    /// let score: Score = "new-red-edges+max-red-degree".parse().unwrap();
+    /// assert_eq!(score, Score::Lexicographic(vec![Score::NewRedEdges, Score::MaxRedDegree]));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('+') {
+            return s
+                .split('+')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map(Score::Lexicographic);
+        }
+        match s {
+            "max-red-degree" => Ok(Score::MaxRedDegree),
+            "merged-red-degree" => Ok(Score::MergedRedDegree),
+            "symmetric-difference" => Ok(Score::SymmetricDifference),
+            "new-red-edges" => Ok(Score::NewRedEdges),
+            _ => Err(ParseScoreError {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
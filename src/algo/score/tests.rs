@@ -0,0 +1,87 @@
+use crate::graph::{EdgeColor, Graph, Trigraph};
+
+use super::Score;
+
+/// The path 1 - 2 - 3 - 4 - 5 - 6 after contracting 2 and 4, which leaves the red edges
+/// 2 - 1 and 2 - 5
+fn trigraph() -> Trigraph {
+    let mut trigraph = Trigraph::from(&Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 6),
+    ]));
+    trigraph.contract(2, 4);
+    trigraph
+}
+
+#[test]
+fn test_trigraph() {
+    let trigraph = trigraph();
+
+    assert_eq!(trigraph.get_edge(2, 3), Some(EdgeColor::Black));
+    assert_eq!(trigraph.get_edge(2, 5), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_edge(2, 1), Some(EdgeColor::Red));
+}
+
+#[test]
+fn test_evaluate_single_scores() {
+    let trigraph = trigraph();
+
+    //Contracting 3 into 2 keeps the red edges 2 - 1 and 2 - 5
+    assert_eq!(Score::MaxRedDegree.evaluate(&trigraph, 2, 3), vec![2]);
+    assert_eq!(Score::MergedRedDegree.evaluate(&trigraph, 2, 3), vec![2]);
+    assert_eq!(
+        Score::SymmetricDifference.evaluate(&trigraph, 2, 3),
+        vec![2]
+    );
+    assert_eq!(Score::NewRedEdges.evaluate(&trigraph, 2, 3), vec![0]);
+
+    //Contracting 1 and 6 keeps the red edge to 2 and makes the edge to 5 red
+    assert_eq!(Score::MaxRedDegree.evaluate(&trigraph, 1, 6), vec![2]);
+    assert_eq!(Score::MergedRedDegree.evaluate(&trigraph, 1, 6), vec![2]);
+    assert_eq!(
+        Score::SymmetricDifference.evaluate(&trigraph, 1, 6),
+        vec![2]
+    );
+    assert_eq!(Score::NewRedEdges.evaluate(&trigraph, 1, 6), vec![1]);
+}
+
+#[test]
+fn test_evaluate_lexicographic() {
+    let trigraph = trigraph();
+    let score = Score::Lexicographic(vec![
+        Score::NewRedEdges,
+        Score::Lexicographic(vec![Score::MaxRedDegree, Score::MergedRedDegree]),
+    ]);
+
+    assert_eq!(score.evaluate(&trigraph, 1, 6), vec![1, 2, 2]);
+    assert_eq!(
+        Score::Lexicographic(Vec::new()).evaluate(&trigraph, 1, 6),
+        vec![]
+    );
+}
+
+#[test]
+fn test_display() {
+    let score = Score::Lexicographic(vec![Score::MaxRedDegree, Score::SymmetricDifference]);
+
+    assert_eq!(Score::default().to_string(), "max-red-degree");
+    assert_eq!(score.to_string(), "max-red-degree+symmetric-difference");
+}
+
+#[test]
+fn test_from_str() {
+    let score = Score::Lexicographic(vec![Score::MaxRedDegree, Score::SymmetricDifference]);
+
+    assert_eq!(score.to_string().parse(), Ok(score));
+    assert_eq!("merged-red-degree".parse(), Ok(Score::MergedRedDegree));
+    assert_eq!(
+        "new-red-edges+red"
+            .parse::<Score>()
+            .unwrap_err()
+            .to_string(),
+        "'red' is not a score"
+    );
+}
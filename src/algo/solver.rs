@@ -0,0 +1,271 @@
+//! This module contains the interface of configured algorithms
+//!
+//! `Algo` is one run of an algorithm on one graph, which is created with `new_with_graph`.
+//! A `Solver` is the configuration of an algorithm instead: it is created once, e.g. from
+//! the options of the command line, and then solves any number of graphs. `Solver` is object
+//! safe, so the algorithm can be chosen at runtime as `Box<dyn Solver>`.
+use std::{
+    cmp,
+    error::Error,
+    fmt, panic,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::anytime::{trivial_sequence, BestSoFar, CancellationToken};
+use crate::graph::Graph;
+use crate::sequence::ContractionSequence;
+use crate::verify::{verify, VerifyError};
+
+/// An algorithm together with its configuration
+///
+/// # Examples
+/// ```
+/// use twinwidth::algo::{beam_search::BeamConfig, greedy::GreedyConfig, solver::Solver};
+/// use twinwidth::graph::Graph;
+/// let solvers: Vec<Box<dyn Solver>> = vec![
+///     Box::new(GreedyConfig::default()),
+///     Box::new(BeamConfig::default()),
+/// ];
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// for solver in solvers {
+///     let result = solver.solve(graph.clone()).unwrap();
+///     assert_eq!(result.width, 2);
+/// }
+/// ```
+pub trait Solver: Send + Sync {
+    /// The name of the algorithm, e.g. for tables and diagnostics
+    fn name(&self) -> String;
+
+    /// Solves the graph, publishes every complete sequence which improves on the best one and
+    /// stops as early as the algorithm allows once the token is cancelled
+    ///
+    /// # Returns
+    /// * The best sequence found, which is verified against the graph
+    /// * A `SolveError` if the graph has no contraction sequence or no valid one was found
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError>;
+
+    /// Solves the graph until the algorithm is done
+    fn solve(&self, graph: Graph) -> Result<SolveResult, SolveError> {
+        self.solve_anytime(graph, &BestSoFar::new(), &CancellationToken::new())
+    }
+}
+
+/// Measurements of a run
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SolveStats {
+    /// The time of the run
+    pub time: Duration,
+    /// Restarts of randomized greedy or explored search nodes of branch and bound,
+    /// None for algorithms without iterations
+    pub iterations: Option<u64>,
+    /// The seed which reproduces the sequence, None for deterministic algorithms
+    pub seed: Option<u64>,
+}
+
+/// The result of a `Solver`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SolveResult {
+    pub sequence: ContractionSequence,
+    /// The width of the sequence
+    pub width: usize,
+    /// A lower bound of the twin-width, if the algorithm knows one
+    pub lower_bound: Option<usize>,
+    pub stats: SolveStats,
+    /// Whether the width is proven to be the twin-width
+    pub optimal: bool,
+}
+
+impl SolveResult {
+    /// Creates the result of a run and verifies its sequence
+    ///
+    /// # Parameters
+    /// * graph: The graph of the run
+    /// * sequence: The sequence the algorithm found
+    /// * lower_bound: A lower bound the algorithm knows, width 0 is always optimal
+    /// * start: The start of the run
+    /// * iterations: The iterations of the run, if the algorithm has any
+    ///
+    /// # Returns
+    /// * A `SolveError::InvalidSequence` if the sequence is not valid for the graph
+    pub fn new(
+        graph: &Graph,
+        sequence: &[(u32, u32)],
+        lower_bound: Option<usize>,
+        start: Instant,
+        iterations: Option<u64>,
+    ) -> Result<Self, SolveError> {
+        let width = verify(graph, sequence).map_err(SolveError::InvalidSequence)?;
+        let lower_bound = match lower_bound {
+            Some(bound) => Some(cmp::min(bound, width)),
+            None if width == 0 => Some(0),
+            None => None,
+        };
+        Ok(SolveResult {
+            sequence: ContractionSequence::from(sequence.to_vec()),
+            width,
+            lower_bound,
+            stats: SolveStats {
+                time: start.elapsed(),
+                iterations,
+                seed: None,
+            },
+            optimal: lower_bound == Some(width),
+        })
+    }
+
+    /// Records the seed which reproduces the sequence of a randomized algorithm
+    pub fn with_seed(self, seed: u64) -> Self {
+        let stats = SolveStats {
+            seed: Some(seed),
+            ..self.stats
+        };
+        SolveResult { stats, ..self }
+    }
+}
+
+/// Reasons why a `Solver` has no result
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SolveError {
+    /// The graph has no vertices, so it has no contraction sequence
+    EmptyGraph,
+    /// The token was cancelled before a complete sequence was found
+    Cancelled,
+    /// The algorithm found a sequence which is not valid for the graph
+    InvalidSequence(VerifyError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::EmptyGraph => write!(f, "the graph has no vertices"),
+            SolveError::Cancelled => {
+                write!(
+                    f,
+                    "no contraction sequence was found before the cancellation"
+                )
+            }
+            SolveError::InvalidSequence(err) => {
+                write!(
+                    f,
+                    "the algorithm found an invalid contraction sequence: {err}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::InvalidSequence(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// How long `solve_in_background` waits for a cancelled solver to finish its sequence
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Solves the graph in a background thread until it is done or the token is cancelled.
+///
+/// The trivial sequence is published before the solver starts, so there is a result even if
+/// the token is cancelled before the solver found its first sequence. A cancelled solver gets
+/// a short grace period to complete its sequence, e.g. `Greedy` contracts the remaining nodes.
+/// Algorithms which ignore the token keep running in the background until they are done, but
+/// their result is not awaited after the grace period.
+///
+/// # Parameters
+/// * graph: The graph to solve
+/// * solver: The configured algorithm
+/// * token: Stops waiting for the solver, e.g. at a deadline or on SIGTERM
+///
+/// # Returns
+/// * The result of the solver, or the best sequence published before the token was cancelled
+/// * A `SolveError` if the graph is empty or the solver failed
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use twinwidth::algo::{greedy::GreedyConfig, solver::solve_in_background};
+/// use twinwidth::anytime::CancellationToken;
+/// use twinwidth::graph::Graph;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// let solver = Arc::new(GreedyConfig::default());
+/// let result = solve_in_background(graph, solver, &CancellationToken::new()).unwrap();
+/// assert_eq!(result.width, 2);
+/// ```
+pub fn solve_in_background(
+    graph: Graph,
+    solver: Arc<dyn Solver>,
+    token: &CancellationToken,
+) -> Result<SolveResult, SolveError> {
+    solve_until_cancelled(graph, solver, token, Some(GRACE_PERIOD))
+}
+
+/// Solves like `solve_in_background`, but with a given grace period for the cancelled solver.
+/// Without a grace period the solver is always awaited, so no thread outlives the call.
+pub(crate) fn solve_until_cancelled(
+    graph: Graph,
+    solver: Arc<dyn Solver>,
+    token: &CancellationToken,
+    grace_period: Option<Duration>,
+) -> Result<SolveResult, SolveError> {
+    check_graph(&graph)?;
+    let start = Instant::now();
+    let best_so_far = BestSoFar::new();
+    let (sequence, twin_width) = trivial_sequence(&graph);
+    best_so_far.publish(&sequence, twin_width);
+
+    let handle = {
+        let (graph, best_so_far, token) = (graph.clone(), best_so_far.clone(), token.clone());
+        thread::spawn(move || solver.solve_anytime(graph, &best_so_far, &token))
+    };
+
+    while !token.is_cancelled() && !handle.is_finished() {
+        thread::sleep(Duration::from_millis(5));
+    }
+    let grace_end = grace_period.map(|grace_period| Instant::now() + grace_period);
+    while !handle.is_finished() && grace_end.is_none_or(|grace_end| Instant::now() < grace_end) {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    let finished = handle.is_finished();
+    //The published sequence is always complete
+    let (sequence, published_width) = best_so_far
+        .get()
+        .expect("The trivial sequence was published");
+    let (lower_bound, iterations) = if finished {
+        //A panic of the solver is passed on with its own message
+        match handle
+            .join()
+            .unwrap_or_else(|panic| panic::resume_unwind(panic))
+        {
+            Ok(result) if result.width <= published_width => return Ok(result),
+            //Completing a sequence after a cancellation may be worse than a published one
+            Ok(result) => (result.lower_bound, result.stats.iterations),
+            Err(SolveError::Cancelled) => (None, None),
+            Err(err) => return Err(err),
+        }
+    } else {
+        (None, None)
+    };
+    SolveResult::new(&graph, &sequence, lower_bound, start, iterations)
+}
+
+/// Rejects graphs which no algorithm can solve
+pub(crate) fn check_graph(graph: &Graph) -> Result<(), SolveError> {
+    if graph.get_all_nodes().is_empty() {
+        return Err(SolveError::EmptyGraph);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
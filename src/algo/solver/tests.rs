@@ -0,0 +1,192 @@
+use std::{
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    algo::{
+        beam_search::BeamConfig, branch_and_bound::Budget, greedy::GreedyConfig,
+        greedy_v2::GreedyV2Config, randomized_greedy::RandomizedConfig, sat::SatConfig,
+    },
+    anytime::{BestSoFar, CancellationToken},
+    graph::Graph,
+    local_search::{Improved, LocalSearchConfig},
+    verify::{verify, VerifyError},
+};
+
+use super::{solve_in_background, SolveError, SolveResult, Solver};
+
+fn cycle() -> Graph {
+    Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)])
+}
+
+/// A star with center 5, where the trivial sequence has width 1
+fn star() -> Graph {
+    Graph::from_edges(vec![(5, 1), (5, 2), (5, 3), (5, 4), (5, 6)])
+}
+
+/// Publishes nothing and returns the optimal sequence of the star shortly after the token is
+/// cancelled, or ignores the token and sleeps
+struct Late {
+    reacts: bool,
+}
+
+impl Solver for Late {
+    fn name(&self) -> String {
+        "late".to_string()
+    }
+
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        _best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        let start = Instant::now();
+        if !self.reacts {
+            thread::sleep(Duration::from_secs(5));
+        }
+        while !token.is_cancelled() {
+            thread::sleep(Duration::from_millis(5));
+        }
+        thread::sleep(Duration::from_millis(50));
+        let sequence = [(1, 2), (1, 3), (1, 4), (1, 6), (1, 5)];
+        SolveResult::new(&graph, &sequence, None, start, None)
+    }
+}
+
+fn all_solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(GreedyConfig::default()),
+        Box::new(GreedyV2Config),
+        Box::new(RandomizedConfig::default()),
+        Box::new(BeamConfig::default()),
+        Box::new(Budget::default()),
+        Box::new(SatConfig::default()),
+        Box::new(Improved {
+            solver: Box::new(GreedyConfig::default()),
+            config: LocalSearchConfig::default(),
+        }),
+    ]
+}
+
+#[test]
+fn test_result_new() {
+    let graph = cycle();
+    let sequence = [(1, 6), (2, 3), (4, 5), (1, 2), (1, 4)];
+
+    let result = SolveResult::new(&graph, &sequence, Some(1), Instant::now(), Some(3)).unwrap();
+
+    assert_eq!(result.width, 2);
+    assert_eq!(result.lower_bound, Some(1));
+    assert_eq!(result.stats.iterations, Some(3));
+    assert!(!result.optimal);
+
+    let result = SolveResult::new(&graph, &sequence, Some(2), Instant::now(), None).unwrap();
+    assert!(result.optimal);
+}
+
+#[test]
+fn test_result_new_zero_width_is_optimal() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+
+    let result = SolveResult::new(
+        &graph,
+        &[(1, 3), (2, 4), (1, 2)],
+        None,
+        Instant::now(),
+        None,
+    );
+
+    assert_eq!(result.map(|result| result.optimal), Ok(true));
+}
+
+#[test]
+fn test_result_new_invalid_sequence() {
+    let result = SolveResult::new(&cycle(), &[(1, 2)], None, Instant::now(), None);
+
+    assert_eq!(
+        result,
+        Err(SolveError::InvalidSequence(VerifyError::SequenceTooShort {
+            remaining: 5
+        }))
+    );
+}
+
+#[test]
+fn test_every_solver() {
+    let graph = cycle();
+
+    for solver in all_solvers() {
+        let best_so_far = BestSoFar::new();
+
+        let result = solver
+            .solve_anytime(graph.clone(), &best_so_far, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(result.width, 2, "Solver: {}", solver.name());
+        assert_eq!(verify(&graph, &result.sequence), Ok(2));
+        assert_eq!(best_so_far.get_twin_width(), Some(2));
+    }
+}
+
+#[test]
+fn test_exact_solvers_are_optimal() {
+    let exact: Vec<Box<dyn Solver>> =
+        vec![Box::new(Budget::default()), Box::new(SatConfig::default())];
+
+    for solver in exact {
+        let result = solver.solve(cycle()).unwrap();
+
+        assert!(result.optimal, "Solver: {}", solver.name());
+        assert_eq!(result.lower_bound, Some(2));
+    }
+}
+
+#[test]
+fn test_every_solver_rejects_empty_graph() {
+    for solver in all_solvers() {
+        let result = solver.solve(Graph::from_edges(Vec::new()));
+
+        assert_eq!(
+            result,
+            Err(SolveError::EmptyGraph),
+            "Solver: {}",
+            solver.name()
+        );
+    }
+}
+
+#[test]
+fn test_single_vertex() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+
+    let result = GreedyConfig::default().solve(graph).unwrap();
+
+    assert_eq!(result.width, 0);
+    assert!(result.sequence.is_empty());
+    assert!(result.optimal);
+}
+
+#[test]
+fn test_solve_in_background_waits_for_cancelled_solver() {
+    let token = CancellationToken::new().with_deadline(Instant::now() + Duration::from_millis(50));
+
+    let result = solve_in_background(star(), Arc::new(Late { reacts: true }), &token).unwrap();
+
+    assert_eq!(result.width, 0);
+}
+
+#[test]
+fn test_solve_in_background_falls_back_to_trivial_sequence() {
+    let start = Instant::now();
+    let token = CancellationToken::new().with_deadline(start + Duration::from_millis(50));
+
+    let result = solve_in_background(star(), Arc::new(Late { reacts: false }), &token).unwrap();
+
+    assert_eq!(result.width, 1);
+    assert_eq!(verify(&star(), &result.sequence), Ok(1));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
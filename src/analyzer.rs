@@ -1,55 +1,802 @@
-//! This module contains utility to analyze a given algorithm
-//! Vision: * compare mulitple implementaitons or configurations from various alogrithm
-//!         * provide some kind of probe which can collect metrics from inside of the algorithm
+//! This module contains utility to analyze and compare algorithms
+//!
+//! The instances are solved in parallel on a pool of workers, while a progress line is written
+//! to stderr. The results are recorded in the order of the instances and solvers, regardless of
+//! the order in which the workers finish.
+//!
+//! Vision: * provide some kind of probe which can collect metrics from inside of the algorithm
 //!         * provide some export to excel/python/whatever to furhter analyze the results individaully
-//!         * calculate multiple graphs parralel und give some progress information
-use crate::{algo::Algo, graph::Graph};
-use std::{fs, marker::PhantomData, path::PathBuf, time::Instant};
+use crate::{
+    algo::solver::{solve_until_cancelled, SolveError, SolveResult, Solver},
+    anytime::CancellationToken,
+    bounds::{lower_bound, lower_bound_anytime, BoundConfig},
+    graph::{GrParseError, Graph},
+    sequence::{ContractionSequence, TwwParseError},
+    verify::{verify, VerifyError},
+};
+use std::{
+    cmp,
+    collections::HashMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
 struct Metrics(f32);
 
+/// The result of one instance
+struct Run {
+    filename: String,
+    /// The configuration of the algorithm, empty for runs without one
+    label: String,
+    lower_bound: usize,
+    /// None if the timeout was reached before a sequence was found
+    upper_bound: Option<usize>,
+    /// The seed which reproduces the sequence of a randomized solver
+    seed: Option<u64>,
+    timed_out: bool,
+    metrics: Metrics,
+}
+
+/// Settings for the runs of `Analyzer`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RunConfig {
+    /// Number of instances which are solved at the same time
+    pub workers: usize,
+    /// Maximum time per instance and solver, `None` means unlimited.
+    /// At the timeout the best sequence the solver published so far is recorded, which is at
+    /// least the trivial sequence of `solve_in_background`.
+    /// The lower bound of every instance gets the same time on a worker.
+    /// A job is only done once its solver returned, so a solver which ignores the token keeps
+    /// its worker busy past the timeout. All solvers of this crate stop on the token.
+    pub timeout: Option<Duration>,
+    /// Whether a progress line is written to stderr
+    pub progress: bool,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        RunConfig {
+            workers: thread::available_parallelism().map_or(1, |workers| workers.get()),
+            timeout: None,
+            progress: true,
+        }
+    }
+}
+
+/// Runs solvers on directories of .gr files and compares their results.
+///
+/// Solvers can either be run one by one with `run` and `run_with`, or registered under a name
+/// with `add_solver` and run together with `run_all`.
+///
+/// # Examples
+/// ```no_run
+/// use std::path::PathBuf;
+/// use twinwidth::algo::{beam_search::BeamConfig, greedy::GreedyConfig};
+/// use twinwidth::analyzer::Analyzer;
+/// let mut analyzer = Analyzer::new();
+/// analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+/// analyzer.add_solver("beam", Box::new(BeamConfig::default()));
+/// analyzer.run_all(PathBuf::from("pacechallenge/tiny-set"));
+/// analyzer.show_comparison();
+/// ```
 #[derive(Default)]
-pub struct Analyzer<T: Algo> {
-    runs: Vec<(String, usize, Metrics)>,
-    phantom: PhantomData<T>,
+pub struct Analyzer {
+    runs: Vec<Run>,
+    bounds: BoundConfig,
+    config: RunConfig,
+    solvers: Vec<(String, Arc<dyn Solver>)>,
+}
+
+/// The results of all labels on one instance
+#[derive(PartialEq, Debug, Clone)]
+pub struct InstanceComparison {
+    pub filename: String,
+    pub lower_bound: usize,
+    /// The smallest width of all labels, None if no label found a sequence
+    pub best_width: Option<usize>,
+    /// The labels which reached the best width
+    pub winners: Vec<String>,
+    /// The width of every label, None if the label did not run on the instance or found no
+    /// sequence before the timeout
+    pub widths: Vec<Option<usize>>,
+    /// The time of every label divided by the time of the fastest label
+    pub time_ratios: Vec<Option<f64>>,
+    /// The seed of every label which reproduces its width, None for deterministic labels
+    pub seeds: Vec<Option<u64>>,
+}
+
+/// The results of one label on all instances
+#[derive(PartialEq, Debug, Clone)]
+pub struct LabelSummary {
+    pub label: String,
+    pub total_width: usize,
+    pub total_time: f64,
+    /// Instances on which the label alone reached the best width
+    pub wins: usize,
+    /// Instances on which the label and others reached the best width
+    pub ties: usize,
+    /// Instances on which the label did not reach the best width
+    pub losses: usize,
+    /// Instances on which the label reached the timeout
+    pub timeouts: usize,
+    /// Geometric mean of the time ratios of the label
+    pub mean_time_ratio: f64,
+}
+
+/// The comparison of all labels, see `Analyzer::comparison`
+#[derive(PartialEq, Debug, Clone)]
+pub struct Comparison {
+    pub labels: Vec<String>,
+    pub instances: Vec<InstanceComparison>,
+    pub summaries: Vec<LabelSummary>,
+}
+
+/// Reasons why the instances of a directory or their solutions can not be read
+#[derive(Debug)]
+pub enum InstanceError {
+    /// Reading the directory or a file failed
+    Io { path: PathBuf, err: io::Error },
+    /// A .gr file is not a valid graph
+    Parse { filename: String, err: GrParseError },
+    /// The .tww file of an instance is not a valid contraction sequence
+    Solution {
+        filename: String,
+        err: TwwParseError,
+    },
+    /// The contraction sequence of an instance is not valid for its graph
+    Sequence { filename: String, err: VerifyError },
+}
+
+impl fmt::Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstanceError::Io { path, err } => {
+                write!(f, "can not read {}: {}", path.display(), err)
+            }
+            InstanceError::Parse { filename, err } => write!(f, "{filename}: {err}"),
+            InstanceError::Solution { filename, err } => {
+                write!(f, "solution of {filename}: {err}")
+            }
+            InstanceError::Sequence { filename, err } => {
+                write!(f, "invalid contraction sequence for {filename}: {err}")
+            }
+        }
+    }
+}
+
+impl Error for InstanceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InstanceError::Io { err, .. } => Some(err),
+            InstanceError::Parse { err, .. } => Some(err),
+            InstanceError::Solution { err, .. } => Some(err),
+            InstanceError::Sequence { err, .. } => Some(err),
+        }
+    }
 }
 
-impl<T: Algo> Analyzer<T> {
+impl Analyzer {
     /// New analyzer instance
     pub fn new() -> Self {
+        Self::with_bound_config(BoundConfig::default())
+    }
+
+    /// New analyzer instance which spends the given effort on lower bounds
+    pub fn with_bound_config(bounds: BoundConfig) -> Self {
         Analyzer {
             runs: Vec::new(),
-            phantom: PhantomData,
+            bounds,
+            config: RunConfig::default(),
+            solvers: Vec::new(),
+        }
+    }
+
+    /// Runs the instances with the given workers, timeout and progress
+    ///
+    /// # Panics
+    /// If the number of workers is 0
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use twinwidth::analyzer::{Analyzer, RunConfig};
+    /// let config = RunConfig { workers: 4, timeout: Some(Duration::from_secs(60)), progress: false };
+    /// let analyzer = Analyzer::new().with_run_config(config);
+    /// ```
+    pub fn with_run_config(self, config: RunConfig) -> Self {
+        if config.workers == 0 {
+            panic!("At least one worker is needed");
+        }
+        Analyzer { config, ..self }
+    }
+
+    /// Registers a solver for `run_all`
+    ///
+    /// # Parameters
+    /// * label: The name of the configuration in the comparison
+    /// * solver: The algorithm and its configuration
+    ///
+    /// # Panics
+    /// If the label is already registered
+    pub fn add_solver(&mut self, label: &str, solver: Box<dyn Solver>) {
+        if self.solvers.iter().any(|(other, _)| other == label) {
+            panic!("Label {} is already registered", label);
         }
+        self.solvers.push((label.to_string(), Arc::from(solver)));
+    }
+
+    /// Runs every registered solver on every .gr file of the directory.
+    /// The results are recorded by instance and then in the order of the registration.
+    ///
+    /// # Returns
+    /// * An `InstanceError` if a .gr file can not be read, then nothing is run
+    ///
+    /// # Panics
+    /// If a solver fails on an instance for another reason than the timeout
+    pub fn run_all(&mut self, path: PathBuf) -> Result<(), InstanceError> {
+        let solvers = self.solvers.clone();
+        self.execute(read_instances(&path)?, &solvers);
+        Ok(())
     }
 
-    /// Runs the Analyzer with the given configuration (at the moment the path with .gr files)
-    pub fn run(&mut self, path: PathBuf) {
-        let mut files: Vec<_> = fs::read_dir(path).unwrap().map(|r| r.unwrap()).collect();
-        files.sort_by_key(|file| file.path());
+    /// Runs the solver on every .gr file of the directory, labelled by the name of the solver
+    pub fn run(&mut self, path: PathBuf, solver: Box<dyn Solver>) -> Result<(), InstanceError> {
+        let label = solver.name();
+        self.run_with(path, &label, solver)
+    }
 
-        for file in files {
-            let content = fs::read_to_string(file.path()).unwrap();
+    /// Runs the Analyzer like `run`, but with an own label.
+    /// Running the same path with different labels allows to compare the configurations
+    /// with `show_comparison`.
+    ///
+    /// # Parameters
+    /// * path: The directory with the .gr files
+    /// * label: The name of the configuration
+    /// * solver: The algorithm and its configuration
+    ///
+    /// # Returns
+    /// * An `InstanceError` if a .gr file can not be read, then nothing is run
+    ///
+    /// # Panics
+    /// If the solver fails on an instance for another reason than the timeout
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use twinwidth::algo::{greedy::GreedyConfig, score::Score};
+    /// use twinwidth::analyzer::Analyzer;
+    /// let mut analyzer = Analyzer::new();
+    /// for score in [Score::MaxRedDegree, Score::SymmetricDifference] {
+    ///     let label = score.to_string();
+    ///     let config = GreedyConfig { score };
+    ///     let path = PathBuf::from("pacechallenge/tiny-set");
+    ///     analyzer.run_with(path, &label, Box::new(config)).unwrap();
+    /// }
+    /// analyzer.show_comparison();
+    /// ```
+    pub fn run_with(
+        &mut self,
+        path: PathBuf,
+        label: &str,
+        solver: Box<dyn Solver>,
+    ) -> Result<(), InstanceError> {
+        let solvers = [(label.to_string(), Arc::from(solver))];
+        self.execute(read_instances(&path)?, &solvers);
+        Ok(())
+    }
 
-            let graph = Graph::from_gr(&content);
-            let mut algo = T::new_with_graph(graph);
+    /// Re-scores existing solutions instead of running the algorithm.
+    ///
+    /// For every .gr file in `path` the .tww file with the same name is loaded from `solutions`
+    /// and its width is computed by replaying the contraction sequence.
+    /// The measured time is the time of the verification.
+    ///
+    /// # Returns
+    /// * An `InstanceError` if a .gr or .tww file can not be read or a sequence is not valid for
+    ///   its graph, then nothing is recorded
+    pub fn rescore(&mut self, path: PathBuf, solutions: PathBuf) -> Result<(), InstanceError> {
+        let mut scored = Vec::new();
+        for (filename, graph) in read_instances(&path)? {
+            let solution = solutions.join(Path::new(&filename).with_extension("tww"));
+            let tww = fs::read_to_string(&solution).map_err(|err| InstanceError::Io {
+                path: solution.clone(),
+                err,
+            })?;
+            let sequence =
+                ContractionSequence::from_tww(&tww).map_err(|err| InstanceError::Solution {
+                    filename: filename.clone(),
+                    err,
+                })?;
 
             let now = Instant::now();
-            algo.solve();
+            let width = verify(&graph, &sequence).map_err(|err| InstanceError::Sequence {
+                filename: filename.clone(),
+                err,
+            })?;
             let elapsed = now.elapsed().as_secs_f32();
 
-            let filename = file.file_name().to_str().unwrap().to_string();
+            let run = Run {
+                filename,
+                label: String::new(),
+                lower_bound: 0,
+                upper_bound: Some(width),
+                seed: None,
+                timed_out: false,
+                metrics: Metrics(elapsed),
+            };
+            scored.push((graph, run));
+        }
 
-            self.runs
-                .push((filename, algo.get_max_red_degree(), Metrics(elapsed)));
+        for (graph, run) in scored {
+            let lower_bound = self
+                .known_bound(&run.filename)
+                .unwrap_or_else(|| lower_bound(&graph, &self.bounds));
+            self.push_run(run, lower_bound);
         }
+        Ok(())
     }
 
+    /// Prints filename, lower bound, upper bound, gap and time of every instance.
+    /// The upper bound is the width of the sequence, the gap is the difference of both bounds.
+    /// Runs which reached the timeout are marked, randomized runs are followed by their seed.
     pub fn show_result(&self) {
         println!("{:-<30}", "");
         for run in &self.runs {
-            println!("{}, {}, {}", run.0, run.1, run.2 .0);
+            let (upper_bound, gap) = match run.upper_bound {
+                Some(width) => (width.to_string(), (width - run.lower_bound).to_string()),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            println!(
+                "{}, {}, {}, {}, {}{}{}",
+                run.filename,
+                run.lower_bound,
+                upper_bound,
+                gap,
+                run.metrics.0,
+                if run.timed_out { " (timeout)" } else { "" },
+                run.seed
+                    .map_or(String::new(), |seed| format!(", seed {seed}"))
+            );
         }
         println!("{:-<30}", "");
     }
+
+    /// Compares all labels on all instances, in the order in which they were run first
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use twinwidth::algo::{greedy::GreedyConfig, greedy_v2::GreedyV2Config};
+    /// use twinwidth::analyzer::Analyzer;
+    /// let mut analyzer = Analyzer::new();
+    /// analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+    /// analyzer.add_solver("greedy-v2", Box::new(GreedyV2Config));
+    /// analyzer.run_all(PathBuf::from("pacechallenge/tiny-set"));
+    /// for summary in analyzer.comparison().summaries {
+    ///     println!("{}: {} wins", summary.label, summary.wins);
+    /// }
+    /// ```
+    pub fn comparison(&self) -> Comparison {
+        let mut labels: Vec<String> = Vec::new();
+        let mut filenames: Vec<&str> = Vec::new();
+        for run in &self.runs {
+            if !labels.contains(&run.label) {
+                labels.push(run.label.clone());
+            }
+            if !filenames.contains(&run.filename.as_str()) {
+                filenames.push(&run.filename);
+            }
+        }
+        let results: HashMap<(&str, &str), &Run> = self
+            .runs
+            .iter()
+            .map(|run| ((run.filename.as_str(), run.label.as_str()), run))
+            .collect();
+
+        let mut summaries: Vec<LabelSummary> = labels
+            .iter()
+            .map(|label| LabelSummary {
+                label: label.clone(),
+                total_width: 0,
+                total_time: 0.0,
+                wins: 0,
+                ties: 0,
+                losses: 0,
+                timeouts: 0,
+                mean_time_ratio: 0.0,
+            })
+            .collect();
+        let mut log_ratios = vec![(0.0, 0); labels.len()];
+
+        let instances = filenames
+            .into_iter()
+            .map(|filename| {
+                let runs: Vec<Option<&Run>> = labels
+                    .iter()
+                    .map(|label| results.get(&(filename, label.as_str())).copied())
+                    .collect();
+                let present = runs.iter().flatten();
+                let best_width = present.clone().filter_map(|run| run.upper_bound).min();
+                //Times of zero would make every ratio infinite
+                let fastest = present
+                    .clone()
+                    .map(|run| f64::from(run.metrics.0).max(MIN_TIME))
+                    .fold(f64::INFINITY, f64::min);
+                let lower_bound = present.map(|run| run.lower_bound).max().unwrap();
+
+                let widths: Vec<Option<usize>> = runs
+                    .iter()
+                    .map(|run| run.and_then(|run| run.upper_bound))
+                    .collect();
+                let winners: Vec<String> = labels
+                    .iter()
+                    .zip(&widths)
+                    .filter(|(_, width)| width.is_some() && **width == best_width)
+                    .map(|(label, _)| label.clone())
+                    .collect();
+                let time_ratios: Vec<Option<f64>> = runs
+                    .iter()
+                    .map(|run| run.map(|run| f64::from(run.metrics.0).max(MIN_TIME) / fastest))
+                    .collect();
+                let seeds: Vec<Option<u64>> = runs
+                    .iter()
+                    .map(|run| run.and_then(|run| run.seed))
+                    .collect();
+
+                for (index, run) in runs.iter().enumerate() {
+                    let Some(run) = run else { continue };
+                    let summary = &mut summaries[index];
+                    summary.total_width += run.upper_bound.unwrap_or(0);
+                    summary.total_time += f64::from(run.metrics.0);
+                    if run.timed_out {
+                        summary.timeouts += 1;
+                    }
+                    if run.upper_bound.is_none() || run.upper_bound > best_width {
+                        summary.losses += 1;
+                    } else if winners.len() == 1 {
+                        summary.wins += 1;
+                    } else {
+                        summary.ties += 1;
+                    }
+                    let ratio = time_ratios[index].expect("The label ran on the instance");
+                    log_ratios[index].0 += ratio.ln();
+                    log_ratios[index].1 += 1;
+                }
+
+                InstanceComparison {
+                    filename: filename.to_string(),
+                    lower_bound,
+                    best_width,
+                    winners,
+                    widths,
+                    time_ratios,
+                    seeds,
+                }
+            })
+            .collect();
+
+        for (summary, (sum, count)) in summaries.iter_mut().zip(log_ratios) {
+            summary.mean_time_ratio = (sum / count as f64).exp();
+        }
+
+        Comparison {
+            labels,
+            instances,
+            summaries,
+        }
+    }
+
+    /// Prints one line per instance with the width, the time ratio and the seed of every label,
+    /// the best width and the labels which reached it. At the end the total width and time, the
+    /// wins, ties and losses and the mean time ratio of every label are printed.
+    pub fn show_comparison(&self) {
+        let comparison = self.comparison();
+
+        println!("{:-<30}", "");
+        println!("file, lb, {}, best, winner", comparison.labels.join(", "));
+        for instance in &comparison.instances {
+            let results: Vec<String> = instance
+                .widths
+                .iter()
+                .zip(&instance.time_ratios)
+                .zip(&instance.seeds)
+                .map(|(result, seed)| {
+                    let seed = seed.map_or(String::new(), |seed| format!(", seed {seed}"));
+                    match result {
+                        (Some(width), Some(ratio)) => format!("{} ({:.2}x{})", width, ratio, seed),
+                        (None, Some(ratio)) => format!("timeout ({:.2}x)", ratio),
+                        _ => "-".to_string(),
+                    }
+                })
+                .collect();
+            let best_width = instance
+                .best_width
+                .map_or("-".to_string(), |width| width.to_string());
+            println!(
+                "{}, {}, {}, {}, {}",
+                instance.filename,
+                instance.lower_bound,
+                results.join(", "),
+                best_width,
+                instance.winners.join("/")
+            );
+        }
+        println!("{:-<30}", "");
+        for summary in &comparison.summaries {
+            println!(
+                "{}: total width {}, total time {}, wins {}, ties {}, losses {}, timeouts {}, mean time ratio {:.2}x",
+                summary.label,
+                summary.total_width,
+                summary.total_time,
+                summary.wins,
+                summary.ties,
+                summary.losses,
+                summary.timeouts,
+                summary.mean_time_ratio
+            );
+        }
+        println!("{:-<30}", "");
+    }
+
+    /// Bounds every new instance and solves every instance with every solver on the workers.
+    /// The runs are recorded in the order of the instances and then of the solvers.
+    fn execute(&mut self, instances: Vec<(String, Graph)>, solvers: &[(String, Arc<dyn Solver>)]) {
+        //Every configuration runs on the same instances, so the bound is computed only once
+        let mut bounds: Vec<Option<usize>> = instances
+            .iter()
+            .map(|(filename, _)| self.known_bound(filename))
+            .collect();
+        let jobs: Vec<Job> = (0..instances.len())
+            .filter(|instance| bounds[*instance].is_none())
+            .map(Job::Bound)
+            .chain((0..instances.len()).flat_map(|instance| {
+                (0..solvers.len()).map(move |solver| Job::Solve(instance, solver))
+            }))
+            .collect();
+        let mut results: Vec<Option<JobResult>> = (0..jobs.len()).map(|_| None).collect();
+        let (timeout, progress) = (self.config.timeout, self.config.progress);
+        let bound_config = &self.bounds;
+        let workers = cmp::min(self.config.workers, jobs.len());
+
+        let start = Instant::now();
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let sender = sender.clone();
+                let (jobs, instances, next) = (&jobs, &instances, &next);
+                scope.spawn(move || loop {
+                    let job = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(&current) = jobs.get(job) else {
+                        return;
+                    };
+                    sender.send(Message::Started(job)).unwrap();
+                    let result = match current {
+                        Job::Bound(instance) => {
+                            let graph = &instances[instance].1;
+                            JobResult::Bound(bound(graph, bound_config, timeout))
+                        }
+                        Job::Solve(instance, solver) => JobResult::Solve(solve(
+                            &instances[instance].1,
+                            &solvers[solver].1,
+                            timeout,
+                        )),
+                    };
+                    sender.send(Message::Finished(job, result)).unwrap();
+                });
+            }
+            //The loop ends once every worker has dropped its sender
+            drop(sender);
+
+            let (mut done, mut current, mut width) = (0, String::new(), 0);
+            for message in receiver {
+                match message {
+                    Message::Started(job) => {
+                        current = match jobs[job] {
+                            Job::Bound(instance) => {
+                                format!("{} (lower bound)", instances[instance].0)
+                            }
+                            Job::Solve(instance, solver) => {
+                                format!("{} ({})", instances[instance].0, solvers[solver].0)
+                            }
+                        };
+                    }
+                    Message::Finished(job, result) => {
+                        results[job] = Some(result);
+                        done += 1;
+                    }
+                }
+                if progress {
+                    let line = progress_line(done, jobs.len(), &current, start.elapsed());
+                    //Pad with spaces to overwrite a longer previous line
+                    eprint!("\r{:<width$}", line);
+                    width = cmp::max(width, line.len());
+                }
+            }
+            if progress {
+                eprintln!();
+            }
+        });
+
+        let mut outcomes = Vec::new();
+        for (job, result) in jobs.into_iter().zip(results) {
+            match (job, result.expect("Every job is finished")) {
+                (Job::Bound(instance), JobResult::Bound(bound)) => bounds[instance] = Some(bound),
+                (Job::Solve(instance, solver), JobResult::Solve(outcome)) => {
+                    outcomes.push((instance, solver, outcome))
+                }
+                _ => unreachable!("Every job has a result of its kind"),
+            }
+        }
+
+        for (instance, solver, outcome) in outcomes {
+            let filename = &instances[instance].0;
+            let label = &solvers[solver].0;
+            let (width, solver_bound, seed) = match outcome.result {
+                Ok(result) => (Some(result.width), result.lower_bound, result.stats.seed),
+                Err(SolveError::Cancelled) => (None, None, None),
+                Err(err) => panic!("{} failed for {}: {}", label, filename, err),
+            };
+            let run = Run {
+                filename: filename.clone(),
+                label: label.clone(),
+                lower_bound: solver_bound.unwrap_or(0),
+                upper_bound: width,
+                seed,
+                timed_out: outcome.timed_out,
+                metrics: Metrics(outcome.elapsed),
+            };
+            let lower_bound = bounds[instance].expect("Every instance is bounded");
+            self.push_run(run, lower_bound);
+        }
+    }
+
+    /// The lower bound of an instance which was already run
+    fn known_bound(&self, filename: &str) -> Option<usize> {
+        self.runs
+            .iter()
+            .find(|run| run.filename == filename)
+            .map(|run| run.lower_bound)
+    }
+
+    /// Records a run together with the lower bound of the bounds module, which is not part of
+    /// the measured time. The lower bound of the run is the bound the solver knows, which may be
+    /// better than the one of the bounds module.
+    fn push_run(&mut self, mut run: Run, lower_bound: usize) {
+        run.lower_bound = cmp::max(lower_bound, run.lower_bound);
+        //Bounds are heuristic, but a lower bound above a verified width would be a bug
+        if let Some(width) = run.upper_bound {
+            assert!(
+                run.lower_bound <= width,
+                "Lower bound {} exceeds the width {} of {}",
+                run.lower_bound,
+                width,
+                run.filename
+            );
+        }
+
+        self.runs.push(run);
+    }
 }
+
+/// Times below are counted as this time in the ratios
+const MIN_TIME: f64 = 1e-6;
+
+/// The result of one solver on one instance, as computed by a worker
+#[derive(Clone)]
+struct Outcome {
+    result: Result<SolveResult, SolveError>,
+    timed_out: bool,
+    elapsed: f32,
+}
+
+/// The work of a worker
+#[derive(Clone, Copy)]
+enum Job {
+    /// Computes the lower bound of an instance
+    Bound(usize),
+    /// Solves an instance with a solver
+    Solve(usize, usize),
+}
+
+/// The result of a `Job` of the same kind
+enum JobResult {
+    Bound(usize),
+    Solve(Outcome),
+}
+
+/// Messages from the workers to the progress line
+enum Message {
+    Started(usize),
+    Finished(usize, JobResult),
+}
+
+/// Computes the lower bound of an instance, stopped at the timeout
+fn bound(graph: &Graph, config: &BoundConfig, timeout: Option<Duration>) -> usize {
+    let token = match timeout {
+        Some(timeout) => CancellationToken::new().with_deadline(Instant::now() + timeout),
+        None => CancellationToken::new(),
+    };
+    lower_bound_anytime(graph, config, &token)
+}
+
+/// Solves an instance, with a timeout in a background thread which is awaited after the timeout
+fn solve(graph: &Graph, solver: &Arc<dyn Solver>, timeout: Option<Duration>) -> Outcome {
+    let start = Instant::now();
+    let (result, timed_out) = match timeout {
+        Some(timeout) => {
+            let token = CancellationToken::new().with_deadline(start + timeout);
+            let result = solve_until_cancelled(graph.clone(), Arc::clone(solver), &token, None);
+            (result, token.is_cancelled())
+        }
+        None => (solver.solve(graph.clone()), false),
+    };
+    let elapsed = match &result {
+        Ok(result) => result.stats.time.as_secs_f32(),
+        Err(_) => start.elapsed().as_secs_f32(),
+    };
+    Outcome {
+        result,
+        timed_out,
+        elapsed,
+    }
+}
+
+/// The progress line, e.g. `[3/10] tiny003.gr (greedy), ETA 4s`
+///
+/// # Parameters
+/// * done: The number of finished jobs
+/// * total: The number of all jobs
+/// * current: The job which was started last
+/// * elapsed: The time since the first job was started
+fn progress_line(done: usize, total: usize, current: &str, elapsed: Duration) -> String {
+    let eta = if done == 0 {
+        "?".to_string()
+    } else {
+        let remaining = elapsed.as_secs_f64() / done as f64 * (total - done) as f64;
+        format!("{}s", remaining.ceil())
+    };
+    format!("[{}/{}] {}, ETA {}", done, total, current, eta)
+}
+
+/// Reads all .gr files of the directory, sorted by their filename. Other files are ignored.
+fn read_instances(path: &Path) -> Result<Vec<(String, Graph)>, InstanceError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |err| InstanceError::Io { path, err }
+    };
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path).map_err(io_error(path))? {
+        let file = entry.map_err(io_error(path))?.path();
+        if file.extension().is_some_and(|extension| extension == "gr") {
+            files.push(file);
+        }
+    }
+    files.sort();
+
+    files
+        .into_iter()
+        .map(|file| {
+            let content = fs::read_to_string(&file).map_err(io_error(&file))?;
+            let filename = file
+                .file_name()
+                .expect("A file of a directory has a name")
+                .to_string_lossy()
+                .to_string();
+            match Graph::try_from_gr(&content) {
+                Ok(graph) => Ok((filename, graph)),
+                Err(err) => Err(InstanceError::Parse { filename, err }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,452 @@
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    algo::{
+        greedy::GreedyConfig,
+        randomized_greedy::RandomizedConfig,
+        solver::{SolveError, SolveResult, Solver},
+    },
+    anytime::{trivial_sequence, BestSoFar, CancellationToken},
+    generate::Family,
+    graph::Graph,
+    sequence::ContractionSequence,
+};
+
+use super::{progress_line, Analyzer, InstanceError, RunConfig};
+
+/// Contracts all nodes into the smallest one, which is valid but ignores the width
+struct Naive;
+
+impl Solver for Naive {
+    fn name(&self) -> String {
+        "naive".to_string()
+    }
+
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        _best_so_far: &BestSoFar,
+        _token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        let mut nodes = graph.get_all_nodes();
+        nodes.sort();
+        let sequence: Vec<(u32, u32)> =
+            nodes.iter().skip(1).map(|node| (nodes[0], *node)).collect();
+        SolveResult::new(&graph, &sequence, None, Instant::now(), None)
+    }
+}
+
+/// The number of `Sleepy` solvers which are running right now and at most
+#[derive(Default)]
+struct Activity {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// Sleeps before it solves like `Naive`, optionally after publishing the sequence of `Naive`.
+/// A cancellation ends the sleep without a result.
+struct Sleepy {
+    time: Duration,
+    publish: bool,
+    activity: Arc<Activity>,
+}
+
+impl Solver for Sleepy {
+    fn name(&self) -> String {
+        "sleepy".to_string()
+    }
+
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        let running = self.activity.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.activity.peak.fetch_max(running, Ordering::SeqCst);
+        let result = self.sleep(graph, best_so_far, token);
+        self.activity.running.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+}
+
+impl Sleepy {
+    fn sleep(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        let result = Naive.solve_anytime(graph, best_so_far, token)?;
+        if self.publish {
+            best_so_far.publish(&result.sequence, result.width);
+        }
+        let start = Instant::now();
+        while start.elapsed() < self.time {
+            if token.is_cancelled() {
+                return Err(SolveError::Cancelled);
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        Ok(result)
+    }
+}
+
+fn quiet(workers: usize, timeout: Option<Duration>) -> RunConfig {
+    RunConfig {
+        workers,
+        timeout,
+        progress: false,
+    }
+}
+
+/// A fresh directory of a test, which is deleted with its files at the end of the test
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(test: &str) -> Self {
+        let directory =
+            env::temp_dir().join(format!("twinwidth-analyzer-{test}-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        TempDir(directory)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        //A failed cleanup must not hide the result of the test
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Writes a path, on which both solvers find width 1, and a star, on which greedy is better
+fn instances(test: &str) -> TempDir {
+    let directory = TempDir::new(test);
+    fs::write(
+        directory.join("a-path.gr"),
+        Family::Path(6).generate(0).to_gr(),
+    )
+    .unwrap();
+    fs::write(
+        directory.join("b-star.gr"),
+        Family::CompleteBipartite(1, 5).generate(0).to_gr(),
+    )
+    .unwrap();
+    directory
+}
+
+#[test]
+fn test_run_all_comparison() {
+    let mut analyzer = Analyzer::new();
+    analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+    analyzer.add_solver("naive", Box::new(Naive));
+
+    let directory = instances("run-all");
+
+    analyzer.run_all(directory.to_path_buf()).unwrap();
+    let comparison = analyzer.comparison();
+
+    assert_eq!(comparison.labels, vec!["greedy", "naive"]);
+    let path = &comparison.instances[0];
+    assert_eq!(path.filename, "a-path.gr");
+    assert_eq!(path.best_width, Some(1));
+    assert_eq!(path.widths, vec![Some(1), Some(1)]);
+    assert_eq!(path.winners, vec!["greedy", "naive"]);
+
+    let star = &comparison.instances[1];
+    assert_eq!(star.winners, vec!["greedy"]);
+    assert!(star.widths[1] > star.widths[0]);
+    assert!(Some(star.lower_bound) <= star.best_width);
+
+    let greedy = &comparison.summaries[0];
+    let naive = &comparison.summaries[1];
+    assert_eq!((greedy.wins, greedy.ties, greedy.losses), (1, 1, 0));
+    assert_eq!((naive.wins, naive.ties, naive.losses), (0, 1, 1));
+    assert!(naive.total_width > greedy.total_width);
+}
+
+#[test]
+fn test_run_ignores_other_files() {
+    let directory = instances("other-files");
+    fs::write(directory.join("notes.txt"), "not a graph").unwrap();
+    let mut analyzer = Analyzer::new();
+
+    analyzer
+        .run(directory.to_path_buf(), Box::new(Naive))
+        .unwrap();
+
+    assert_eq!(analyzer.runs.len(), 2);
+}
+
+#[test]
+fn test_run_invalid_instance() {
+    let directory = instances("invalid");
+    fs::write(directory.join("c-broken.gr"), "p tww 3 2\n1 2\n").unwrap();
+    let mut analyzer = Analyzer::new();
+
+    let result = analyzer.run(directory.to_path_buf(), Box::new(Naive));
+
+    assert!(matches!(
+        result,
+        Err(InstanceError::Parse { filename, .. }) if filename == "c-broken.gr"
+    ));
+    assert!(analyzer.runs.is_empty());
+}
+
+#[test]
+fn test_seeds() {
+    let mut analyzer = Analyzer::new();
+    analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+    let randomized = RandomizedConfig {
+        seed: 7,
+        restarts: Some(1),
+        ..RandomizedConfig::default()
+    };
+    analyzer.add_solver("randomized", Box::new(randomized));
+
+    let directory = instances("seeds");
+
+    analyzer.run_all(directory.to_path_buf()).unwrap();
+    let comparison = analyzer.comparison();
+
+    for instance in &comparison.instances {
+        assert_eq!(instance.seeds, vec![None, Some(7)]);
+    }
+}
+
+/// Writes the trivial sequence of every instance as .tww file next to it
+fn trivial_solutions(directory: &Path) {
+    for instance in ["a-path", "b-star"] {
+        let graph = Graph::try_from_gr(
+            &fs::read_to_string(directory.join(format!("{instance}.gr"))).unwrap(),
+        )
+        .unwrap();
+        let sequence = ContractionSequence::from(trivial_sequence(&graph).0);
+        fs::write(directory.join(format!("{instance}.tww")), sequence.to_tww()).unwrap();
+    }
+}
+
+#[test]
+fn test_rescore() {
+    let directory = instances("rescore");
+    trivial_solutions(&directory);
+    let mut analyzer = Analyzer::new();
+
+    analyzer
+        .rescore(directory.to_path_buf(), directory.to_path_buf())
+        .unwrap();
+
+    //The .tww files are no instances
+    assert_eq!(analyzer.runs.len(), 2);
+    assert_eq!(analyzer.runs[0].upper_bound, Some(1));
+    assert_eq!(analyzer.runs[1].upper_bound, Some(4));
+}
+
+#[test]
+fn test_rescore_invalid_solutions() {
+    let directory = instances("rescore-invalid");
+    let mut analyzer = Analyzer::new();
+
+    let result = analyzer.rescore(directory.to_path_buf(), directory.to_path_buf());
+    assert!(matches!(result, Err(InstanceError::Io { .. })));
+
+    trivial_solutions(&directory);
+    fs::write(directory.join("b-star.tww"), "1 2\n").unwrap();
+    let result = analyzer.rescore(directory.to_path_buf(), directory.to_path_buf());
+    assert!(matches!(
+        result,
+        Err(InstanceError::Sequence { filename, .. }) if filename == "b-star.gr"
+    ));
+
+    fs::write(directory.join("b-star.tww"), "1 x\n").unwrap();
+    let result = analyzer.rescore(directory.to_path_buf(), directory.to_path_buf());
+    assert!(matches!(result, Err(InstanceError::Solution { .. })));
+    assert!(analyzer.runs.is_empty());
+}
+
+#[test]
+fn test_time_ratios() {
+    let mut analyzer = Analyzer::new();
+    analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+    analyzer.add_solver("naive", Box::new(Naive));
+
+    let directory = instances("ratios");
+
+    analyzer.run_all(directory.to_path_buf()).unwrap();
+    let comparison = analyzer.comparison();
+
+    for instance in &comparison.instances {
+        let ratios: Vec<f64> = instance.time_ratios.iter().flatten().copied().collect();
+        assert_eq!(ratios.len(), 2);
+        assert!(ratios.iter().all(|ratio| *ratio >= 1.0));
+        assert!(ratios.contains(&1.0));
+    }
+    for summary in &comparison.summaries {
+        assert!(summary.mean_time_ratio >= 1.0);
+    }
+}
+
+#[test]
+fn test_run_with_labels_missing_instances() {
+    let directory = instances("labels");
+    let single = TempDir::new("single");
+    fs::copy(directory.join("b-star.gr"), single.join("b-star.gr")).unwrap();
+    let mut analyzer = Analyzer::new();
+
+    analyzer
+        .run(directory.to_path_buf(), Box::new(GreedyConfig::default()))
+        .unwrap();
+    analyzer
+        .run_with(single.to_path_buf(), "only star", Box::new(Naive))
+        .unwrap();
+    let comparison = analyzer.comparison();
+
+    assert_eq!(comparison.labels, vec!["greedy", "only star"]);
+    assert_eq!(comparison.instances[0].widths[1], None);
+    assert_eq!(comparison.instances[0].time_ratios[1], None);
+    assert_eq!(comparison.instances[0].winners, vec!["greedy"]);
+    assert_eq!(comparison.summaries[1].losses, 1);
+    assert_eq!(
+        comparison.summaries[1].wins + comparison.summaries[1].ties,
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Label greedy is already registered")]
+fn test_add_solver_twice() {
+    let mut analyzer = Analyzer::new();
+    analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+    analyzer.add_solver("greedy", Box::new(Naive));
+}
+
+#[test]
+fn test_parallel_order_is_deterministic() {
+    let directory = instances("parallel");
+    for index in 0..6 {
+        let graph = Family::Random(8, 0.5).generate(index);
+        fs::write(
+            directory.join(format!("c-random-{index}.gr")),
+            graph.to_gr(),
+        )
+        .unwrap();
+    }
+    let mut sequential = Analyzer::new().with_run_config(quiet(1, None));
+    let mut parallel = Analyzer::new().with_run_config(quiet(4, None));
+    for analyzer in [&mut sequential, &mut parallel] {
+        analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+        analyzer.add_solver(
+            "sleepy",
+            Box::new(Sleepy {
+                time: Duration::from_millis(20),
+                publish: false,
+                activity: Arc::default(),
+            }),
+        );
+        analyzer.add_solver("naive", Box::new(Naive));
+    }
+
+    sequential.run_all(directory.to_path_buf()).unwrap();
+    parallel.run_all(directory.to_path_buf()).unwrap();
+
+    let key = |analyzer: &Analyzer| -> Vec<(String, String, Option<usize>)> {
+        analyzer
+            .runs
+            .iter()
+            .map(|run| (run.filename.clone(), run.label.clone(), run.upper_bound))
+            .collect()
+    };
+    assert_eq!(parallel.runs.len(), 8 * 3);
+    assert_eq!(key(&parallel), key(&sequential));
+    assert_eq!(parallel.runs[0].filename, "a-path.gr");
+    assert_eq!(parallel.runs[2].label, "naive");
+}
+
+#[test]
+fn test_timeout() {
+    let mut analyzer = Analyzer::new().with_run_config(quiet(2, Some(Duration::from_millis(50))));
+    let activity = Arc::new(Activity::default());
+    let slow = |publish| {
+        Box::new(Sleepy {
+            time: Duration::from_secs(2),
+            publish,
+            activity: Arc::clone(&activity),
+        })
+    };
+    analyzer.add_solver("published", slow(true));
+    analyzer.add_solver("silent", slow(false));
+    analyzer.add_solver("greedy", Box::new(GreedyConfig::default()));
+
+    let directory = instances("timeout");
+
+    let start = Instant::now();
+    analyzer.run_all(directory.to_path_buf()).unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(2));
+    //Every timed out solver was awaited, so no worker took a new job while one was still running
+    assert_eq!(activity.running.load(Ordering::SeqCst), 0);
+    assert!(activity.peak.load(Ordering::SeqCst) <= 2);
+    let comparison = analyzer.comparison();
+    let path = &comparison.instances[0];
+    //The silent solver is credited with the trivial sequence published before it started
+    assert_eq!(path.widths, vec![Some(1), Some(1), Some(1)]);
+    assert_eq!(path.winners, vec!["published", "silent", "greedy"]);
+
+    let silent = &comparison.summaries[1];
+    assert_eq!((silent.timeouts, silent.losses), (2, 1));
+    assert_eq!(comparison.summaries[0].timeouts, 2);
+    assert_eq!(comparison.summaries[2].timeouts, 0);
+}
+
+#[test]
+fn test_timeout_covers_lower_bound() {
+    let directory = TempDir::new("bound-timeout");
+    fs::write(
+        directory.join("random.gr"),
+        Family::Random(300, 0.1).generate(3).to_gr(),
+    )
+    .unwrap();
+    let mut analyzer = Analyzer::new().with_run_config(quiet(1, Some(Duration::from_millis(100))));
+
+    let start = Instant::now();
+    analyzer
+        .run(directory.to_path_buf(), Box::new(Naive))
+        .unwrap();
+
+    //Without the timeout the bound alone takes seconds
+    assert!(start.elapsed() < Duration::from_secs(3));
+    assert_eq!(analyzer.runs.len(), 1);
+}
+
+#[test]
+fn test_progress_line() {
+    let line = progress_line(0, 4, "a.gr (greedy)", Duration::from_secs(1));
+    assert_eq!(line, "[0/4] a.gr (greedy), ETA ?");
+
+    let line = progress_line(1, 4, "b.gr (greedy)", Duration::from_secs(2));
+    assert_eq!(line, "[1/4] b.gr (greedy), ETA 6s");
+}
+
+#[test]
+#[should_panic(expected = "At least one worker is needed")]
+fn test_no_workers() {
+    Analyzer::new().with_run_config(quiet(0, None));
+}
@@ -0,0 +1,238 @@
+//! This module contains the support for anytime solving
+//!
+//! The heuristic track of the pace challenge sends SIGTERM at the deadline and expects the best
+//! sequence found so far on stdout. Anytime algorithms publish every complete sequence which
+//! improves on their best one to a `BestSoFar` and stop as soon as their `CancellationToken` is
+//! cancelled or its deadline has passed. Another thread can print the published sequence at
+//! any time.
+use std::{
+    cmp,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::algo::Algo;
+use crate::graph::{Graph, Trigraph};
+use crate::sequence::ContractionSequence;
+
+/// Tells an algorithm to stop, either on request or at a deadline.
+/// All clones share the request.
+///
+/// # Examples
+/// ```
+/// use std::time::{Duration, Instant};
+/// use twinwidth::anytime::CancellationToken;
+/// let token = CancellationToken::new();
+/// let clone = token.clone();
+/// clone.cancel();
+/// assert!(token.is_cancelled());
+///
+/// let token = CancellationToken::new().with_deadline(Instant::now() + Duration::from_secs(60));
+/// assert!(!token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// Creates a token which is only cancelled on request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token additionally at the deadline, an earlier deadline of the token is kept
+    pub fn with_deadline(self, deadline: Instant) -> Self {
+        CancellationToken {
+            deadline: Some(
+                self.deadline
+                    .map_or(deadline, |own| cmp::min(own, deadline)),
+            ),
+            ..self
+        }
+    }
+
+    /// Cancels the token and all of its clones
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the token was cancelled or its deadline has passed
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Tokens are equal if they share their request and have the same deadline
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled) && self.deadline == other.deadline
+    }
+}
+
+impl Eq for CancellationToken {}
+
+/// The best complete contraction sequence published so far.
+/// All clones share the sequence.
+///
+/// # Examples
+/// ```
+/// use twinwidth::anytime::BestSoFar;
+/// let best = BestSoFar::new();
+/// assert!(best.publish(&[(1, 2), (1, 3)], 1));
+/// assert!(!best.publish(&[(2, 1), (2, 3)], 1));
+/// assert_eq!(best.get_twin_width(), Some(1));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BestSoFar {
+    best: Arc<Mutex<Option<(ContractionSequence, usize)>>>,
+}
+
+impl BestSoFar {
+    /// Creates an empty `BestSoFar`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the sequence if the new one has a smaller width
+    ///
+    /// # Parameters
+    /// * sequence: A complete contraction sequence
+    /// * twin_width: The width of the sequence
+    ///
+    /// # Returns
+    /// * Whether the sequence was replaced
+    pub fn publish(&self, sequence: &[(u32, u32)], twin_width: usize) -> bool {
+        let mut best = self.best.lock().unwrap();
+        if best.as_ref().is_some_and(|(_, width)| *width <= twin_width) {
+            return false;
+        }
+        *best = Some((ContractionSequence::from(sequence.to_vec()), twin_width));
+        true
+    }
+
+    /// Gets the best sequence and its width, None if nothing was published yet
+    pub fn get(&self) -> Option<(ContractionSequence, usize)> {
+        self.best.lock().unwrap().clone()
+    }
+
+    /// Gets the width of the best sequence, None if nothing was published yet
+    pub fn get_twin_width(&self) -> Option<usize> {
+        self.best.lock().unwrap().as_ref().map(|(_, width)| *width)
+    }
+}
+
+/// An algorithm which can be stopped early and publishes its intermediate results
+pub trait Anytime: Algo {
+    /// Solves like `Algo::solve`, but publishes every improvement and stops early once the token
+    /// is cancelled. A cancelled run still returns a complete, but probably worse, sequence.
+    fn solve_anytime(
+        &mut self,
+        best: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (Vec<(u32, u32)>, usize);
+}
+
+/// Contracts all remaining nodes into the smallest one, which is fast but ignores the width
+///
+/// # Returns
+/// * The max red degree of these contractions
+pub(crate) fn contract_rest(trigraph: &mut Trigraph, sequence: &mut Vec<(u32, u32)>) -> usize {
+    let mut nodes = trigraph.get_all_nodes();
+    nodes.sort();
+    let mut twin_width = 0;
+    for node in nodes.iter().skip(1) {
+        twin_width = cmp::max(twin_width, trigraph.contract(nodes[0], *node));
+        sequence.push((nodes[0], *node));
+    }
+    twin_width
+}
+
+/// Contracts every vertex of the graph into the smallest one.
+///
+/// The sequence is valid for every graph with vertices, so it can be published before an
+/// algorithm starts, which then has a sequence to print even if it is cancelled right away.
+///
+/// # Returns
+/// * The sequence and its width
+///
+/// # Examples
+/// ```
+/// use twinwidth::anytime::trivial_sequence;
+/// use twinwidth::graph::Graph;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+/// assert_eq!(trivial_sequence(&graph), (vec![(1, 2), (1, 3), (1, 4)], 1));
+/// ```
+pub fn trivial_sequence(graph: &Graph) -> (Vec<(u32, u32)>, usize) {
+    let mut sequence = Vec::new();
+    let twin_width = contract_rest(&mut Trigraph::from(graph), &mut sequence);
+    (sequence, twin_width)
+}
+
+/// Cancels the token when the process receives SIGTERM.
+/// On other platforms than unix the token is never cancelled by a signal.
+///
+/// The signal handler only sets a flag, which a background thread checks every few milliseconds.
+pub fn cancel_on_sigterm(token: &CancellationToken) {
+    if !signal::install() {
+        return;
+    }
+    let token = token.clone();
+    thread::spawn(move || loop {
+        if signal::received() {
+            token.cancel();
+            return;
+        }
+        thread::sleep(Duration::from_millis(5));
+    });
+}
+
+#[cfg(unix)]
+mod signal {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static RECEIVED: AtomicBool = AtomicBool::new(false);
+    const SIGTERM: i32 = 15;
+
+    extern "C" {
+        fn signal(signum: i32, handler: extern "C" fn(i32)) -> usize;
+    }
+
+    //Storing to an atomic is async-signal-safe
+    extern "C" fn handle(_: i32) {
+        RECEIVED.store(true, Ordering::SeqCst);
+    }
+
+    pub(super) fn install() -> bool {
+        unsafe {
+            signal(SIGTERM, handle);
+        }
+        true
+    }
+
+    pub(super) fn received() -> bool {
+        RECEIVED.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(not(unix))]
+mod signal {
+    pub(super) fn install() -> bool {
+        false
+    }
+
+    pub(super) fn received() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{graph::Graph, graph::Trigraph, verify::verify};
+
+use super::{cancel_on_sigterm, contract_rest, BestSoFar, CancellationToken};
+
+#[test]
+fn test_token_cancel() {
+    let token = CancellationToken::new();
+    let clone = token.clone();
+    assert!(!token.is_cancelled());
+
+    clone.cancel();
+
+    assert!(token.is_cancelled());
+    assert!(clone.is_cancelled());
+}
+
+#[test]
+fn test_token_deadline() {
+    let token = CancellationToken::new().with_deadline(Instant::now() + Duration::from_millis(20));
+    assert!(!token.is_cancelled());
+
+    thread::sleep(Duration::from_millis(30));
+
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn test_token_keeps_earlier_deadline() {
+    let now = Instant::now();
+    let token = CancellationToken::new().with_deadline(now);
+
+    let later = token.clone().with_deadline(now + Duration::from_secs(60));
+
+    assert!(later.is_cancelled());
+}
+
+#[test]
+fn test_best_so_far_keeps_smallest_width() {
+    let best = BestSoFar::new();
+    let clone = best.clone();
+    assert_eq!(best.get(), None);
+
+    assert!(best.publish(&[(1, 2)], 3));
+    assert!(clone.publish(&[(2, 1)], 2));
+    assert!(!best.publish(&[(1, 2)], 2));
+    assert!(!best.publish(&[(1, 2)], 4));
+
+    assert_eq!(best.get(), Some((vec![(2, 1)].into(), 2)));
+    assert_eq!(clone.get_twin_width(), Some(2));
+}
+
+#[test]
+fn test_contract_rest() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    let mut trigraph = Trigraph::from(&graph);
+    let mut sequence = vec![(2, 5)];
+    let width = trigraph.contract(2, 5);
+
+    let rest = contract_rest(&mut trigraph, &mut sequence);
+
+    assert_eq!(sequence.len(), 4);
+    assert_eq!(trigraph.get_node_count(), 1);
+    assert_eq!(verify(&graph, &sequence), Ok(width.max(rest)));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cancel_on_sigterm() {
+    extern "C" {
+        fn raise(signum: i32) -> i32;
+    }
+    let token = CancellationToken::new();
+    cancel_on_sigterm(&token);
+
+    unsafe {
+        raise(15);
+    }
+    let start = Instant::now();
+    while !token.is_cancelled() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(token.is_cancelled());
+}
@@ -0,0 +1,289 @@
+//! This module contains lower bounds for the twin-width of a graph
+//!
+//! Twin-width is monotone under induced subgraphs, so the twin-width of every induced subgraph
+//! is a lower bound as well. The bounds here search for subgraphs with a large first contraction
+//! bound and solve small subgraphs exactly.
+//! Most bounds compare all pairs of vertices and are therefore quadratic in the number of vertices.
+use std::{
+    cmp,
+    collections::{HashSet, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::algo::{
+    branch_and_bound::{BranchAndBound, Budget},
+    get_all_combinations,
+};
+use crate::anytime::{Anytime, BestSoFar, CancellationToken};
+use crate::graph::Graph;
+
+/// Settings for `lower_bound`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct BoundConfig {
+    /// Maximum number of vertices the local search removes
+    pub local_search_steps: usize,
+    /// Maximum number of vertices of a subgraph which is solved exactly
+    pub subgraph_size: usize,
+    /// Number of subgraphs which are solved exactly
+    pub subgraph_count: usize,
+    /// Budget of every exact solve
+    pub budget: Budget,
+    /// Maximum time of the whole bound, `None` means unlimited
+    pub time: Option<Duration>,
+}
+
+impl Default for BoundConfig {
+    fn default() -> Self {
+        BoundConfig {
+            local_search_steps: 100,
+            subgraph_size: 12,
+            subgraph_count: 5,
+            budget: Budget {
+                nodes: Some(10_000),
+                time: Some(Duration::from_secs(1)),
+            },
+            time: Some(Duration::from_secs(5)),
+        }
+    }
+}
+
+/// Computes the best lower bound of this module
+///
+/// # Parameters
+/// * graph: The graph to bound
+/// * config: The effort to spend on the local search and the exact solves
+///
+/// # Returns
+/// * A lower bound for the twin-width of the graph
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::bounds::{lower_bound, BoundConfig};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// assert_eq!(lower_bound(&graph, &BoundConfig::default()), 2);
+/// ```
+pub fn lower_bound(graph: &Graph, config: &BoundConfig) -> usize {
+    lower_bound_anytime(graph, config, &CancellationToken::new())
+}
+
+/// Computes the bound like `lower_bound`, but stops once the token is cancelled or the time of
+/// the config is over. The bound is then the best one proven so far.
+///
+/// # Examples
+/// ```
+/// use twinwidth::anytime::CancellationToken;
+/// use twinwidth::bounds::{lower_bound_anytime, BoundConfig};
+/// use twinwidth::graph::Graph;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// let token = CancellationToken::new();
+/// token.cancel();
+/// assert!(lower_bound_anytime(&graph, &BoundConfig::default(), &token) <= 2);
+/// ```
+pub fn lower_bound_anytime(
+    graph: &Graph,
+    config: &BoundConfig,
+    token: &CancellationToken,
+) -> usize {
+    let token = match config.time {
+        Some(time) => token.clone().with_deadline(Instant::now() + time),
+        None => token.clone(),
+    };
+    let (local_bound, witness) = local_search_bound(graph, config.local_search_steps, &token);
+
+    let mut candidates = balls(graph, config.subgraph_size);
+    if witness.len() <= config.subgraph_size {
+        candidates.push(witness);
+    }
+    let exact_bound = subgraph_bound(
+        graph,
+        candidates,
+        config.subgraph_count,
+        config.budget,
+        &token,
+    );
+
+    cmp::max(local_bound, exact_bound)
+}
+
+/// The smallest max red degree any first contraction creates.
+///
+/// Contracting two vertices of a graph makes the edges to their symmetric difference red, so
+/// this is the size of the smallest symmetric difference of two neighbourhoods.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::bounds::first_contraction_bound;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// assert_eq!(first_contraction_bound(&graph), 2);
+/// ```
+pub fn first_contraction_bound(graph: &Graph) -> usize {
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort();
+    best_pair(graph, &nodes, &CancellationToken::new())
+        .expect("The token is never cancelled")
+        .0
+}
+
+/// Pairs which the local search compares at most, every step compares all pairs twice
+const LOCAL_SEARCH_PAIRS: usize = 1_000_000;
+
+/// Searches an induced subgraph with a large first contraction bound.
+///
+/// Starting with the whole graph, one vertex of the pair with the smallest symmetric difference
+/// is removed in every step, so this pair does not limit the bound anymore.
+/// Every step compares all pairs, so large graphs get fewer steps than given.
+///
+/// # Parameters
+/// * graph: The graph to bound
+/// * steps: The maximum number of removed vertices
+/// * token: Stops the search, the steps which were completed until then count
+///
+/// # Returns
+/// * The best bound and the smallest subgraph which has it
+pub fn local_search_bound(
+    graph: &Graph,
+    steps: usize,
+    token: &CancellationToken,
+) -> (usize, Vec<u32>) {
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort();
+    let Some((mut bound, mut pair)) = best_pair(graph, &nodes, token) else {
+        return (0, nodes);
+    };
+    let mut best = (bound, nodes.clone());
+    let steps = cmp::min(steps, LOCAL_SEARCH_PAIRS / cmp::max(nodes.len().pow(2), 1));
+
+    for _ in 0..steps {
+        let Some((node_a, node_b)) = pair else {
+            break;
+        };
+
+        let without = |removed: u32| {
+            let rest: Vec<u32> = nodes
+                .iter()
+                .filter(|node| **node != removed)
+                .copied()
+                .collect();
+            let (rest_bound, rest_pair) = best_pair(graph, &rest, token)?;
+            Some((rest_bound, rest_pair, rest))
+        };
+        let (Some(without_a), Some(without_b)) = (without(node_a), without(node_b)) else {
+            break;
+        };
+        (bound, pair, nodes) = if without_b.0 > without_a.0 {
+            without_b
+        } else {
+            without_a
+        };
+
+        if bound >= best.0 {
+            best = (bound, nodes.clone());
+        }
+    }
+    best
+}
+
+/// Solves induced subgraphs exactly and returns the largest proven twin-width.
+///
+/// Only the `count` candidates with the largest first contraction bound are solved, and only
+/// solves which finish within the budget and before the token is cancelled count.
+///
+/// # Parameters
+/// * graph: The graph to bound
+/// * candidates: The vertex sets of the subgraphs
+/// * count: The number of subgraphs to solve
+/// * budget: The budget of every solve
+/// * token: Stops the solves
+pub fn subgraph_bound(
+    graph: &Graph,
+    candidates: Vec<Vec<u32>>,
+    count: usize,
+    budget: Budget,
+    token: &CancellationToken,
+) -> usize {
+    let mut seen: HashSet<Vec<u32>> = HashSet::new();
+    let mut subgraphs: Vec<(usize, Graph)> = Vec::new();
+    for mut nodes in candidates {
+        nodes.sort();
+        if !seen.insert(nodes.clone()) {
+            continue;
+        }
+        let subgraph = graph.induced_subgraph(&nodes);
+        subgraphs.push((first_contraction_bound(&subgraph), subgraph));
+    }
+    subgraphs.sort_by_key(|(bound, _)| cmp::Reverse(*bound));
+
+    subgraphs
+        .into_iter()
+        .take(count)
+        .filter_map(|(_, subgraph)| {
+            let mut search = BranchAndBound::new_with_budget(subgraph, budget);
+            let (_, width) = search.solve_anytime(&BestSoFar::new(), token);
+            search.is_optimal().then_some(width)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Collects the first `size` vertices of a breadth first search from every vertex
+fn balls(graph: &Graph, size: usize) -> Vec<Vec<u32>> {
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort();
+
+    nodes
+        .iter()
+        .map(|start| {
+            let mut ball = vec![*start];
+            let mut visited = HashSet::from([*start]);
+            let mut queue = VecDeque::from([*start]);
+            while let Some(node) = queue.pop_front() {
+                let mut neighbours: Vec<u32> = graph.get_neighbours(node).iter().copied().collect();
+                neighbours.sort();
+                for neighbour in neighbours {
+                    if ball.len() < size && visited.insert(neighbour) {
+                        ball.push(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            ball
+        })
+        .collect()
+}
+
+/// Finds the pair of the given vertices with the smallest symmetric difference in the induced subgraph
+///
+/// # Returns
+/// * The smallest difference and its pair, None if the token was cancelled before all pairs
+///   were compared
+fn best_pair(
+    graph: &Graph,
+    nodes: &[u32],
+    token: &CancellationToken,
+) -> Option<(usize, Option<(u32, u32)>)> {
+    let members: HashSet<u32> = nodes.iter().copied().collect();
+    let mut best: (usize, Option<(u32, u32)>) = (0, None);
+
+    for (node_a, node_b) in get_all_combinations(nodes.to_vec()) {
+        if token.is_cancelled() {
+            return None;
+        }
+        let difference = graph
+            .get_neighbours(node_a)
+            .symmetric_difference(graph.get_neighbours(node_b))
+            .filter(|node| **node != node_a && **node != node_b && members.contains(node))
+            .count();
+        if best.1.is_none() || difference < best.0 {
+            best = (difference, Some((node_a, node_b)));
+            if difference == 0 {
+                break;
+            }
+        }
+    }
+    Some(best)
+}
+
+#[cfg(test)]
+mod tests;
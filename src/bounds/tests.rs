@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use super::{
+    first_contraction_bound, local_search_bound, lower_bound, lower_bound_anytime, subgraph_bound,
+    BoundConfig,
+};
+use crate::{
+    algo::{branch_and_bound::BranchAndBound, Algo},
+    anytime::{trivial_sequence, CancellationToken},
+    generate::Family,
+    graph::Graph,
+    random::Rng,
+};
+
+fn petersen_graph() -> Graph {
+    Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 1),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 10),
+        (6, 8),
+        (8, 10),
+        (10, 7),
+        (7, 9),
+        (9, 6),
+    ])
+}
+
+#[test]
+fn test_first_contraction_bound() {
+    let cograph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    let path = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5)]);
+
+    assert_eq!(first_contraction_bound(&cograph), 0);
+    assert_eq!(first_contraction_bound(&path), 1);
+    assert_eq!(first_contraction_bound(&petersen_graph()), 4);
+}
+
+#[test]
+fn test_first_contraction_bound_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+
+    assert_eq!(first_contraction_bound(&graph), 0);
+}
+
+#[test]
+fn test_local_search_bound_removes_twin() {
+    //A twin of node 1 makes the first contraction free
+    let mut graph = petersen_graph();
+    graph.add_node(11);
+    [2, 5, 6].iter().for_each(|node| {
+        graph.add_edge(11, *node);
+    });
+
+    let (bound, witness) = local_search_bound(&graph, 10, &CancellationToken::new());
+
+    assert_eq!(first_contraction_bound(&graph), 0);
+    assert_eq!(bound, 4);
+    assert_eq!(
+        first_contraction_bound(&graph.induced_subgraph(&witness)),
+        4
+    );
+}
+
+#[test]
+fn test_subgraph_bound() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 1)]);
+
+    let bound = subgraph_bound(
+        &graph,
+        vec![graph.get_all_nodes()],
+        1,
+        Default::default(),
+        &CancellationToken::new(),
+    );
+
+    assert_eq!(bound, 2);
+}
+
+#[test]
+fn test_lower_bound_below_twin_width() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for density in [2, 3, 5] {
+        for _ in 0..5 {
+            let mut graph = Graph::new();
+            (1..=9).for_each(|node| graph.add_node(node));
+            for node_a in 1..=9 {
+                for node_b in node_a + 1..=9 {
+                    if rng.below(density) == 0 {
+                        graph.add_edge(node_a, node_b);
+                    }
+                }
+            }
+
+            let mut search = BranchAndBound::new_with_graph(graph.clone());
+            let (_, twin_width) = search.solve();
+            let bound = lower_bound(&graph, &BoundConfig::default());
+
+            assert!(search.is_optimal());
+            assert!(bound <= twin_width);
+        }
+    }
+}
+
+#[test]
+fn test_lower_bound_cancelled() {
+    let graph = petersen_graph();
+    let token = CancellationToken::new();
+    token.cancel();
+
+    //Nothing is proven before the cancellation
+    assert_eq!(
+        lower_bound_anytime(&graph, &BoundConfig::default(), &token),
+        0
+    );
+}
+
+#[test]
+fn test_lower_bound_time() {
+    let graph = Family::Random(300, 0.1).generate(3);
+    let config = BoundConfig {
+        time: Some(Duration::from_millis(200)),
+        ..BoundConfig::default()
+    };
+
+    let start = Instant::now();
+    let bound = lower_bound_anytime(&graph, &config, &CancellationToken::new());
+
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(bound <= trivial_sequence(&graph).1);
+}
@@ -0,0 +1,671 @@
+//! This module contains the command line interface of the solver
+//!
+//! The solver follows the I/O of the pace challenge: the graph is read in the .gr format from a
+//! file or from stdin, the contraction sequence is written in the .tww format to stdout and
+//! everything else goes to stderr.
+//! See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+//!
+//! Next to solving, the binary verifies sequences, analyzes directories of graphs, converts
+//! graph formats and generates graphs. All commands exit with 0 on success, with
+//! `EXIT_INVALID_INPUT` if the arguments or the input files are not valid and with
+//! `EXIT_FAILURE` if the command itself failed, e.g. the sequence is not a valid contraction
+//! sequence of the graph.
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::algo::{
+    beam_search::BeamConfig,
+    branch_and_bound::Budget,
+    greedy::GreedyConfig,
+    greedy_v2::GreedyV2Config,
+    randomized_greedy::RandomizedConfig,
+    sat::SatConfig,
+    score::Score,
+    solver::{solve_in_background, SolveError, SolveResult, Solver},
+};
+use crate::analyzer::{Analyzer, RunConfig};
+use crate::anytime::CancellationToken;
+use crate::generate::Family;
+use crate::graph::{Graph, GraphFormat};
+use crate::local_search::{Improved, LocalSearchConfig};
+use crate::sat::ExternalSolver;
+use crate::sequence::ContractionSequence;
+use crate::verify::verify;
+
+/// The exit code if the command failed
+pub const EXIT_FAILURE: i32 = 1;
+/// The exit code if the arguments or the input are not valid
+pub const EXIT_INVALID_INPUT: i32 = 2;
+
+/// The usage of the binary
+pub const USAGE: &str = "\
+usage: twinwidth [solve] [options] [graph.gr]
+       twinwidth verify <graph.gr> <sequence.tww>
+       twinwidth analyze [options] <directory>
+       twinwidth convert [--from <format>] [--to <format>] [graph]
+       twinwidth generate <family> <sizes...> [--seed <n>]
+
+solve      reads the graph from the file or from stdin and writes the contraction sequence to
+           stdout, on SIGTERM the best sequence found so far is written
+verify     writes the width of the contraction sequence
+analyze    solves all .gr files of the directory and writes a table of the results
+convert    converts a graph between the formats gr, dimacs, edges and dot (only written),
+           by default the format of the input follows its extension and the output is gr
+generate   writes a graph of the family as .gr: path <n>, cycle <n>, grid <rows> <columns>,
+           complete <n>, complete-bipartite <a> <b>, random-tree <n>, random <n> <p>
+
+options of solve and analyze:
+  --algo <name>        greedy (default), greedy-v2, randomized, beam, branch-and-bound, sat
+  --score <score>      score of greedy, randomized and beam, e.g. max-red-degree+new-red-edges
+  --seed <n>           seed of randomized and of the improvement
+  --restarts <n>       maximum number of restarts of randomized
+  --top-k <n>          number of best pairs randomized samples from
+  --beam-width <n>     number of states beam keeps
+  --candidates <n>     number of pairs beam contracts per state
+  --sat-solver <cmd>   external solver of sat which reads DIMACS from stdin, e.g. 'kissat -q',
+                       by default sat uses the built-in CDCL solver
+  --time <seconds>     time limit, afterwards the best sequence so far is written
+  --improve            improves the sequence by local search until the time limit or SIGTERM
+  --workers <n>        number of instances analyze solves at the same time, default all cores
+  --timeout <seconds>  time limit of analyze per instance, afterwards the best sequence so far
+                       is recorded
+  -h, --help           prints this help
+
+exit codes: 0 on success, 1 if the command failed, 2 if the arguments or the input are invalid";
+
+/// The algorithms of the command line interface
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum AlgoName {
+    #[default]
+    Greedy,
+    GreedyV2,
+    Randomized,
+    Beam,
+    BranchAndBound,
+    Sat,
+}
+
+impl fmt::Display for AlgoName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlgoName::Greedy => write!(f, "greedy"),
+            AlgoName::GreedyV2 => write!(f, "greedy-v2"),
+            AlgoName::Randomized => write!(f, "randomized"),
+            AlgoName::Beam => write!(f, "beam"),
+            AlgoName::BranchAndBound => write!(f, "branch-and-bound"),
+            AlgoName::Sat => write!(f, "sat"),
+        }
+    }
+}
+
+/// Reasons why the arguments are not valid
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ArgsError {
+    /// The flag is not known
+    UnknownFlag(String),
+    /// The flag needs a value, but it is the last argument
+    MissingValue(String),
+    /// The value of the flag or argument can not be parsed
+    InvalidValue { flag: String, value: String },
+    /// The command needs another argument
+    MissingArgument(String),
+    /// The command does not take this argument
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::UnknownFlag(flag) => write!(f, "unknown option '{flag}'"),
+            ArgsError::MissingValue(flag) => write!(f, "option '{flag}' needs a value"),
+            ArgsError::InvalidValue { flag, value } => {
+                write!(f, "'{value}' is not a valid value for '{flag}'")
+            }
+            ArgsError::MissingArgument(argument) => write!(f, "missing {argument}"),
+            ArgsError::UnexpectedArgument(argument) => {
+                write!(f, "unexpected argument '{argument}'")
+            }
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+/// Reasons why a command did not succeed
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum CliError {
+    /// The arguments are not valid
+    Args(ArgsError),
+    /// An input can not be read or is not valid
+    Input(String),
+    /// The command failed on valid input
+    Failed(String),
+}
+
+impl CliError {
+    /// Gets the exit code of the error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Args(_) | CliError::Input(_) => EXIT_INVALID_INPUT,
+            CliError::Failed(_) => EXIT_FAILURE,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Args(err) => write!(f, "{err}"),
+            CliError::Input(message) | CliError::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl From<ArgsError> for CliError {
+    fn from(err: ArgsError) -> Self {
+        CliError::Args(err)
+    }
+}
+
+/// The options of the solver
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct SolveOptions {
+    /// The .gr file, None reads from stdin. The directory of `analyze`.
+    pub input: Option<PathBuf>,
+    pub algo: AlgoName,
+    pub score: Score,
+    pub seed: u64,
+    pub restarts: Option<usize>,
+    pub top_k: Option<usize>,
+    pub beam_width: Option<usize>,
+    pub candidates: Option<usize>,
+    /// The command of an external SAT solver with its arguments, None uses the built-in solver
+    pub sat_solver: Option<String>,
+    pub time: Option<Duration>,
+    pub improve: bool,
+    /// The number of instances `analyze` solves at the same time
+    pub workers: Option<usize>,
+    /// The time limit of `analyze` per instance
+    pub timeout: Option<Duration>,
+}
+
+impl SolveOptions {
+    /// Reads the options from the arguments, without the name of the binary
+    ///
+    /// # Returns
+    /// * The options, unset options keep the defaults of the algorithms
+    /// * An `ArgsError` if an argument is not valid
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use twinwidth::cli::{AlgoName, SolveOptions};
+    /// let args = ["--algo", "beam", "--time", "1.5", "graph.gr"].map(String::from);
+    /// let options = SolveOptions::parse(args).unwrap();
+    /// assert_eq!(options.algo, AlgoName::Beam);
+    /// assert_eq!(options.time, Some(Duration::from_millis(1500)));
+    /// ```
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, ArgsError> {
+        let mut options = SolveOptions::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                if options.input.is_some() {
+                    return Err(ArgsError::UnexpectedArgument(arg));
+                }
+                options.input = Some(PathBuf::from(arg));
+                continue;
+            }
+
+            match arg.as_str() {
+                "--improve" => options.improve = true,
+                "--algo" => {
+                    options.algo = match value(&arg, args.next())?.as_str() {
+                        "greedy" => AlgoName::Greedy,
+                        "greedy-v2" => AlgoName::GreedyV2,
+                        "randomized" => AlgoName::Randomized,
+                        "beam" => AlgoName::Beam,
+                        "branch-and-bound" => AlgoName::BranchAndBound,
+                        "sat" => AlgoName::Sat,
+                        name => return Err(invalid(&arg, name)),
+                    }
+                }
+                "--score" => options.score = parsed(&arg, args.next())?,
+                "--seed" => options.seed = parsed(&arg, args.next())?,
+                "--restarts" => options.restarts = Some(parsed(&arg, args.next())?),
+                "--top-k" => options.top_k = Some(positive(&arg, args.next())?),
+                "--beam-width" => options.beam_width = Some(positive(&arg, args.next())?),
+                "--candidates" => options.candidates = Some(positive(&arg, args.next())?),
+                "--sat-solver" => {
+                    let command = value(&arg, args.next())?;
+                    if command.split_whitespace().next().is_none() {
+                        return Err(invalid(&arg, &command));
+                    }
+                    options.sat_solver = Some(command);
+                }
+                "--time" => options.time = Some(seconds(&arg, args.next())?),
+                "--workers" => options.workers = Some(positive(&arg, args.next())?),
+                "--timeout" => options.timeout = Some(seconds(&arg, args.next())?),
+                _ => return Err(ArgsError::UnknownFlag(arg)),
+            }
+        }
+
+        //A dash reads from stdin as well
+        if options.input.as_deref() == Some("-".as_ref()) {
+            options.input = None;
+        }
+        Ok(options)
+    }
+}
+
+/// A command of the binary
+#[derive(PartialEq, Debug, Clone)]
+pub enum Command {
+    Solve(SolveOptions),
+    Verify {
+        graph: PathBuf,
+        sequence: PathBuf,
+    },
+    /// Solves all .gr files of the directory in `input`
+    Analyze(SolveOptions),
+    Convert {
+        /// None reads from stdin
+        input: Option<PathBuf>,
+        /// None follows the extension of the input and is gr otherwise
+        from: Option<GraphFormat>,
+        to: GraphFormat,
+    },
+    Generate {
+        family: Family,
+        seed: u64,
+    },
+    Help,
+}
+
+impl Command {
+    /// Reads the command from the arguments, without the name of the binary.
+    /// Arguments without a command are the arguments of `solve`.
+    ///
+    /// # Returns
+    /// * The command and its options
+    /// * An `ArgsError` if an argument is not valid
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::cli::{Command, SolveOptions};
+    /// let args = ["verify", "graph.gr", "graph.tww"].map(String::from);
+    /// assert!(matches!(Command::parse(args), Ok(Command::Verify { .. })));
+    ///
+    /// let args = ["graph.gr"].map(String::from);
+    /// assert!(matches!(Command::parse(args), Ok(Command::Solve(_))));
+    /// ```
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, ArgsError> {
+        let args: Vec<String> = args.into_iter().collect();
+        if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+            return Ok(Command::Help);
+        }
+
+        let rest = args.iter().skip(1).cloned();
+        match args.first().map(String::as_str) {
+            Some("help") => Ok(Command::Help),
+            Some("solve") => Ok(Command::Solve(SolveOptions::parse(rest)?)),
+            Some("analyze") => {
+                let options = SolveOptions::parse(rest)?;
+                if options.input.is_none() {
+                    return Err(ArgsError::MissingArgument("directory".to_string()));
+                }
+                Ok(Command::Analyze(options))
+            }
+            Some("verify") => {
+                let mut paths = positionals(rest, 2)?.into_iter().map(PathBuf::from);
+                match (paths.next(), paths.next()) {
+                    (Some(graph), Some(sequence)) => Ok(Command::Verify { graph, sequence }),
+                    _ => Err(ArgsError::MissingArgument(
+                        "graph or contraction sequence".to_string(),
+                    )),
+                }
+            }
+            Some("convert") => parse_convert(rest),
+            Some("generate") => parse_generate(rest),
+            _ => Ok(Command::Solve(SolveOptions::parse(args)?)),
+        }
+    }
+}
+
+fn parse_convert<I: Iterator<Item = String>>(mut args: I) -> Result<Command, ArgsError> {
+    let (mut input, mut from, mut to) = (None, None, GraphFormat::Gr);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from" => from = Some(parsed(&arg, args.next())?),
+            "--to" => to = parsed(&arg, args.next())?,
+            "-" if input.is_none() => {}
+            _ if arg.starts_with('-') => return Err(ArgsError::UnknownFlag(arg)),
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Convert { input, from, to })
+}
+
+fn parse_generate<I: Iterator<Item = String>>(mut args: I) -> Result<Command, ArgsError> {
+    let mut seed = 0;
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parsed(&arg, args.next())?,
+            _ if arg.starts_with('-') => return Err(ArgsError::UnknownFlag(arg)),
+            _ => rest.push(arg),
+        }
+    }
+    let Some((name, sizes)) = rest.split_first() else {
+        return Err(ArgsError::MissingArgument("family".to_string()));
+    };
+
+    let size = |index: usize| -> Result<u32, ArgsError> {
+        parsed(
+            name,
+            Some(
+                sizes
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| ArgsError::MissingArgument(format!("size of {name}")))?,
+            ),
+        )
+    };
+    let (family, count) = match name.as_str() {
+        "path" => (Family::Path(size(0)?), 1),
+        "cycle" => (Family::Cycle(size(0)?), 1),
+        "grid" => (Family::Grid(size(0)?, size(1)?), 2),
+        "complete" => (Family::Complete(size(0)?), 1),
+        "complete-bipartite" => (Family::CompleteBipartite(size(0)?, size(1)?), 2),
+        "random-tree" => (Family::RandomTree(size(0)?), 1),
+        "random" => {
+            let probability: f64 =
+                parsed(
+                    name,
+                    Some(sizes.get(1).cloned().ok_or_else(|| {
+                        ArgsError::MissingArgument("edge probability".to_string())
+                    })?),
+                )?;
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(invalid(name, &probability.to_string()));
+            }
+            (Family::Random(size(0)?, probability), 2)
+        }
+        _ => return Err(invalid("generate", name)),
+    };
+    if let Some(extra) = sizes.get(count) {
+        return Err(ArgsError::UnexpectedArgument(extra.clone()));
+    }
+    Ok(Command::Generate { family, seed })
+}
+
+/// Collects exactly `count` arguments which are no flags
+fn positionals<I: Iterator<Item = String>>(
+    args: I,
+    count: usize,
+) -> Result<Vec<String>, ArgsError> {
+    let mut positionals = Vec::new();
+    for arg in args {
+        if arg.starts_with('-') {
+            return Err(ArgsError::UnknownFlag(arg));
+        }
+        if positionals.len() == count {
+            return Err(ArgsError::UnexpectedArgument(arg));
+        }
+        positionals.push(arg);
+    }
+    Ok(positionals)
+}
+
+fn value(flag: &str, next: Option<String>) -> Result<String, ArgsError> {
+    next.ok_or_else(|| ArgsError::MissingValue(flag.to_string()))
+}
+
+fn invalid(flag: &str, value: &str) -> ArgsError {
+    ArgsError::InvalidValue {
+        flag: flag.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn parsed<T: FromStr>(flag: &str, next: Option<String>) -> Result<T, ArgsError> {
+    let value = value(flag, next)?;
+    value.parse().map_err(|_| invalid(flag, &value))
+}
+
+fn seconds(flag: &str, next: Option<String>) -> Result<Duration, ArgsError> {
+    let seconds: f64 = parsed(flag, next)?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid(flag, &seconds.to_string()))
+}
+
+fn positive(flag: &str, next: Option<String>) -> Result<usize, ArgsError> {
+    match parsed(flag, next)? {
+        0 => Err(invalid(flag, "0")),
+        number => Ok(number),
+    }
+}
+
+/// Executes a command
+///
+/// # Parameters
+/// * command: The command to execute
+/// * token: Cancels the solver, e.g. on SIGTERM
+/// * output: Receives the result, e.g. stdout. The tables of `analyze` always go to stdout.
+///
+/// # Returns
+/// * A `CliError` if the input is not valid or the command failed
+///
+/// # Examples
+/// ```
+/// use twinwidth::anytime::CancellationToken;
+/// use twinwidth::cli::{execute, Command};
+/// use twinwidth::generate::Family;
+/// let mut output = Vec::new();
+/// let command = Command::Generate { family: Family::Path(3), seed: 0 };
+/// execute(command, &CancellationToken::new(), &mut output).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "p tww 3 2\n1 2\n2 3\n");
+/// ```
+pub fn execute(
+    command: Command,
+    token: &CancellationToken,
+    output: &mut dyn Write,
+) -> Result<(), CliError> {
+    let written = match command {
+        Command::Help => writeln!(output, "{USAGE}"),
+        Command::Solve(options) => {
+            let start = Instant::now();
+            let graph = read_graph(options.input.as_deref(), GraphFormat::Gr)?;
+            let token = match options.time {
+                Some(time) => token.clone().with_deadline(start + time),
+                None => token.clone(),
+            };
+            //The improvement runs until the time limit or SIGTERM
+            let search = LocalSearchConfig {
+                seed: options.seed,
+                iterations: None,
+                time: None,
+                ..LocalSearchConfig::default()
+            };
+            let solver = solver(&options, options.improve.then_some(search));
+            let result =
+                solve(graph, solver, &token).map_err(|err| CliError::Failed(err.to_string()))?;
+            eprintln!(
+                "c algo {}, twin-width {}{}{}, {:.3}s",
+                options.algo,
+                result.width,
+                if result.optimal { " (optimal)" } else { "" },
+                result
+                    .stats
+                    .seed
+                    .map_or(String::new(), |seed| format!(", seed {seed}")),
+                start.elapsed().as_secs_f64()
+            );
+            write!(output, "{}", result.sequence.to_tww())
+        }
+        Command::Verify { graph, sequence } => {
+            let graph = read_graph(Some(&graph), GraphFormat::Gr)?;
+            let tww = read_input(Some(&sequence))?;
+            let sequence = ContractionSequence::from_tww(&tww)
+                .map_err(|err| CliError::Input(format!("{}: {err}", path_name(&sequence))))?;
+            let twin_width = verify(&graph, &sequence)
+                .map_err(|err| CliError::Failed(format!("invalid contraction sequence: {err}")))?;
+            writeln!(output, "twin-width {twin_width}")
+        }
+        Command::Analyze(options) => {
+            let directory = options.input.clone().expect("Analyze has a directory");
+            if !directory.is_dir() {
+                return Err(CliError::Input(format!(
+                    "{} is not a directory",
+                    path_name(&directory)
+                )));
+            }
+            let search = LocalSearchConfig {
+                seed: options.seed,
+                time: options.time.or(LocalSearchConfig::default().time),
+                ..LocalSearchConfig::default()
+            };
+            let solver = solver(&options, options.improve.then_some(search));
+            let default = RunConfig::default();
+            let config = RunConfig {
+                workers: options.workers.unwrap_or(default.workers),
+                timeout: options.timeout,
+                ..default
+            };
+            let mut analyzer = Analyzer::new().with_run_config(config);
+            analyzer
+                .run(directory, solver)
+                .map_err(|err| CliError::Input(err.to_string()))?;
+            analyzer.show_result();
+            Ok(())
+        }
+        Command::Convert { input, from, to } => {
+            let from = from
+                .or_else(|| input.as_deref().and_then(GraphFormat::from_extension))
+                .unwrap_or(GraphFormat::Gr);
+            let graph = read_graph(input.as_deref(), from)?;
+            write!(output, "{}", graph.write_as(to))
+        }
+        Command::Generate { family, seed } => write!(output, "{}", family.generate(seed).to_gr()),
+    };
+    written.map_err(|err| CliError::Failed(format!("can not write the output: {err}")))
+}
+
+/// Reads a file, None reads stdin
+fn read_input(path: Option<&Path>) -> Result<String, CliError> {
+    let input = match path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+    };
+    input.map_err(|err| CliError::Input(format!("{}: {err}", name(path))))
+}
+
+fn read_graph(path: Option<&Path>, format: GraphFormat) -> Result<Graph, CliError> {
+    let input = read_input(path)?;
+    Graph::read_as(&input, format).map_err(|err| CliError::Input(format!("{}: {err}", name(path))))
+}
+
+fn name(path: Option<&Path>) -> String {
+    path.map_or("stdin".to_string(), path_name)
+}
+
+fn path_name(path: &Path) -> String {
+    path.display().to_string()
+}
+
+/// Creates the configured algorithm of the options
+///
+/// # Parameters
+/// * options: The algorithm and its options
+/// * search: The configuration of the improvement, None does not improve the sequence
+pub fn solver(options: &SolveOptions, search: Option<LocalSearchConfig>) -> Box<dyn Solver> {
+    let solver: Box<dyn Solver> = match options.algo {
+        AlgoName::Greedy => Box::new(GreedyConfig {
+            score: options.score.clone(),
+        }),
+        AlgoName::GreedyV2 => Box::new(GreedyV2Config),
+        AlgoName::Randomized => {
+            let default = RandomizedConfig::default();
+            Box::new(RandomizedConfig {
+                seed: options.seed,
+                top_k: options.top_k.unwrap_or(default.top_k),
+                restarts: options.restarts.or(default.restarts),
+                time: options.time,
+                score: options.score.clone(),
+            })
+        }
+        AlgoName::Beam => {
+            let default = BeamConfig::default();
+            Box::new(BeamConfig {
+                beam_width: options.beam_width.unwrap_or(default.beam_width),
+                candidates: options.candidates.unwrap_or(default.candidates),
+                score: options.score.clone(),
+            })
+        }
+        AlgoName::BranchAndBound => Box::new(Budget {
+            time: options.time,
+            ..Budget::default()
+        }),
+        AlgoName::Sat => Box::new(SatConfig {
+            external: options.sat_solver.as_deref().map(external_solver),
+            time: options.time,
+        }),
+    };
+    match search {
+        Some(config) => Box::new(Improved { solver, config }),
+        None => solver,
+    }
+}
+
+/// Splits the command of `--sat-solver` into the program and its arguments
+fn external_solver(command: &str) -> ExternalSolver {
+    let mut words = command.split_whitespace();
+    let program = words.next().expect("parse rejects empty commands");
+    ExternalSolver::new(program, words.map(String::from).collect())
+}
+
+/// Solves the graph with `solve_in_background`
+///
+/// # Parameters
+/// * graph: The graph to solve
+/// * solver: The configured algorithm, the time limit has to be part of the token
+/// * token: Cancels the solver, e.g. on SIGTERM
+///
+/// # Returns
+/// * The result of the solver, or the best sequence published before the token was cancelled,
+///   which is at least the trivial sequence
+/// * A `SolveError` if the graph is empty or the solver failed
+///
+/// # Examples
+/// ```
+/// use twinwidth::anytime::CancellationToken;
+/// use twinwidth::cli::{solve, solver, SolveOptions};
+/// use twinwidth::graph::Graph;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+/// let solver = solver(&SolveOptions::default(), None);
+/// let result = solve(graph, solver, &CancellationToken::new()).unwrap();
+/// assert_eq!(result.width, 2);
+/// ```
+pub fn solve(
+    graph: Graph,
+    solver: Box<dyn Solver>,
+    token: &CancellationToken,
+) -> Result<SolveResult, SolveError> {
+    solve_in_background(graph, Arc::from(solver), token)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,447 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    algo::{score::Score, solver::SolveError},
+    anytime::CancellationToken,
+    generate::Family,
+    graph::{Graph, GraphFormat},
+    local_search::LocalSearchConfig,
+    sequence::ContractionSequence,
+    verify::verify,
+};
+
+use super::{
+    execute, solve, solver, AlgoName, ArgsError, CliError, Command, SolveOptions, EXIT_FAILURE,
+    EXIT_INVALID_INPUT,
+};
+
+fn parse(args: &[&str]) -> Result<SolveOptions, ArgsError> {
+    SolveOptions::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+fn command(args: &[&str]) -> Result<Command, ArgsError> {
+    Command::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+fn run(command: Command) -> Result<String, CliError> {
+    let mut output = Vec::new();
+    execute(command, &CancellationToken::new(), &mut output)?;
+    Ok(String::from_utf8(output).unwrap())
+}
+
+/// Writes a file into a fresh directory of the test
+fn write_file(test: &str, name: &str, content: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("twinwidth-cli-{test}-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let path = directory.join(name);
+    fs::write(&path, content).unwrap();
+    path
+}
+
+/// The 5x5 grid, where `Greedy` finds width 4
+fn grid() -> Graph {
+    let mut edges = Vec::new();
+    for node in 1..=25 {
+        if node % 5 != 0 {
+            edges.push((node, node + 1));
+        }
+        if node <= 20 {
+            edges.push((node, node + 5));
+        }
+    }
+    Graph::from_edges(edges)
+}
+
+#[test]
+fn test_parse_defaults() {
+    assert_eq!(parse(&[]), Ok(SolveOptions::default()));
+    assert_eq!(parse(&["-"]), Ok(SolveOptions::default()));
+}
+
+#[test]
+fn test_parse_all_options() {
+    let options = parse(&[
+        "--algo",
+        "randomized",
+        "--score",
+        "new-red-edges+max-red-degree",
+        "--seed",
+        "7",
+        "--restarts",
+        "20",
+        "--top-k",
+        "2",
+        "--beam-width",
+        "3",
+        "--candidates",
+        "4",
+        "--sat-solver",
+        "kissat -q",
+        "--time",
+        "0.25",
+        "--improve",
+        "--workers",
+        "2",
+        "--timeout",
+        "1",
+        "graph.gr",
+    ])
+    .unwrap();
+
+    let expected = SolveOptions {
+        input: Some(PathBuf::from("graph.gr")),
+        algo: AlgoName::Randomized,
+        score: Score::Lexicographic(vec![Score::NewRedEdges, Score::MaxRedDegree]),
+        seed: 7,
+        restarts: Some(20),
+        top_k: Some(2),
+        beam_width: Some(3),
+        candidates: Some(4),
+        sat_solver: Some("kissat -q".to_string()),
+        time: Some(Duration::from_millis(250)),
+        improve: true,
+        workers: Some(2),
+        timeout: Some(Duration::from_secs(1)),
+    };
+    assert_eq!(options, expected);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        parse(&["--fast"]),
+        Err(ArgsError::UnknownFlag("--fast".to_string()))
+    );
+    assert_eq!(
+        parse(&["--seed"]),
+        Err(ArgsError::MissingValue("--seed".to_string()))
+    );
+    assert_eq!(
+        parse(&["--algo", "exact"]),
+        Err(ArgsError::InvalidValue {
+            flag: "--algo".to_string(),
+            value: "exact".to_string()
+        })
+    );
+    assert!(parse(&["--beam-width", "0"]).is_err());
+    assert!(parse(&["--time", "-1"]).is_err());
+    assert!(parse(&["--sat-solver", " "]).is_err());
+    assert_eq!(
+        parse(&["a.gr", "b.gr"]),
+        Err(ArgsError::UnexpectedArgument("b.gr".to_string()))
+    );
+}
+
+#[test]
+fn test_solve_every_algo() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+    let algos = [
+        AlgoName::Greedy,
+        AlgoName::GreedyV2,
+        AlgoName::Randomized,
+        AlgoName::Beam,
+        AlgoName::BranchAndBound,
+        AlgoName::Sat,
+    ];
+
+    for algo in algos {
+        let options = SolveOptions {
+            algo,
+            ..SolveOptions::default()
+        };
+
+        let result = solve(
+            graph.clone(),
+            solver(&options, None),
+            &CancellationToken::new(),
+        )
+        .unwrap();
+
+        assert_eq!(result.width, 2, "Algo: {algo}");
+        assert_eq!(verify(&graph, &result.sequence), Ok(2), "Algo: {algo}");
+    }
+}
+
+#[test]
+fn test_solve_improve_until_deadline() {
+    let graph = grid();
+    let options = parse(&["--improve", "--time", "0.3"]).unwrap();
+    let token = CancellationToken::new().with_deadline(Instant::now() + options.time.unwrap());
+
+    let search = LocalSearchConfig {
+        iterations: None,
+        time: None,
+        ..LocalSearchConfig::default()
+    };
+
+    let result = solve(graph.clone(), solver(&options, Some(search)), &token).unwrap();
+
+    assert!(token.is_cancelled());
+    assert_eq!(verify(&graph, &result.sequence), Ok(result.width));
+}
+
+#[test]
+fn test_solve_cancelled_before_first_sequence() {
+    //Greedy needs minutes for this graph and publishes only its complete sequence
+    let graph = Family::Random(300, 0.3).generate(1);
+    let token = CancellationToken::new().with_deadline(Instant::now() + Duration::from_millis(100));
+
+    let result = solve(
+        graph.clone(),
+        solver(&SolveOptions::default(), None),
+        &token,
+    )
+    .unwrap();
+
+    assert_eq!(result.sequence.len(), 299);
+    assert_eq!(verify(&graph, &result.sequence), Ok(result.width));
+}
+
+#[test]
+fn test_solve_empty_graph() {
+    let options = SolveOptions::default();
+
+    let result = solve(
+        Graph::from_edges(Vec::new()),
+        solver(&options, None),
+        &CancellationToken::new(),
+    );
+
+    assert_eq!(result, Err(SolveError::EmptyGraph));
+}
+
+#[test]
+fn test_solver_names() {
+    let options = parse(&["--algo", "beam", "--improve"]).unwrap();
+
+    assert_eq!(solver(&options, None).name(), "beam");
+    let search = LocalSearchConfig::default();
+    assert_eq!(solver(&options, Some(search)).name(), "beam+improve");
+}
+
+#[test]
+fn test_parse_commands() {
+    assert_eq!(command(&[]), Ok(Command::Solve(SolveOptions::default())));
+    assert_eq!(command(&["solve", "--help"]), Ok(Command::Help));
+    assert_eq!(
+        command(&["--algo", "beam", "g.gr"]),
+        command(&["solve", "--algo", "beam", "g.gr"])
+    );
+    assert_eq!(
+        command(&["verify", "g.gr", "g.tww"]),
+        Ok(Command::Verify {
+            graph: PathBuf::from("g.gr"),
+            sequence: PathBuf::from("g.tww")
+        })
+    );
+    assert_eq!(
+        command(&["convert", "--to", "dot", "g.col"]),
+        Ok(Command::Convert {
+            input: Some(PathBuf::from("g.col")),
+            from: None,
+            to: GraphFormat::Dot
+        })
+    );
+    assert_eq!(
+        command(&["generate", "grid", "3", "4", "--seed", "2"]),
+        Ok(Command::Generate {
+            family: Family::Grid(3, 4),
+            seed: 2
+        })
+    );
+    assert_eq!(
+        command(&["generate", "random", "10", "0.5"]),
+        Ok(Command::Generate {
+            family: Family::Random(10, 0.5),
+            seed: 0
+        })
+    );
+    assert!(matches!(
+        command(&["analyze", "--algo", "sat", "instances"]),
+        Ok(Command::Analyze(SolveOptions {
+            algo: AlgoName::Sat,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn test_parse_command_errors() {
+    assert_eq!(
+        command(&["verify", "g.gr"]),
+        Err(ArgsError::MissingArgument(
+            "graph or contraction sequence".to_string()
+        ))
+    );
+    assert_eq!(
+        command(&["analyze"]),
+        Err(ArgsError::MissingArgument("directory".to_string()))
+    );
+    assert_eq!(
+        command(&["generate", "grid", "3"]),
+        Err(ArgsError::MissingArgument("size of grid".to_string()))
+    );
+    assert_eq!(
+        command(&["generate", "path", "3", "4"]),
+        Err(ArgsError::UnexpectedArgument("4".to_string()))
+    );
+    assert!(command(&["generate", "random", "3", "2"]).is_err());
+    assert!(command(&["generate", "star", "3"]).is_err());
+    assert!(command(&["convert", "--to", "png"]).is_err());
+}
+
+#[test]
+fn test_execute_verify() {
+    let graph = write_file("verify", "path.gr", "p tww 4 3\n1 2\n2 3\n3 4\n");
+    let valid = write_file("verify", "valid.tww", "4 3\n2 1\n2 4\n");
+    let invalid = write_file("verify", "invalid.tww", "1 2\n1 2\n1 3\n");
+    let broken = write_file("verify", "broken.tww", "1 x\n");
+
+    let verify = |sequence: &PathBuf| {
+        run(Command::Verify {
+            graph: graph.clone(),
+            sequence: sequence.clone(),
+        })
+    };
+
+    assert_eq!(verify(&valid), Ok("twin-width 1\n".to_string()));
+    assert_eq!(verify(&invalid).unwrap_err().exit_code(), EXIT_FAILURE);
+    assert_eq!(verify(&broken).unwrap_err().exit_code(), EXIT_INVALID_INPUT);
+}
+
+#[test]
+fn test_execute_convert() {
+    let input = write_file("convert", "path.col", "c path\np edge 3 2\ne 1 2\ne 2 3\n");
+
+    let output = run(Command::Convert {
+        input: Some(input),
+        from: None,
+        to: GraphFormat::Gr,
+    });
+
+    assert_eq!(output, Ok("p tww 3 2\n1 2\n2 3\n".to_string()));
+}
+
+#[test]
+fn test_execute_convert_solve_round_trip() {
+    let edges = write_file("convert-round-trip", "path.edges", "1 2\n2 3\n3 4\n4 5\n");
+    let zero_based = write_file("convert-round-trip", "zero.edges", "0 1\n1 2\n");
+
+    let gr = run(Command::Convert {
+        input: Some(edges),
+        from: None,
+        to: GraphFormat::Gr,
+    })
+    .unwrap();
+    let graph = write_file("convert-round-trip", "path.gr", &gr);
+    let tww = run(Command::Solve(SolveOptions {
+        input: Some(graph.clone()),
+        ..SolveOptions::default()
+    }))
+    .unwrap();
+    let sequence = write_file("convert-round-trip", "path.tww", &tww);
+
+    let verified = run(Command::Verify { graph, sequence });
+    assert_eq!(verified, Ok("twin-width 1\n".to_string()));
+
+    let converted = run(Command::Convert {
+        input: Some(zero_based),
+        from: None,
+        to: GraphFormat::Gr,
+    });
+    assert_eq!(converted.unwrap_err().exit_code(), EXIT_INVALID_INPUT);
+}
+
+#[test]
+fn test_execute_generate_solve_round_trip() {
+    let output = run(Command::Generate {
+        family: Family::Cycle(6),
+        seed: 0,
+    })
+    .unwrap();
+    let graph = write_file("round-trip", "cycle.gr", &output);
+
+    let tww = run(Command::Solve(SolveOptions {
+        input: Some(graph.clone()),
+        ..SolveOptions::default()
+    }))
+    .unwrap();
+    let sequence = write_file("round-trip", "cycle.tww", &tww);
+
+    let verified = run(Command::Verify { graph, sequence });
+    assert_eq!(verified, Ok("twin-width 2\n".to_string()));
+}
+
+#[test]
+fn test_execute_solve_time_limit() {
+    //Larger than the tiny set, so the exact algorithms cannot finish within the time limit
+    let graph = Family::Random(30, 0.3).generate(1);
+    let input = write_file("time-limit", "random.gr", &graph.to_gr());
+    let input = input.to_str().unwrap();
+
+    for algo in ["branch-and-bound", "sat", "greedy", "greedy-v2", "beam"] {
+        let start = Instant::now();
+        let args = ["solve", "--algo", algo, "--time", "0.5", input];
+
+        let tww = run(command(&args).unwrap()).unwrap();
+
+        let sequence = ContractionSequence::from_tww(&tww).unwrap();
+        assert_eq!(sequence.len(), 29, "Algo: {algo}");
+        assert!(verify(&graph, &sequence).is_ok(), "Algo: {algo}");
+        assert!(start.elapsed() < Duration::from_secs(5), "Algo: {algo}");
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_execute_solve_external_sat_solver() {
+    //sleep never answers, so only the time limit ends the search of the external solver
+    let graph = Family::Random(30, 0.3).generate(1);
+    let input = write_file("sat-solver", "random.gr", &graph.to_gr());
+    let args = [
+        "solve",
+        "--algo",
+        "sat",
+        "--sat-solver",
+        "sleep 10",
+        "--time",
+        "0.5",
+        input.to_str().unwrap(),
+    ];
+
+    let start = Instant::now();
+    let tww = run(command(&args).unwrap()).unwrap();
+
+    let sequence = ContractionSequence::from_tww(&tww).unwrap();
+    assert!(verify(&graph, &sequence).is_ok());
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn test_execute_invalid_input() {
+    let broken = write_file("invalid", "broken.gr", "p tww 2 1\n1 x\n");
+    let missing = PathBuf::from("does/not/exist.gr");
+    let directory = broken.parent().unwrap().to_path_buf();
+
+    let solve = run(Command::Solve(SolveOptions {
+        input: Some(broken),
+        ..SolveOptions::default()
+    }));
+    let analyze = run(Command::Analyze(SolveOptions {
+        input: Some(missing),
+        ..SolveOptions::default()
+    }));
+
+    let analyze_broken = run(Command::Analyze(SolveOptions {
+        input: Some(directory),
+        ..SolveOptions::default()
+    }));
+
+    assert_eq!(solve.unwrap_err().exit_code(), EXIT_INVALID_INPUT);
+    assert_eq!(analyze.unwrap_err().exit_code(), EXIT_INVALID_INPUT);
+    assert_eq!(analyze_broken.unwrap_err().exit_code(), EXIT_INVALID_INPUT);
+}
@@ -0,0 +1,373 @@
+//! This module contains the recognition of cographs, the graphs of twin-width 0
+//!
+//! A graph is a cograph if and only if it can be built from single vertices by disjoint unions
+//! and joins. The cotree records these operations, its leaves are the vertices.
+//! Equivalently, every induced subgraph with at least two vertices contains twins, so a graph
+//! is a cograph if and only if contracting twins leaves a single vertex.
+//!
+//! The recognition removes twins one at a time. Neighbourhoods are compared by the sum of random
+//! values of their vertices, so only the neighbours of a removed vertex need a new hash and the
+//! whole recognition takes expected linear time. Vertices with equal hashes are compared exactly
+//! before they are contracted, so a hash collision can never produce a wrong result.
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::graph::Graph;
+use crate::random::splitmix64;
+use crate::sequence::ContractionSequence;
+
+/// The operation of a node of a cotree
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CotreeKind {
+    /// A vertex of the graph
+    Leaf(u32),
+    /// The disjoint union of the children, they are pairwise non-adjacent
+    Union,
+    /// The join of the children, they are pairwise completely adjacent
+    Join,
+}
+
+/// A cotree stored as arena, so even very deep cotrees need no recursion.
+/// Children of a node never have the same kind as their parent.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Cotree {
+    kinds: Vec<CotreeKind>,
+    children: Vec<Vec<usize>>,
+    root: usize,
+}
+
+impl Cotree {
+    /// Builds the cotree of a graph
+    ///
+    /// # Parameters
+    /// * graph: The graph to decompose
+    ///
+    /// # Returns
+    /// * The cotree, None if the graph is not a cograph or has no nodes
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::cograph::{Cotree, CotreeKind};
+    /// //A square is the join of the non-edges {1, 3} and {2, 4}
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    /// let cotree = Cotree::from_graph(&graph).unwrap();
+    /// assert_eq!(cotree.get_kind(cotree.get_root()), CotreeKind::Join);
+    /// assert_eq!(cotree.get_children(cotree.get_root()).len(), 2);
+    ///
+    /// let path = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    /// assert!(Cotree::from_graph(&path).is_none());
+    /// ```
+    pub fn from_graph(graph: &Graph) -> Option<Self> {
+        let mut nodes = graph.get_all_nodes();
+        nodes.sort();
+        if nodes.is_empty() {
+            return None;
+        }
+        let indices: HashMap<u32, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (*node, index))
+            .collect();
+        let adjacency: Vec<HashSet<usize>> = nodes
+            .iter()
+            .map(|node| {
+                graph
+                    .get_neighbours(*node)
+                    .iter()
+                    .map(|neighbour| indices[neighbour])
+                    .collect()
+            })
+            .collect();
+
+        let mut cotree = Cotree {
+            kinds: nodes.iter().map(|node| CotreeKind::Leaf(*node)).collect(),
+            children: vec![Vec::new(); nodes.len()],
+            root: 0,
+        };
+        //The cotree node which represents everything contracted into a vertex
+        let mut subtrees: Vec<usize> = (0..nodes.len()).collect();
+
+        let mut reducer = TwinReducer::new(adjacency);
+        while let Some((vertex_a, vertex_b, kind)) = reducer.next_twins() {
+            subtrees[vertex_a] = cotree.merge(subtrees[vertex_a], subtrees[vertex_b], kind);
+        }
+
+        if reducer.get_vertex_count() > 1 {
+            return None;
+        }
+        cotree.root = subtrees[reducer.get_last_vertex()];
+        Some(cotree)
+    }
+
+    /// Gets the root of the cotree
+    pub fn get_root(&self) -> usize {
+        self.root
+    }
+
+    /// Gets the kind of a node of the cotree
+    ///
+    /// # Panics
+    /// If the node does not exist
+    pub fn get_kind(&self, node: usize) -> CotreeKind {
+        self.kinds[node]
+    }
+
+    /// Gets the children of a node of the cotree, leaves have none
+    ///
+    /// # Panics
+    /// If the node does not exist
+    pub fn get_children(&self, node: usize) -> &[usize] {
+        &self.children[node]
+    }
+
+    /// Constructs a contraction sequence of width 0.
+    ///
+    /// Bottom up, the children of every node are contracted into its first child.
+    /// The vertices of a subtree form a module, so contracting inside it creates no red edge to
+    /// other vertices, and once every child is a single vertex, the children are twins.
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::cograph::Cotree;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    /// let sequence = Cotree::from_graph(&graph).unwrap().to_contraction_sequence();
+    /// assert_eq!(sequence.to_vec(), vec![(1, 3), (2, 4), (1, 2)]);
+    /// ```
+    pub fn to_contraction_sequence(&self) -> ContractionSequence {
+        let mut sequence = ContractionSequence::new();
+        //The vertex which is left of every finished node
+        let mut representatives: Vec<Option<u32>> = vec![None; self.kinds.len()];
+
+        let mut stack = vec![(self.root, false)];
+        while let Some((node, expanded)) = stack.pop() {
+            if let CotreeKind::Leaf(vertex) = self.kinds[node] {
+                representatives[node] = Some(vertex);
+            } else if expanded {
+                let mut children = self.children[node]
+                    .iter()
+                    .map(|child| representatives[*child].expect("Children are finished first"));
+                let first = children.next().expect("Inner nodes have children");
+                children.for_each(|vertex| sequence.push(first, vertex));
+                representatives[node] = Some(first);
+            } else {
+                stack.push((node, true));
+                stack.extend(
+                    self.children[node]
+                        .iter()
+                        .rev()
+                        .map(|child| (*child, false)),
+                );
+            }
+        }
+        sequence
+    }
+
+    /// Combines two subtrees with a union or join, children of the same kind are flattened.
+    /// When two lists of children are merged, the shorter one is moved, so every node is only
+    /// moved a logarithmic number of times.
+    fn merge(&mut self, subtree_a: usize, subtree_b: usize, kind: CotreeKind) -> usize {
+        match (self.kinds[subtree_a] == kind, self.kinds[subtree_b] == kind) {
+            (true, true) => {
+                let mut moved = std::mem::take(&mut self.children[subtree_b]);
+                if moved.len() > self.children[subtree_a].len() {
+                    std::mem::swap(&mut moved, &mut self.children[subtree_a]);
+                }
+                self.children[subtree_a].append(&mut moved);
+                subtree_a
+            }
+            (true, false) => {
+                self.children[subtree_a].push(subtree_b);
+                subtree_a
+            }
+            (false, true) => {
+                self.children[subtree_b].push(subtree_a);
+                subtree_b
+            }
+            (false, false) => {
+                self.kinds.push(kind);
+                self.children.push(vec![subtree_a, subtree_b]);
+                self.kinds.len() - 1
+            }
+        }
+    }
+}
+
+/// Recognizes cographs and constructs a contraction sequence of width 0 for them.
+///
+/// # Parameters
+/// * graph: The graph to recognize
+///
+/// # Returns
+/// * A contraction sequence of width 0, None if the graph is not a cograph or has no nodes
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::cograph::recognize_cograph;
+/// let mut graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+/// graph.add_node(5);
+/// assert_eq!(recognize_cograph(&graph).unwrap().len(), 4);
+///
+/// let path = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+/// assert!(recognize_cograph(&path).is_none());
+/// ```
+pub fn recognize_cograph(graph: &Graph) -> Option<ContractionSequence> {
+    Cotree::from_graph(graph).map(|cotree| cotree.to_contraction_sequence())
+}
+
+/// Removes twins one pair at a time while keeping hashes of all neighbourhoods up to date
+struct TwinReducer {
+    adjacency: Vec<HashSet<usize>>,
+    alive: usize,
+    weights: Vec<u64>,
+    /// Sum of the weights of the neighbours
+    open: Vec<u64>,
+    /// Vertices by the hash of their neighbourhood and of their closed neighbourhood
+    open_buckets: HashMap<u64, BTreeSet<usize>>,
+    closed_buckets: HashMap<u64, BTreeSet<usize>>,
+    /// Hashes of buckets which might contain twins, with true for closed neighbourhoods
+    candidates: Vec<(u64, bool)>,
+}
+
+impl TwinReducer {
+    fn new(adjacency: Vec<HashSet<usize>>) -> Self {
+        let weights: Vec<u64> = (0..adjacency.len() as u64).map(splitmix64).collect();
+        let mut reducer = TwinReducer {
+            alive: adjacency.len(),
+            open: adjacency
+                .iter()
+                .map(|neighbours| {
+                    neighbours
+                        .iter()
+                        .fold(0u64, |sum, neighbour| sum.wrapping_add(weights[*neighbour]))
+                })
+                .collect(),
+            adjacency,
+            weights,
+            open_buckets: HashMap::new(),
+            closed_buckets: HashMap::new(),
+            candidates: Vec::new(),
+        };
+        (0..reducer.adjacency.len()).for_each(|vertex| reducer.insert(vertex));
+        reducer
+    }
+
+    fn get_vertex_count(&self) -> usize {
+        self.alive
+    }
+
+    /// Gets a vertex which is not removed
+    fn get_last_vertex(&self) -> usize {
+        self.open_buckets
+            .values()
+            .flat_map(|bucket| bucket.iter())
+            .copied()
+            .next()
+            .expect("At least one vertex is left")
+    }
+
+    /// Finds twins, removes the second one and returns them with the kind of cotree node they form
+    fn next_twins(&mut self) -> Option<(usize, usize, CotreeKind)> {
+        while let Some((hash, closed)) = self.candidates.pop() {
+            let buckets = if closed {
+                &self.closed_buckets
+            } else {
+                &self.open_buckets
+            };
+            let Some(bucket) = buckets.get(&hash).filter(|bucket| bucket.len() > 1) else {
+                continue;
+            };
+
+            //Usually the two smallest vertices are twins, only a hash collision needs all pairs
+            let mut members = bucket.iter().copied();
+            let smallest = (members.next().unwrap(), members.next().unwrap());
+            let twins = if self.are_twins(smallest.0, smallest.1, closed) {
+                Some(smallest)
+            } else {
+                let members: Vec<usize> = bucket.iter().copied().collect();
+                members.iter().enumerate().find_map(|(position, vertex_a)| {
+                    members[position + 1..]
+                        .iter()
+                        .find(|vertex_b| self.are_twins(*vertex_a, **vertex_b, closed))
+                        .map(|vertex_b| (*vertex_a, *vertex_b))
+                })
+            };
+
+            if let Some((vertex_a, vertex_b)) = twins {
+                //The bucket might contain more twins
+                self.candidates.push((hash, closed));
+                self.remove(vertex_b);
+                let kind = if closed {
+                    CotreeKind::Join
+                } else {
+                    CotreeKind::Union
+                };
+                return Some((vertex_a, vertex_b, kind));
+            }
+        }
+        None
+    }
+
+    fn are_twins(&self, vertex_a: usize, vertex_b: usize, closed: bool) -> bool {
+        let neighbours_a = &self.adjacency[vertex_a];
+        let neighbours_b = &self.adjacency[vertex_b];
+        if closed {
+            neighbours_a.contains(&vertex_b)
+                && neighbours_a.len() == neighbours_b.len()
+                && neighbours_a
+                    .iter()
+                    .all(|node| *node == vertex_b || neighbours_b.contains(node))
+        } else {
+            neighbours_a == neighbours_b
+        }
+    }
+
+    fn remove(&mut self, vertex: usize) {
+        self.erase(vertex);
+        self.alive -= 1;
+        let neighbours = std::mem::take(&mut self.adjacency[vertex]);
+        for neighbour in neighbours {
+            self.erase(neighbour);
+            self.adjacency[neighbour].remove(&vertex);
+            self.open[neighbour] = self.open[neighbour].wrapping_sub(self.weights[vertex]);
+            self.insert(neighbour);
+        }
+    }
+
+    fn insert(&mut self, vertex: usize) {
+        let open = self.open[vertex];
+        let closed = open.wrapping_add(self.weights[vertex]);
+        for (hash, is_closed) in [(open, false), (closed, true)] {
+            let buckets = if is_closed {
+                &mut self.closed_buckets
+            } else {
+                &mut self.open_buckets
+            };
+            let bucket = buckets.entry(hash).or_default();
+            bucket.insert(vertex);
+            if bucket.len() > 1 {
+                self.candidates.push((hash, is_closed));
+            }
+        }
+    }
+
+    fn erase(&mut self, vertex: usize) {
+        let open = self.open[vertex];
+        let closed = open.wrapping_add(self.weights[vertex]);
+        for (hash, buckets) in [
+            (open, &mut self.open_buckets),
+            (closed, &mut self.closed_buckets),
+        ] {
+            if let Some(bucket) = buckets.get_mut(&hash) {
+                bucket.remove(&vertex);
+                if bucket.is_empty() {
+                    buckets.remove(&hash);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+use super::{recognize_cograph, Cotree, CotreeKind};
+use crate::{graph::Graph, random::Rng, verify::verify};
+
+/// Builds a random cograph by unions and joins of random parts
+fn random_cograph(rng: &mut Rng, nodes: u32) -> Graph {
+    let mut parts: Vec<Graph> = (1..=nodes)
+        .map(|node| {
+            let mut graph = Graph::new();
+            graph.add_node(node);
+            graph
+        })
+        .collect();
+
+    while parts.len() > 1 {
+        let graph_b = parts.swap_remove(rng.below(parts.len()));
+        let index = rng.below(parts.len());
+        let join = rng.below(2) == 0;
+
+        let graph_a = &mut parts[index];
+        let nodes_a = graph_a.get_all_nodes();
+        for node in graph_b.get_all_nodes() {
+            graph_a.add_node(node);
+            for neighbour in graph_b.get_neighbours(node) {
+                graph_a.add_node(*neighbour);
+                graph_a.add_edge(node, *neighbour);
+            }
+            if join {
+                for other in &nodes_a {
+                    graph_a.add_edge(node, *other);
+                }
+            }
+        }
+    }
+    parts.pop().unwrap()
+}
+
+#[test]
+fn test_recognize_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+
+    assert_eq!(recognize_cograph(&graph).unwrap().len(), 0);
+    assert!(recognize_cograph(&Graph::new()).is_none());
+}
+
+#[test]
+fn test_recognize_path() {
+    let path = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    let short_path = Graph::from_edges(vec![(1, 2), (2, 3)]);
+
+    assert!(recognize_cograph(&path).is_none());
+    assert_eq!(
+        verify(&short_path, &recognize_cograph(&short_path).unwrap()),
+        Ok(0)
+    );
+}
+
+#[test]
+fn test_cotree_kinds() {
+    //Two triangles
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)]);
+
+    let cotree = Cotree::from_graph(&graph).unwrap();
+
+    let root = cotree.get_root();
+    assert_eq!(cotree.get_kind(root), CotreeKind::Union);
+    assert_eq!(cotree.get_children(root).len(), 2);
+    for child in cotree.get_children(root) {
+        assert_eq!(cotree.get_kind(*child), CotreeKind::Join);
+        let mut leaves: Vec<CotreeKind> = cotree
+            .get_children(*child)
+            .iter()
+            .map(|leaf| cotree.get_kind(*leaf))
+            .collect();
+        leaves.sort_by_key(|kind| match kind {
+            CotreeKind::Leaf(node) => *node,
+            _ => 0,
+        });
+        assert!(
+            leaves == [1, 2, 3].map(CotreeKind::Leaf) || leaves == [4, 5, 6].map(CotreeKind::Leaf)
+        );
+    }
+}
+
+#[test]
+fn test_recognize_random_cographs() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for nodes in [2, 5, 10, 30, 60] {
+        for _ in 0..5 {
+            let graph = random_cograph(&mut rng, nodes);
+
+            let sequence = recognize_cograph(&graph).unwrap();
+
+            assert_eq!(verify(&graph, &sequence), Ok(0));
+        }
+    }
+}
+
+#[test]
+fn test_recognize_random_non_cographs() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..20 {
+        //A cograph with an induced path on four new nodes attached to all other nodes
+        let mut graph = random_cograph(&mut rng, 20);
+        let others = graph.get_all_nodes();
+        for (node_a, node_b) in [(21, 22), (22, 23), (23, 24)] {
+            graph.add_node(node_a);
+            graph.add_node(node_b);
+            graph.add_edge(node_a, node_b);
+        }
+        for node in 21..=24 {
+            for other in &others {
+                graph.add_edge(node, *other);
+            }
+        }
+
+        assert!(recognize_cograph(&graph).is_none());
+    }
+}
+
+#[test]
+fn test_recognize_large_star() {
+    let graph = Graph::from_edges((2..=100_000).map(|leaf| (1, leaf)).collect());
+
+    let sequence = recognize_cograph(&graph).unwrap();
+
+    assert_eq!(sequence.len(), 99_999);
+    assert_eq!(verify(&graph, &sequence), Ok(0));
+}
@@ -0,0 +1,110 @@
+//! This module contains generators for synthetic graphs
+//!
+//! The generated graphs have the vertices `1..=n`, so they can be written as .gr directly.
+//! Families with known twin-width are useful to check algorithms, the random families to
+//! benchmark them. Random graphs are reproducible with their seed.
+use crate::graph::Graph;
+use crate::random::Rng;
+
+/// A family of graphs together with its size
+#[derive(PartialEq, Debug, Clone)]
+pub enum Family {
+    /// A path with n nodes
+    Path(u32),
+    /// A cycle with n nodes
+    Cycle(u32),
+    /// A grid with rows and columns
+    Grid(u32, u32),
+    /// A complete graph with n nodes
+    Complete(u32),
+    /// A complete bipartite graph with both sides
+    CompleteBipartite(u32, u32),
+    /// A uniformly random recursive tree with n nodes, every node is attached to an earlier one
+    RandomTree(u32),
+    /// A random graph with n nodes, where every edge exists with probability p
+    Random(u32, f64),
+}
+
+impl Family {
+    /// Generates a graph of the family
+    ///
+    /// # Parameters
+    /// * seed: The seed of random families, other families ignore it
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::generate::Family;
+    /// let grid = Family::Grid(3, 4).generate(0);
+    /// assert_eq!(grid.get_all_nodes().len(), 12);
+    /// assert_eq!(grid.get_edges().len(), 17);
+    ///
+    /// assert_eq!(Family::Random(20, 0.5).generate(7), Family::Random(20, 0.5).generate(7));
+    /// ```
+    pub fn generate(&self, seed: u64) -> Graph {
+        let mut rng = Rng::new(seed);
+        let (n, edges): (u32, Vec<(u32, u32)>) = match *self {
+            Family::Path(n) => (n, (1..n).map(|node| (node, node + 1)).collect()),
+            Family::Cycle(n) => {
+                let mut edges: Vec<(u32, u32)> = (1..n).map(|node| (node, node + 1)).collect();
+                if n >= 3 {
+                    edges.push((n, 1));
+                }
+                (n, edges)
+            }
+            Family::Grid(rows, columns) => {
+                let node = |row: u32, column: u32| row * columns + column + 1;
+                let mut edges = Vec::new();
+                for row in 0..rows {
+                    for column in 0..columns {
+                        if column + 1 < columns {
+                            edges.push((node(row, column), node(row, column + 1)));
+                        }
+                        if row + 1 < rows {
+                            edges.push((node(row, column), node(row + 1, column)));
+                        }
+                    }
+                }
+                (rows * columns, edges)
+            }
+            Family::Complete(n) => {
+                let edges = (1..=n)
+                    .flat_map(|a| (a + 1..=n).map(move |b| (a, b)))
+                    .collect();
+                (n, edges)
+            }
+            Family::CompleteBipartite(side_a, side_b) => {
+                let edges = (1..=side_a)
+                    .flat_map(|a| (side_a + 1..=side_a + side_b).map(move |b| (a, b)))
+                    .collect();
+                (side_a + side_b, edges)
+            }
+            Family::RandomTree(n) => {
+                let edges = (2..=n)
+                    .map(|node| (rng.below(node as usize - 1) as u32 + 1, node))
+                    .collect();
+                (n, edges)
+            }
+            Family::Random(n, probability) => {
+                let mut edges = Vec::new();
+                for a in 1..=n {
+                    for b in a + 1..=n {
+                        if rng.next_f64() < probability {
+                            edges.push((a, b));
+                        }
+                    }
+                }
+                (n, edges)
+            }
+        };
+
+        let mut graph = Graph::new();
+        (1..=n).for_each(|node| graph.add_node(node));
+        for (node_a, node_b) in edges {
+            graph.add_edge(node_a, node_b);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,61 @@
+use crate::graph::GraphClass;
+
+use super::Family;
+
+#[test]
+fn test_generate_classes() {
+    assert!(matches!(
+        Family::Path(5).generate(0).get_class(),
+        Some(GraphClass::Path(_))
+    ));
+    assert!(matches!(
+        Family::Cycle(6).generate(0).get_class(),
+        Some(GraphClass::Cycle(_))
+    ));
+    assert!(matches!(
+        Family::Grid(4, 5).generate(0).get_class(),
+        Some(GraphClass::Grid(_))
+    ));
+    assert!(matches!(
+        Family::CompleteBipartite(2, 3).generate(0).get_class(),
+        Some(GraphClass::CompleteBipartite(_, _))
+    ));
+}
+
+#[test]
+fn test_generate_complete() {
+    let graph = Family::Complete(6).generate(0);
+
+    assert_eq!(graph.get_edges().len(), 15);
+    assert_eq!(graph.get_max_degree(), 5);
+}
+
+#[test]
+fn test_generate_random_tree() {
+    let graph = Family::RandomTree(30).generate(3);
+
+    assert_eq!(graph.get_all_nodes().len(), 30);
+    assert_eq!(graph.get_edges().len(), 29);
+    assert_eq!(graph.get_components().len(), 1);
+    assert_ne!(graph, Family::RandomTree(30).generate(4));
+}
+
+#[test]
+fn test_generate_random() {
+    let empty = Family::Random(10, 0.0).generate(1);
+    let complete = Family::Random(10, 1.0).generate(1);
+    let half = Family::Random(40, 0.5).generate(1);
+
+    assert_eq!(empty.get_all_nodes().len(), 10);
+    assert_eq!(empty.get_edges().len(), 0);
+    assert_eq!(complete.get_edges().len(), 45);
+    assert!((300..480).contains(&half.get_edges().len()));
+    assert_eq!(half, Family::Random(40, 0.5).generate(1));
+}
+
+#[test]
+fn test_generate_small() {
+    assert_eq!(Family::Path(0).generate(0).get_all_nodes().len(), 0);
+    assert_eq!(Family::Cycle(2).generate(0).get_edges(), vec![(1, 2)]);
+    assert_eq!(Family::RandomTree(1).generate(0).get_edges(), vec![]);
+}
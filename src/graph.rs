@@ -1,6 +1,19 @@
 //! This modules contains stuff for basic graph tasks
 use std::collections::{HashMap, HashSet};
 
+mod classes;
+mod components;
+mod dense;
+mod formats;
+mod gr;
+mod trigraph;
+pub use classes::GraphClass;
+pub use dense::DenseGraph;
+pub use formats::{FormatError, GraphFormat};
+pub use gr::GrParseError;
+pub(crate) use gr::{is_comment, tokens};
+pub use trigraph::{ContractionRecord, EdgeColor, Trigraph};
+
 /// A Graph implementation using adjacency HashSet
 ///
 /// The idea behind using a HashSet is to make the comparison of neighbourhoods easy
@@ -60,26 +73,46 @@ impl Graph {
     /// Reads graph from a string following the .gr format.
     /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
     ///
+    /// If the string contains a `p tww n m` line, all vertices `1..=n` are created,
+    /// so isolated vertices are part of the graph as well.
+    /// Without a p-line only the vertices of the edges exist.
+    ///
     /// **The function assumes that your provided string is valid!**
+    /// Use `Graph::try_from_gr` for input which needs to be validated.
     ///
     /// # Parameter
-    /// * gr: string slice containing the
+    /// * gr: string slice containing the graph in .gr format
     ///
     /// # Returns
     /// * A new graph instance with respect to the gr-string.
     ///
+    /// # Panics
+    /// If the number of edges does not match m of the p-line
+    ///
     /// # Example
     /// ```
     /// use twinwidth::graph::Graph;
-    ///let gr = "1 2\n\
+    ///let gr = "p tww 4 2\n\
+    ///          1 2\n\
     ///          2 3";
     ///let graph = Graph::from_gr(gr);
+    ///assert_eq!(graph.get_all_nodes().len(), 4);
     /// ```
     pub fn from_gr(gr: &str) -> Self {
-        //TODO: REFACTOR!
+        let mut header: Option<(u32, usize)> = None;
         let mut edges: Vec<(u32, u32)> = Vec::new();
 
         for line in gr.lines() {
+            let mut parts = line.split_whitespace();
+            if let (Some("p"), Some("tww"), Some(n), Some(m)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            {
+                if let (Ok(n), Ok(m)) = (n.parse(), m.parse()) {
+                    header = Some((n, m));
+                }
+                continue;
+            }
+
             let mut parts = line.split_whitespace().map(|s| s.parse::<u32>());
             let edge: (u32, u32) = match (parts.next(), parts.next()) {
                 (Some(Ok(a)), Some(Ok(b))) => (a, b),
@@ -88,7 +121,24 @@ impl Graph {
 
             edges.push(edge);
         }
-        Self::from_edges(edges)
+
+        let mut graph = Self::new();
+        if let Some((n, m)) = header {
+            assert_eq!(
+                edges.len(),
+                m,
+                "The p-line announces {} edges, but {} were found",
+                m,
+                edges.len()
+            );
+            (1..=n).for_each(|node| graph.add_node(node));
+        }
+        for (node_a, node_b) in edges {
+            graph.add_node(node_a);
+            graph.add_node(node_b);
+            graph.add_edge(node_a, node_b);
+        }
+        graph
     }
 
     /// Adds a new node without any edges to the graph
@@ -103,7 +153,7 @@ impl Graph {
     /// graph.add_node(1);
     /// ```
     pub fn add_node(&mut self, node: u32) {
-        if self.adj_set.get(&node).is_some() {
+        if self.adj_set.contains_key(&node) {
             return;
         }
         self.adj_set.insert(node, HashSet::new());
@@ -226,6 +276,33 @@ impl Graph {
         }
     }
 
+    /// Removes a node and all its edges
+    ///
+    /// # Parameters
+    /// * node: The node to remove
+    ///
+    /// # Panics
+    /// If the node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let mut graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    /// graph.remove_node(2);
+    /// assert!(graph.get_neighbours(1).is_empty());
+    /// ```
+    pub fn remove_node(&mut self, node: u32) {
+        let neighbours = match self.adj_set.remove(&node) {
+            Some(set) => set,
+            None => panic!("Node does not exist"),
+        };
+        for neighbour in neighbours {
+            if let Some(set) = self.adj_set.get_mut(&neighbour) {
+                set.remove(&node);
+            }
+        }
+    }
+
     /// Gets all existing nodes from the graph
     ///
     /// # Return
@@ -245,6 +322,60 @@ impl Graph {
         self.adj_set.keys().cloned().collect()
     }
 
+    /// Gets all edges of the graph, every edge once with the smaller node first, in sorted order
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let graph = Graph::from_edges(vec![(3, 2), (1, 2)]);
+    /// assert_eq!(graph.get_edges(), vec![(1, 2), (2, 3)]);
+    /// ```
+    pub fn get_edges(&self) -> Vec<(u32, u32)> {
+        let mut edges: Vec<(u32, u32)> = self
+            .adj_set
+            .iter()
+            .flat_map(|(node, neighbours)| {
+                neighbours
+                    .iter()
+                    .filter(move |neighbour| *node < **neighbour)
+                    .map(move |neighbour| (*node, *neighbour))
+            })
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    /// Creates the subgraph induced by some nodes, i.e. with all edges between them
+    ///
+    /// # Parameters
+    /// * nodes: The nodes of the subgraph
+    ///
+    /// # Returns
+    /// * New Graph with the given nodes
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 1), (3, 4)]);
+    /// let triangle = graph.induced_subgraph(&[1, 2, 3]);
+    /// assert_eq!(triangle.get_neighbours(3).len(), 2);
+    /// ```
+    pub fn induced_subgraph(&self, nodes: &[u32]) -> Graph {
+        let mut subgraph = Graph::new();
+        nodes.iter().for_each(|node| subgraph.add_node(*node));
+        for node in nodes {
+            for neighbour in self.get_neighbours(*node) {
+                if subgraph.adj_set.contains_key(neighbour) {
+                    subgraph.add_edge(*node, *neighbour);
+                }
+            }
+        }
+        subgraph
+    }
+
     /// Gets the max degree of the graph
     /// TODO: Implement tests and complete documentation
     pub fn get_max_degree(&self) -> usize {
@@ -0,0 +1,230 @@
+//! This module contains the detection of graph classes whose twin-width is known
+//!
+//! The detection is linear in the size of the graph. It also returns the structure it found,
+//! e.g. the order of a path or the rows of a grid, so contraction sequences can be built from it.
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::Graph;
+
+/// A graph class with known twin-width together with the structure of the graph
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum GraphClass {
+    /// A complete bipartite graph with its two sides, each of them sorted
+    CompleteBipartite(Vec<u32>, Vec<u32>),
+    /// A path with its nodes in path order
+    Path(Vec<u32>),
+    /// A cycle with its nodes in cycle order
+    Cycle(Vec<u32>),
+    /// A tree which is neither a path nor complete bipartite
+    Tree,
+    /// A grid with at least two rows and two columns, every row holds its nodes in column order
+    Grid(Vec<Vec<u32>>),
+}
+
+impl Graph {
+    /// Detects whether the graph belongs to a class with known twin-width.
+    ///
+    /// The classes are tested in the order of the variants of `GraphClass`, so e.g. a star is
+    /// complete bipartite and a path is not a tree.
+    ///
+    /// # Returns
+    /// * The first matching class, None if the graph is empty, disconnected or in no class
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{Graph, GraphClass};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    /// assert_eq!(graph.get_class(), Some(GraphClass::Path(vec![1, 2, 3, 4])));
+    ///
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    /// assert_eq!(graph.get_class(), Some(GraphClass::Cycle(vec![1, 2, 3, 4, 5])));
+    /// ```
+    pub fn get_class(&self) -> Option<GraphClass> {
+        let mut nodes = self.get_all_nodes();
+        nodes.sort();
+        if nodes.is_empty() || self.get_components().len() > 1 {
+            return None;
+        }
+        let edge_count = nodes
+            .iter()
+            .map(|node| self.get_neighbours(*node).len())
+            .sum::<usize>()
+            / 2;
+
+        if let Some((side_a, side_b)) = self.complete_bipartite_sides(&nodes, edge_count) {
+            return Some(GraphClass::CompleteBipartite(side_a, side_b));
+        }
+        if edge_count + 1 == nodes.len() {
+            if self.get_max_degree() <= 2 {
+                return Some(GraphClass::Path(self.walk(&nodes)));
+            }
+            return Some(GraphClass::Tree);
+        }
+        if nodes.len() >= 3
+            && nodes
+                .iter()
+                .all(|node| self.get_neighbours(*node).len() == 2)
+        {
+            return Some(GraphClass::Cycle(self.walk(&nodes)));
+        }
+        self.grid_rows(&nodes, edge_count).map(GraphClass::Grid)
+    }
+
+    /// Gets the length of the shortest path from a node to every node it reaches
+    ///
+    /// # Parameters
+    /// * start: The node to start from
+    ///
+    /// # Panics
+    /// If the node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    /// let distances = graph.get_distances(2);
+    /// assert_eq!(distances[&4], 2);
+    /// assert_eq!(distances[&2], 0);
+    /// ```
+    pub fn get_distances(&self, start: u32) -> HashMap<u32, usize> {
+        if !self.adj_set.contains_key(&start) {
+            panic!("Node does not exist");
+        }
+
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            for neighbour in self.get_neighbours(node) {
+                if !distances.contains_key(neighbour) {
+                    distances.insert(*neighbour, distance + 1);
+                    queue.push_back(*neighbour);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Splits the graph into two sides which are independent and completely adjacent to each other.
+    /// The second side has to be the neighbourhood of the smallest node.
+    fn complete_bipartite_sides(
+        &self,
+        nodes: &[u32],
+        edge_count: usize,
+    ) -> Option<(Vec<u32>, Vec<u32>)> {
+        let side_b: HashSet<u32> = self.get_neighbours(nodes[0]).clone();
+        if side_b.is_empty() {
+            return None;
+        }
+        let side_a: Vec<u32> = nodes
+            .iter()
+            .filter(|node| !side_b.contains(node))
+            .copied()
+            .collect();
+
+        //Every edge has an end in the first side, so the second side is independent
+        if side_a.len() * side_b.len() != edge_count
+            || side_a
+                .iter()
+                .any(|node| self.get_neighbours(*node) != &side_b)
+        {
+            return None;
+        }
+
+        let mut side_b: Vec<u32> = side_b.into_iter().collect();
+        side_b.sort();
+        Some((side_a, side_b))
+    }
+
+    /// Walks along a graph with max degree 2, starting at an end if it has one
+    fn walk(&self, nodes: &[u32]) -> Vec<u32> {
+        let start = nodes
+            .iter()
+            .find(|node| self.get_neighbours(**node).len() <= 1)
+            .unwrap_or(&nodes[0]);
+
+        let mut order = vec![*start];
+        let mut visited = HashSet::from([*start]);
+        loop {
+            let last = order[order.len() - 1];
+            let next = self
+                .get_neighbours(last)
+                .iter()
+                .filter(|node| !visited.contains(node))
+                .min();
+            match next {
+                Some(node) => {
+                    visited.insert(*node);
+                    order.push(*node);
+                }
+                None => break,
+            }
+        }
+        order
+    }
+
+    /// Finds the rows of a grid.
+    ///
+    /// In a grid with corner (0, 0) and the corner (0, columns - 1) next to it, the distances of
+    /// the node (row, column) to them are row + column and row + columns - 1 - column,
+    /// so the distances give the position of every node, which is then checked against the edges.
+    fn grid_rows(&self, nodes: &[u32], edge_count: usize) -> Option<Vec<Vec<u32>>> {
+        let corners: Vec<u32> = nodes
+            .iter()
+            .filter(|node| self.get_neighbours(**node).len() == 2)
+            .copied()
+            .collect();
+        if corners.len() != 4 || self.get_max_degree() > 4 {
+            return None;
+        }
+
+        let from_first = self.get_distances(corners[0]);
+        let second = *corners[1..].iter().min_by_key(|node| from_first[*node])?;
+        let from_second = self.get_distances(second);
+
+        let columns = from_first[&second] + 1;
+        let rows = nodes.len() / columns;
+        if rows < 2 || rows * columns != nodes.len() {
+            return None;
+        }
+        if edge_count != rows * (columns - 1) + columns * (rows - 1) {
+            return None;
+        }
+
+        let mut grid: Vec<Vec<Option<u32>>> = vec![vec![None; columns]; rows];
+        let mut positions: HashMap<u32, (usize, usize)> = HashMap::new();
+        for node in nodes {
+            let sum = from_first[node] + from_second[node];
+            if sum < columns - 1 || !(sum - (columns - 1)).is_multiple_of(2) {
+                return None;
+            }
+            let row = (sum - (columns - 1)) / 2;
+            let column = from_first[node].checked_sub(row)?;
+            if row >= rows || column >= columns || grid[row][column].is_some() {
+                return None;
+            }
+            grid[row][column] = Some(*node);
+            positions.insert(*node, (row, column));
+        }
+
+        //The number of edges is right, so the graph is the grid if all edges are grid edges
+        for node in nodes {
+            let (row, column) = positions[node];
+            for neighbour in self.get_neighbours(*node) {
+                let (other_row, other_column) = positions[neighbour];
+                if row.abs_diff(other_row) + column.abs_diff(other_column) != 1 {
+                    return None;
+                }
+            }
+        }
+
+        Some(
+            grid.into_iter()
+                .map(|row| row.into_iter().map(Option::unwrap).collect())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
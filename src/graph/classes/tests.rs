@@ -0,0 +1,159 @@
+use crate::graph::{Graph, GraphClass};
+
+/// Gets the edges of a grid whose nodes are numbered row by row starting with 1
+fn grid_edges(rows: u32, columns: u32) -> Vec<(u32, u32)> {
+    let mut edges = Vec::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let node = row * columns + column + 1;
+            if column + 1 < columns {
+                edges.push((node, node + 1));
+            }
+            if row + 1 < rows {
+                edges.push((node, node + columns));
+            }
+        }
+    }
+    edges
+}
+
+fn grid(rows: u32, columns: u32) -> Graph {
+    Graph::from_edges(grid_edges(rows, columns))
+}
+
+#[test]
+fn test_get_class_empty_and_disconnected() {
+    assert_eq!(Graph::new().get_class(), None);
+    assert_eq!(Graph::from_edges(vec![(1, 2), (3, 4)]).get_class(), None);
+}
+
+#[test]
+fn test_get_class_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(7);
+
+    assert_eq!(graph.get_class(), Some(GraphClass::Path(vec![7])));
+}
+
+#[test]
+fn test_get_class_complete_bipartite() {
+    let graph = Graph::from_edges(vec![(1, 4), (1, 5), (2, 4), (2, 5), (3, 4), (3, 5)]);
+    let star = Graph::from_edges(vec![(1, 2), (1, 3), (1, 4)]);
+
+    assert_eq!(
+        graph.get_class(),
+        Some(GraphClass::CompleteBipartite(vec![1, 2, 3], vec![4, 5]))
+    );
+    assert_eq!(
+        star.get_class(),
+        Some(GraphClass::CompleteBipartite(vec![1], vec![2, 3, 4]))
+    );
+}
+
+#[test]
+fn test_get_class_path_starts_at_an_end() {
+    let graph = Graph::from_edges(vec![(3, 1), (1, 4), (4, 2), (2, 5)]);
+
+    assert_eq!(
+        graph.get_class(),
+        Some(GraphClass::Path(vec![3, 1, 4, 2, 5]))
+    );
+}
+
+#[test]
+fn test_get_class_tree() {
+    let graph = Graph::from_edges(vec![(1, 2), (1, 3), (1, 4), (2, 5)]);
+
+    assert_eq!(graph.get_class(), Some(GraphClass::Tree));
+}
+
+#[test]
+fn test_get_class_cycle() {
+    let graph = Graph::from_edges(vec![(1, 3), (3, 5), (5, 2), (2, 4), (4, 6), (6, 1)]);
+
+    assert_eq!(
+        graph.get_class(),
+        Some(GraphClass::Cycle(vec![1, 3, 5, 2, 4, 6]))
+    );
+}
+
+#[test]
+fn test_get_class_grid() {
+    for (rows, columns) in [(2, 3), (3, 3), (3, 5), (5, 3), (4, 7)] {
+        let Some(GraphClass::Grid(found)) = grid(rows, columns).get_class() else {
+            panic!("{}x{} is a grid", rows, columns);
+        };
+
+        //The rows are found up to the symmetries of the grid
+        let (found_rows, found_columns) = (found.len() as u32, found[0].len() as u32);
+        assert!(
+            (found_rows, found_columns) == (rows, columns)
+                || (found_rows, found_columns) == (columns, rows)
+        );
+        let graph = grid(rows, columns);
+        for (row, nodes) in found.iter().enumerate() {
+            for (column, node) in nodes.iter().enumerate() {
+                if column + 1 < nodes.len() {
+                    assert!(graph.get_neighbours(*node).contains(&nodes[column + 1]));
+                }
+                if row + 1 < found.len() {
+                    assert!(graph
+                        .get_neighbours(*node)
+                        .contains(&found[row + 1][column]));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_get_class_almost_grid() {
+    //Removing an inner edge keeps the corners but breaks the grid
+    let graph = Graph::from_edges(
+        grid_edges(4, 4)
+            .into_iter()
+            .filter(|edge| *edge != (6, 7))
+            .collect(),
+    );
+    //A torus has no corners
+    let torus = Graph::from_edges(vec![
+        (1, 2),
+        (2, 3),
+        (3, 1),
+        (4, 5),
+        (5, 6),
+        (6, 4),
+        (7, 8),
+        (8, 9),
+        (9, 7),
+        (1, 4),
+        (4, 7),
+        (7, 1),
+        (2, 5),
+        (5, 8),
+        (8, 2),
+        (3, 6),
+        (6, 9),
+        (9, 3),
+    ]);
+
+    assert_eq!(graph.get_class(), None);
+    assert_eq!(torus.get_class(), None);
+}
+
+#[test]
+fn test_get_distances() {
+    let graph = grid(3, 4);
+
+    let distances = graph.get_distances(1);
+
+    assert_eq!(distances.len(), 12);
+    assert_eq!(distances[&12], 5);
+    assert_eq!(distances[&6], 2);
+}
+
+#[test]
+#[should_panic(expected = "Node does not exist")]
+fn test_get_distances_missing_node() {
+    Graph::from_edges(vec![(1, 2)]).get_distances(3);
+}
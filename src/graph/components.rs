@@ -0,0 +1,88 @@
+//! This module contains the decomposition of a graph into components and co-components
+use std::collections::{BTreeSet, HashSet};
+
+use super::Graph;
+
+impl Graph {
+    /// Gets the connected components of the graph
+    ///
+    /// # Returns
+    /// * The sorted nodes of every component, ordered by their smallest node
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let mut graph = Graph::from_edges(vec![(1, 2), (3, 4)]);
+    /// graph.add_node(5);
+    /// assert_eq!(graph.get_components(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    pub fn get_components(&self) -> Vec<Vec<u32>> {
+        let mut nodes = self.get_all_nodes();
+        nodes.sort();
+
+        let mut visited: HashSet<u32> = HashSet::new();
+        let mut components = Vec::new();
+        for start in nodes {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                for neighbour in self.get_neighbours(node) {
+                    if visited.insert(*neighbour) {
+                        component.push(*neighbour);
+                        stack.push(*neighbour);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Gets the connected components of the complement of the graph.
+    ///
+    /// A node is only compared with the nodes which are not visited yet, and every comparison
+    /// either visits a node or finds an edge, so the complement is never built.
+    ///
+    /// # Returns
+    /// * The sorted nodes of every co-component, ordered by their smallest node
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// //A square is the join of two non-edges
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+    /// assert_eq!(graph.get_co_components(), vec![vec![1, 3], vec![2, 4]]);
+    /// ```
+    pub fn get_co_components(&self) -> Vec<Vec<u32>> {
+        let mut unvisited: BTreeSet<u32> = self.get_all_nodes().into_iter().collect();
+
+        let mut components = Vec::new();
+        while let Some(start) = unvisited.pop_first() {
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                let neighbours = self.get_neighbours(node);
+                let non_neighbours: Vec<u32> = unvisited
+                    .iter()
+                    .filter(|other| !neighbours.contains(other))
+                    .copied()
+                    .collect();
+                for other in non_neighbours {
+                    unvisited.remove(&other);
+                    component.push(other);
+                    stack.push(other);
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests;
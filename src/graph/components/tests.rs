@@ -0,0 +1,42 @@
+use crate::graph::Graph;
+
+#[test]
+fn test_get_components_empty() {
+    assert!(Graph::new().get_components().is_empty());
+    assert!(Graph::new().get_co_components().is_empty());
+}
+
+#[test]
+fn test_get_components() {
+    let mut graph = Graph::from_edges(vec![(5, 1), (1, 3), (2, 4)]);
+    graph.add_node(6);
+
+    assert_eq!(
+        graph.get_components(),
+        vec![vec![1, 3, 5], vec![2, 4], vec![6]]
+    );
+}
+
+#[test]
+fn test_get_co_components_connected_complement() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+
+    assert_eq!(graph.get_co_components(), vec![vec![1, 2, 3, 4]]);
+}
+
+#[test]
+fn test_get_co_components() {
+    //The join of a path 1 - 2 - 3 and the independent set {4, 5}, 2 is adjacent to all nodes
+    let mut graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+    for node_a in [1, 2, 3] {
+        for node_b in [4, 5] {
+            graph.add_node(node_b);
+            graph.add_edge(node_a, node_b);
+        }
+    }
+
+    assert_eq!(
+        graph.get_co_components(),
+        vec![vec![1, 3], vec![2], vec![4, 5]]
+    );
+}
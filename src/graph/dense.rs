@@ -0,0 +1,301 @@
+//! This module contains a dense graph representation
+use std::collections::HashMap;
+
+use super::Graph;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A Graph implementation using one bitset row per vertex
+///
+/// The vertices are stored at the indices `0..n` of the rows, a remap table translates
+/// the vertex ids to their index. Compared to `Graph` no hashing is necessary to access
+/// a neighbourhood and neighbourhoods can be compared word by word.
+/// Contracted vertices keep their (empty) row, so the indices of the other vertices stay stable.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct DenseGraph {
+    rows: Vec<Vec<u64>>,
+    ids: Vec<u32>,
+    indices: HashMap<u32, usize>,
+    alive: Vec<bool>,
+}
+
+impl DenseGraph {
+    /// Creates an empty `DenseGraph`.
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let graph: DenseGraph = DenseGraph::new();
+    /// ```
+    pub fn new() -> Self {
+        DenseGraph {
+            rows: Vec::new(),
+            ids: Vec::new(),
+            indices: HashMap::new(),
+            alive: Vec::new(),
+        }
+    }
+
+    /// Creates an `DenseGraph` from a Vector of edges.
+    ///
+    /// # Parameters
+    /// * edges: A vector of edges
+    ///
+    /// # Returns
+    /// * New DenseGraph with edges
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let edges = vec![(1,2),(2,3),(3,4)];
+    /// let graph: DenseGraph = DenseGraph::from_edges(edges);
+    /// ```
+    pub fn from_edges(edges: Vec<(u32, u32)>) -> Self {
+        let mut g = Self::new();
+        for (node_a, node_b) in edges {
+            g.add_node(node_a);
+            g.add_node(node_b);
+            g.add_edge(node_a, node_b);
+        }
+        g
+    }
+
+    /// Adds a new node without any edges to the graph
+    ///
+    /// # Parameters
+    /// * node: The node to add to the graph
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let mut graph: DenseGraph = DenseGraph::new();
+    /// graph.add_node(1);
+    /// ```
+    pub fn add_node(&mut self, node: u32) {
+        if self.indices.contains_key(&node) {
+            return;
+        }
+
+        let index = self.ids.len();
+        let words = index / WORD_BITS + 1;
+        if words > self.words() {
+            self.rows.iter_mut().for_each(|row| row.resize(words, 0));
+        }
+
+        self.rows.push(vec![0; words]);
+        self.ids.push(node);
+        self.alive.push(true);
+        self.indices.insert(node, index);
+    }
+
+    /// Adds a new edge between two nodes
+    ///
+    /// # Parameters
+    /// * node_a: the first node of the edge
+    /// * node_b: the second node of the edge
+    ///
+    /// # Returns
+    /// * returns true if the edge was newly created
+    /// * returns false if the edge already existed
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let mut graph: DenseGraph = DenseGraph::new();
+    /// graph.add_node(1);
+    /// graph.add_node(2);
+    /// graph.add_edge(1, 2);
+    /// ```
+    pub fn add_edge(&mut self, node_a: u32, node_b: u32) -> bool {
+        let index_a = self.index(node_a);
+        let index_b = self.index(node_b);
+
+        let existed = self.contains(index_a, index_b);
+        self.set(index_a, index_b);
+        self.set(index_b, index_a);
+
+        !existed
+    }
+
+    /// Gets the adjacent nodes
+    ///
+    /// # Parameters
+    /// * node: The node wich neighbours should be returned
+    ///
+    /// # Returns
+    /// * Vector of adjacent nodes in the order of their index
+    ///
+    /// # Panics
+    /// * If the node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let graph = DenseGraph::from_edges(vec![(1, 2)]);
+    /// assert_eq!(graph.get_neighbours(1), vec![2]);
+    /// ```
+    pub fn get_neighbours(&self, node: u32) -> Vec<u32> {
+        let index = self.index(node);
+        self.row_indices(index)
+            .map(|index| self.ids[index])
+            .collect()
+    }
+
+    /// Gets the degree of a node
+    ///
+    /// # Panics
+    /// * If the node does not exist
+    pub fn get_degree(&self, node: u32) -> usize {
+        Self::count(&self.rows[self.index(node)])
+    }
+
+    /// Contracts two nodes
+    /// The emerging node will be saved under node_a.
+    /// node_b will be deleted.
+    ///
+    /// # Parameters
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let mut graph = DenseGraph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    /// graph.contract_nodes(2, 3);
+    /// assert_eq!(graph.get_neighbours(2), vec![1, 4]);
+    /// ```
+    pub fn contract_nodes(&mut self, node_a: u32, node_b: u32) {
+        let index_a = self.index(node_a);
+        let index_b = self.index(node_b);
+
+        //The neighbours of b are exactly the rows which contain b
+        let neighbours_b: Vec<usize> = self.row_indices(index_b).collect();
+        for neighbour in neighbours_b {
+            self.unset(neighbour, index_b);
+            if neighbour != index_a {
+                self.set(neighbour, index_a);
+                self.set(index_a, neighbour);
+            }
+        }
+
+        self.rows[index_b].iter_mut().for_each(|word| *word = 0);
+        self.unset(index_a, index_b);
+        self.alive[index_b] = false;
+        self.indices.remove(&node_b);
+    }
+
+    /// Gets all existing nodes from the graph
+    ///
+    /// # Return
+    /// * Returns a vector containig all nodes
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::DenseGraph;
+    /// let graph = DenseGraph::from_edges(vec![(1, 2)]);
+    /// let nodes = graph.get_all_nodes();
+    /// ```
+    pub fn get_all_nodes(&self) -> Vec<u32> {
+        self.ids
+            .iter()
+            .zip(&self.alive)
+            .filter(|(_, alive)| **alive)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Gets the max degree of the graph
+    ///
+    /// # Return
+    /// * The maximum number of neighbours of a node, 0 for an empty graph
+    pub fn get_max_degree(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| Self::count(row))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn words(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    fn index(&self, node: u32) -> usize {
+        match self.indices.get(&node) {
+            Some(index) => *index,
+            None => panic!("Node does not exist"),
+        }
+    }
+
+    fn contains(&self, row: usize, column: usize) -> bool {
+        self.rows[row][column / WORD_BITS] & (1 << (column % WORD_BITS)) != 0
+    }
+
+    fn set(&mut self, row: usize, column: usize) {
+        self.rows[row][column / WORD_BITS] |= 1 << (column % WORD_BITS);
+    }
+
+    fn unset(&mut self, row: usize, column: usize) {
+        self.rows[row][column / WORD_BITS] &= !(1 << (column % WORD_BITS));
+    }
+
+    fn count(row: &[u64]) -> usize {
+        row.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn row_indices(&self, row: usize) -> impl Iterator<Item = usize> + '_ {
+        self.rows[row]
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(word_index * WORD_BITS + bit)
+                })
+            })
+    }
+}
+
+impl From<&Graph> for DenseGraph {
+    fn from(graph: &Graph) -> Self {
+        let mut nodes = graph.get_all_nodes();
+        nodes.sort();
+
+        let mut dense = DenseGraph::new();
+        nodes.iter().for_each(|node| dense.add_node(*node));
+        for node in nodes {
+            for neighbour in graph.get_neighbours(node) {
+                dense.add_edge(node, *neighbour);
+            }
+        }
+        dense
+    }
+}
+
+impl From<&DenseGraph> for Graph {
+    fn from(dense: &DenseGraph) -> Self {
+        let mut graph = Graph::new();
+        let nodes = dense.get_all_nodes();
+        nodes.iter().for_each(|node| graph.add_node(*node));
+        for node in nodes {
+            for neighbour in dense.get_neighbours(node) {
+                graph.add_edge(node, neighbour);
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests;
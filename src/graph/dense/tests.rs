@@ -0,0 +1,89 @@
+use super::DenseGraph;
+use crate::graph::Graph;
+
+#[test]
+fn test_new_dense_graph() {
+    let graph = DenseGraph::new();
+
+    assert_eq!(graph.get_all_nodes().len(), 0);
+    assert_eq!(graph.get_max_degree(), 0);
+}
+
+#[test]
+fn test_add_node_grows_rows() {
+    let mut graph = DenseGraph::new();
+
+    (1..=130).for_each(|node| graph.add_node(node));
+    graph.add_node(1);
+
+    assert_eq!(graph.get_all_nodes().len(), 130);
+    assert!(graph.rows.iter().all(|row| row.len() == 3));
+}
+
+#[test]
+fn test_add_edge() {
+    let mut graph = DenseGraph::new();
+
+    graph.add_node(1);
+    graph.add_node(100);
+
+    assert!(graph.add_edge(1, 100));
+    assert!(!graph.add_edge(100, 1));
+    assert_eq!(graph.get_neighbours(1), vec![100]);
+    assert_eq!(graph.get_neighbours(100), vec![1]);
+}
+
+#[test]
+#[should_panic]
+fn test_add_edge_panic() {
+    let mut graph = DenseGraph::new();
+    graph.add_node(1);
+    graph.add_edge(1, 2);
+}
+
+#[test]
+fn test_contract_nodes() {
+    let mut graph = DenseGraph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+
+    graph.contract_nodes(2, 3);
+
+    assert_eq!(graph.get_all_nodes(), vec![1, 2, 4]);
+    assert_eq!(graph.get_neighbours(2), vec![1, 4]);
+    assert_eq!(graph.get_neighbours(4), vec![2]);
+    assert_eq!(graph.get_max_degree(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_contract_nodes_panic_removed_node() {
+    let mut graph = DenseGraph::from_edges(vec![(1, 2), (2, 3)]);
+
+    graph.contract_nodes(1, 2);
+    graph.contract_nodes(1, 2);
+}
+
+#[test]
+fn test_conversion_round_trip() {
+    let mut graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1), (5, 2)]);
+    graph.add_node(6);
+
+    let dense = DenseGraph::from(&graph);
+
+    assert_eq!(dense.get_degree(2), 3);
+    assert_eq!(dense.get_degree(6), 0);
+    assert_eq!(Graph::from(&dense), graph);
+}
+
+#[test]
+fn test_contract_like_graph() {
+    let mut graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1), (5, 2), (5, 6)]);
+    let mut dense = DenseGraph::from(&graph);
+
+    for (node_a, node_b) in [(1, 3), (2, 5), (4, 6), (1, 2)] {
+        graph.contract_nodes(node_a, node_b);
+        dense.contract_nodes(node_a, node_b);
+
+        assert_eq!(Graph::from(&dense), graph);
+        assert_eq!(dense.get_max_degree(), graph.get_max_degree());
+    }
+}
@@ -0,0 +1,232 @@
+//! This module contains other graph formats than .gr, so graphs can be converted between them
+//!
+//! * DIMACS graphs with a `p edge n m` line and `e a b` edge lines
+//! * Edge lists with one `a b` edge per line, lines starting with `#` or `%` are comments
+//! * Graphviz dot, which can only be written
+//!
+//! Like in .gr the vertices are numbered from 1, so every read graph can be written as .gr.
+use std::{error::Error, fmt, fmt::Write, path::Path, str::FromStr};
+
+use super::{tokens, GrParseError, Graph};
+
+/// The supported graph formats
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum GraphFormat {
+    /// The .gr format of the pace challenge
+    Gr,
+    Dimacs,
+    EdgeList,
+    /// Graphviz dot, only for writing
+    Dot,
+}
+
+impl GraphFormat {
+    /// Guesses the format from the extension of a file
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::Path;
+    /// use twinwidth::graph::GraphFormat;
+    /// assert_eq!(GraphFormat::from_extension(Path::new("a/tiny001.gr")), Some(GraphFormat::Gr));
+    /// assert_eq!(GraphFormat::from_extension(Path::new("graph")), None);
+    /// ```
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "gr" => Some(GraphFormat::Gr),
+            "col" | "dimacs" => Some(GraphFormat::Dimacs),
+            "edges" | "txt" => Some(GraphFormat::EdgeList),
+            "dot" | "gv" => Some(GraphFormat::Dot),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GraphFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphFormat::Gr => write!(f, "gr"),
+            GraphFormat::Dimacs => write!(f, "dimacs"),
+            GraphFormat::EdgeList => write!(f, "edges"),
+            GraphFormat::Dot => write!(f, "dot"),
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = FormatError;
+
+    /// Reads a format from the names of `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gr" => Ok(GraphFormat::Gr),
+            "dimacs" => Ok(GraphFormat::Dimacs),
+            "edges" => Ok(GraphFormat::EdgeList),
+            "dot" => Ok(GraphFormat::Dot),
+            _ => Err(FormatError::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+/// Reasons why a graph can not be read in a format.
+/// Lines start at 1.
+#[derive(Debug)]
+pub enum FormatError {
+    /// The input is not a valid .gr graph
+    Gr(GrParseError),
+    /// A line is neither a comment, a p-line nor an edge
+    InvalidLine { line: usize },
+    /// The name is not the name of a format
+    UnknownFormat(String),
+    /// The format can only be written
+    NotReadable(GraphFormat),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Gr(err) => write!(f, "{err}"),
+            FormatError::InvalidLine { line } => write!(f, "line {line}: invalid line"),
+            FormatError::UnknownFormat(name) => write!(f, "'{name}' is not a graph format"),
+            FormatError::NotReadable(format) => {
+                write!(f, "graphs can not be read in the {format} format")
+            }
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Gr(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<GrParseError> for FormatError {
+    fn from(err: GrParseError) -> Self {
+        FormatError::Gr(err)
+    }
+}
+
+impl Graph {
+    /// Reads a graph in the given format
+    ///
+    /// # Parameters
+    /// * input: The graph in the format
+    /// * format: The format of the input
+    ///
+    /// # Returns
+    /// * A new graph instance
+    /// * A `FormatError` describing the first problem of the input
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{Graph, GraphFormat};
+    /// let graph = Graph::read_as("p edge 3 2\ne 1 2\ne 2 3\n", GraphFormat::Dimacs).unwrap();
+    /// assert_eq!(graph, Graph::read_as("1 2\n2 3\n", GraphFormat::EdgeList).unwrap());
+    /// ```
+    pub fn read_as(input: &str, format: GraphFormat) -> Result<Graph, FormatError> {
+        match format {
+            GraphFormat::Gr => Ok(Graph::try_from_gr(input)?),
+            GraphFormat::Dimacs => read_dimacs(input),
+            GraphFormat::EdgeList => read_edge_list(input),
+            GraphFormat::Dot => Err(FormatError::NotReadable(format)),
+        }
+    }
+
+    /// Constructs a string with the graph in the given format
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{Graph, GraphFormat};
+    /// let graph = Graph::from_edges(vec![(1, 2)]);
+    /// assert_eq!(graph.write_as(GraphFormat::Dimacs), "p edge 2 1\ne 1 2\n");
+    /// ```
+    pub fn write_as(&self, format: GraphFormat) -> String {
+        let edges = self.get_edges();
+        let mut output = String::new();
+        match format {
+            GraphFormat::Gr => return self.to_gr(),
+            GraphFormat::Dimacs => {
+                let n = self.get_all_nodes().into_iter().max().unwrap_or(0);
+                writeln!(output, "p edge {} {}", n, edges.len()).unwrap();
+                for (node_a, node_b) in edges {
+                    writeln!(output, "e {node_a} {node_b}").unwrap();
+                }
+            }
+            GraphFormat::EdgeList => {
+                for (node_a, node_b) in edges {
+                    writeln!(output, "{node_a} {node_b}").unwrap();
+                }
+            }
+            GraphFormat::Dot => {
+                let mut nodes = self.get_all_nodes();
+                nodes.sort();
+                output.push_str("graph {\n");
+                for node in nodes {
+                    writeln!(output, "  {node};").unwrap();
+                }
+                for (node_a, node_b) in edges {
+                    writeln!(output, "  {node_a} -- {node_b};").unwrap();
+                }
+                output.push_str("}\n");
+            }
+        }
+        output
+    }
+}
+
+fn read_dimacs(input: &str) -> Result<Graph, FormatError> {
+    let mut graph = Graph::new();
+    let mut n = 0;
+    for (index, line) in input.lines().enumerate() {
+        let invalid = FormatError::InvalidLine { line: index + 1 };
+        let tokens: Vec<&str> = tokens(line).into_iter().map(|(_, token)| token).collect();
+        match tokens.as_slice() {
+            [] | ["c", ..] => {}
+            ["p", _, nodes, _] => n = nodes.parse().map_err(|_| invalid)?,
+            ["e", node_a, node_b] => {
+                let edge = parse_edge(node_a, node_b).ok_or(invalid)?;
+                add_edge(&mut graph, edge);
+            }
+            _ => return Err(invalid),
+        }
+    }
+    //Like in .gr the isolated vertices are added once the whole input is read
+    (1..=n).for_each(|node| graph.add_node(node));
+    Ok(graph)
+}
+
+fn read_edge_list(input: &str) -> Result<Graph, FormatError> {
+    let mut graph = Graph::new();
+    for (index, line) in input.lines().enumerate() {
+        let tokens: Vec<&str> = tokens(line).into_iter().map(|(_, token)| token).collect();
+        match tokens.as_slice() {
+            [] => {}
+            [first, ..] if first.starts_with('#') || first.starts_with('%') => {}
+            [node_a, node_b] => {
+                let edge = parse_edge(node_a, node_b)
+                    .ok_or(FormatError::InvalidLine { line: index + 1 })?;
+                add_edge(&mut graph, edge);
+            }
+            _ => return Err(FormatError::InvalidLine { line: index + 1 }),
+        }
+    }
+    Ok(graph)
+}
+
+/// Parses an edge, self-loops and vertex 0 are not valid
+fn parse_edge(node_a: &str, node_b: &str) -> Option<(u32, u32)> {
+    let edge: (u32, u32) = (node_a.parse().ok()?, node_b.parse().ok()?);
+    (edge.0 != edge.1 && edge.0 != 0 && edge.1 != 0).then_some(edge)
+}
+
+fn add_edge(graph: &mut Graph, (node_a, node_b): (u32, u32)) {
+    graph.add_node(node_a);
+    graph.add_node(node_b);
+    graph.add_edge(node_a, node_b);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use super::{FormatError, GraphFormat};
+use crate::graph::Graph;
+
+fn graph() -> Graph {
+    let mut graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 1), (3, 4)]);
+    graph.add_node(5);
+    graph
+}
+
+#[test]
+fn test_round_trips() {
+    let graph = graph();
+
+    for format in [GraphFormat::Gr, GraphFormat::Dimacs] {
+        let read = Graph::read_as(&graph.write_as(format), format).unwrap();
+        assert_eq!(read, graph, "Format: {format}");
+    }
+    //Edge lists can not hold isolated nodes
+    let read = Graph::read_as(
+        &graph.write_as(GraphFormat::EdgeList),
+        GraphFormat::EdgeList,
+    );
+    assert_eq!(read.unwrap().get_edges(), graph.get_edges());
+}
+
+#[test]
+fn test_read_dimacs() {
+    let dimacs = "c a comment\np edge 4 2\ne 1 2\n\ne 3 1\n";
+
+    let graph = Graph::read_as(dimacs, GraphFormat::Dimacs).unwrap();
+
+    assert_eq!(graph.get_all_nodes().len(), 4);
+    assert_eq!(graph.get_edges(), vec![(1, 2), (1, 3)]);
+    assert!(matches!(
+        Graph::read_as("p edge 2 1\ne 1\n", GraphFormat::Dimacs),
+        Err(FormatError::InvalidLine { line: 2 })
+    ));
+}
+
+#[test]
+fn test_read_edge_list() {
+    let edges = "# a comment\n% another one\n1 2\n2 7\n";
+
+    let graph = Graph::read_as(edges, GraphFormat::EdgeList).unwrap();
+
+    assert_eq!(graph.get_edges(), vec![(1, 2), (2, 7)]);
+    assert!(matches!(
+        Graph::read_as("1 2\n3 3\n", GraphFormat::EdgeList),
+        Err(FormatError::InvalidLine { line: 2 })
+    ));
+}
+
+#[test]
+fn test_read_vertex_zero() {
+    assert!(matches!(
+        Graph::read_as("0 1\n1 2\n", GraphFormat::EdgeList),
+        Err(FormatError::InvalidLine { line: 1 })
+    ));
+    assert!(matches!(
+        Graph::read_as("p edge 2 1\ne 0 1\n", GraphFormat::Dimacs),
+        Err(FormatError::InvalidLine { line: 2 })
+    ));
+}
+
+#[test]
+fn test_read_gr_error() {
+    let error = Graph::read_as("p tww 2 2\n1 2\n", GraphFormat::Gr).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "the p-line announces 2 edges, but 1 were found"
+    );
+}
+
+#[test]
+fn test_write_dot() {
+    let graph = Graph::from_edges(vec![(2, 1)]);
+
+    assert_eq!(
+        graph.write_as(GraphFormat::Dot),
+        "graph {\n  1;\n  2;\n  1 -- 2;\n}\n"
+    );
+    assert!(matches!(
+        Graph::read_as("graph {}", GraphFormat::Dot),
+        Err(FormatError::NotReadable(GraphFormat::Dot))
+    ));
+}
+
+#[test]
+fn test_format_names() {
+    for format in [
+        GraphFormat::Gr,
+        GraphFormat::Dimacs,
+        GraphFormat::EdgeList,
+        GraphFormat::Dot,
+    ] {
+        assert_eq!(format.to_string().parse::<GraphFormat>().unwrap(), format);
+    }
+    assert!("csv".parse::<GraphFormat>().is_err());
+    assert_eq!(
+        GraphFormat::from_extension(Path::new("graph.col")),
+        Some(GraphFormat::Dimacs)
+    );
+}
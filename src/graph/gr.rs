@@ -0,0 +1,352 @@
+//! This module contains a strict parser for the .gr format
+//!
+//! In contrast to `Graph::from_gr` every deviation from the format is reported with its position,
+//! so graphs from untrusted sources can be rejected with a useful message.
+use std::{error::Error, fmt, fmt::Write, io, io::BufRead};
+
+use super::Graph;
+
+/// Reasons why a string or reader does not contain a valid .gr graph.
+/// Lines and columns start at 1.
+#[derive(Debug)]
+pub enum GrParseError {
+    /// Reading from the underlying reader failed
+    Io(io::Error),
+    /// The input does not contain a p-line at all
+    MissingProblemLine,
+    /// An edge appears before the p-line
+    EdgeBeforeProblemLine { line: usize },
+    /// A second p-line was found
+    DuplicateProblemLine { line: usize, first_line: usize },
+    /// The p-line names another problem than `tww`
+    WrongProblemDescriptor {
+        line: usize,
+        column: usize,
+        found: String,
+    },
+    /// The p-line does not have the form `p tww n m`
+    InvalidProblemLine { line: usize },
+    /// A token is not a valid number
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+    /// An edge line consists of a single vertex
+    IncompleteEdge { line: usize },
+    /// A vertex id is not within `1..=n`
+    VertexOutOfRange {
+        line: usize,
+        column: usize,
+        vertex: u32,
+        n: u32,
+    },
+    /// An edge connects a vertex with itself
+    SelfLoop { line: usize, vertex: u32 },
+    /// An edge was already defined in an earlier line
+    DuplicateEdge {
+        line: usize,
+        node_a: u32,
+        node_b: u32,
+    },
+    /// A line contains more tokens than expected
+    TrailingGarbage { line: usize, column: usize },
+    /// The number of edges does not match m of the p-line
+    EdgeCountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for GrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GrParseError::Io(err) => write!(f, "failed to read graph: {err}"),
+            GrParseError::MissingProblemLine => write!(f, "the p-line is missing"),
+            GrParseError::EdgeBeforeProblemLine { line } => {
+                write!(f, "line {line}: edge before the p-line")
+            }
+            GrParseError::DuplicateProblemLine { line, first_line } => {
+                write!(
+                    f,
+                    "line {line}: duplicated p-line, the first one is in line {first_line}"
+                )
+            }
+            GrParseError::WrongProblemDescriptor {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {line}, column {column}: expected problem descriptor 'tww', found '{found}'"
+            ),
+            GrParseError::InvalidProblemLine { line } => {
+                write!(f, "line {line}: the p-line must have the form 'p tww n m'")
+            }
+            GrParseError::InvalidNumber {
+                line,
+                column,
+                token,
+            } => write!(f, "line {line}, column {column}: '{token}' is not a number"),
+            GrParseError::IncompleteEdge { line } => {
+                write!(f, "line {line}: an edge needs two vertices")
+            }
+            GrParseError::VertexOutOfRange {
+                line,
+                column,
+                vertex,
+                n,
+            } => write!(
+                f,
+                "line {line}, column {column}: vertex {vertex} is not in the range 1..={n}"
+            ),
+            GrParseError::SelfLoop { line, vertex } => {
+                write!(f, "line {line}: self-loop at vertex {vertex}")
+            }
+            GrParseError::DuplicateEdge {
+                line,
+                node_a,
+                node_b,
+            } => write!(f, "line {line}: duplicated edge {node_a} {node_b}"),
+            GrParseError::TrailingGarbage { line, column } => {
+                write!(f, "line {line}, column {column}: unexpected trailing input")
+            }
+            GrParseError::EdgeCountMismatch { expected, found } => {
+                write!(
+                    f,
+                    "the p-line announces {expected} edges, but {found} were found"
+                )
+            }
+        }
+    }
+}
+
+impl Error for GrParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GrParseError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GrParseError {
+    fn from(err: io::Error) -> Self {
+        GrParseError::Io(err)
+    }
+}
+
+impl Graph {
+    /// Reads graph from a string following the .gr format and reports every violation of the format.
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// # Parameter
+    /// * gr: string slice containing the graph in .gr format
+    ///
+    /// # Returns
+    /// * A new graph instance with the vertices `1..=n` and all edges
+    /// * A `GrParseError` describing the first problem of the input
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let graph = Graph::try_from_gr("p tww 3 1\n1 2\n").unwrap();
+    /// assert_eq!(graph.get_all_nodes().len(), 3);
+    ///
+    /// assert!(Graph::try_from_gr("p tww 3 1\n1 4\n").is_err());
+    /// ```
+    pub fn try_from_gr(gr: &str) -> Result<Self, GrParseError> {
+        Self::try_from_gr_reader(gr.as_bytes())
+    }
+
+    /// Reads graph from a reader following the .gr format and reports every violation of the format.
+    /// See `Graph::try_from_gr` for details.
+    ///
+    /// # Parameter
+    /// * reader: Any buffered reader, e.g. stdin or a `BufReader<File>`
+    ///
+    /// # Returns
+    /// * A new graph instance with the vertices `1..=n` and all edges
+    /// * A `GrParseError` describing the first problem of the input
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let gr = "p tww 2 1\n1 2\n";
+    /// let graph = Graph::try_from_gr_reader(gr.as_bytes()).unwrap();
+    /// ```
+    pub fn try_from_gr_reader<R: BufRead>(reader: R) -> Result<Self, GrParseError> {
+        let mut graph = Graph::new();
+        // (n, m, line of the p-line)
+        let mut header: Option<(u32, usize, usize)> = None;
+        let mut edge_count = 0;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line?;
+            if is_comment(&line) {
+                continue;
+            }
+            let tokens = tokens(&line);
+
+            match tokens.first() {
+                None => continue,
+                Some((_, "p")) => {
+                    if let Some((_, _, first_line)) = header {
+                        return Err(GrParseError::DuplicateProblemLine {
+                            line: line_number,
+                            first_line,
+                        });
+                    }
+                    let (n, m) = parse_problem_line(&tokens, line_number)?;
+                    header = Some((n, m, line_number));
+                }
+                Some(_) => {
+                    let Some((n, _, _)) = header else {
+                        return Err(GrParseError::EdgeBeforeProblemLine { line: line_number });
+                    };
+                    let (node_a, node_b) = parse_edge(&tokens, line_number, n)?;
+                    graph.add_node(node_a);
+                    graph.add_node(node_b);
+                    if !graph.add_edge(node_a, node_b) {
+                        return Err(GrParseError::DuplicateEdge {
+                            line: line_number,
+                            node_a,
+                            node_b,
+                        });
+                    }
+                    edge_count += 1;
+                }
+            }
+        }
+
+        match header {
+            None => Err(GrParseError::MissingProblemLine),
+            Some((_, m, _)) if m != edge_count => Err(GrParseError::EdgeCountMismatch {
+                expected: m,
+                found: edge_count,
+            }),
+            Some((n, _, _)) => {
+                //The isolated vertices are only added once the input is known to be valid,
+                //so a huge n in the p-line is not allocated before the edges are checked
+                (1..=n).for_each(|node| graph.add_node(node));
+                Ok(graph)
+            }
+        }
+    }
+
+    /// Constructs a string following the .gr format.
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// The format names the vertices `1..=n`, so n is the largest vertex and missing vertices
+    /// become isolated ones.
+    ///
+    /// # Returns
+    /// A new string with the p-line and the edges in sorted order
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// let graph = Graph::from_edges(vec![(2, 3), (1, 2)]);
+    /// assert_eq!(graph.to_gr(), "p tww 3 2\n1 2\n2 3\n");
+    /// assert_eq!(Graph::try_from_gr(&graph.to_gr()).unwrap(), graph);
+    /// ```
+    pub fn to_gr(&self) -> String {
+        let n = self.get_all_nodes().into_iter().max().unwrap_or(0);
+        let edges = self.get_edges();
+        let mut gr = format!("p tww {} {}\n", n, edges.len());
+        for (node_a, node_b) in edges {
+            writeln!(gr, "{node_a} {node_b}").unwrap();
+        }
+        gr
+    }
+}
+
+/// Whether a line of a .gr or .tww file is a comment. Like in the verifier of the pace challenge
+/// these are all lines whose first character is a `c`.
+pub(crate) fn is_comment(line: &str) -> bool {
+    line.starts_with('c')
+}
+
+/// Splits a line into its tokens together with their column
+pub(crate) fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start: Option<usize> = None;
+    for (index, char) in line.char_indices() {
+        match (char.is_whitespace(), start) {
+            (true, Some(begin)) => {
+                tokens.push((begin + 1, &line[begin..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin + 1, &line[begin..]));
+    }
+    tokens
+}
+
+fn parse_number<T: std::str::FromStr>(
+    (column, token): (usize, &str),
+    line: usize,
+) -> Result<T, GrParseError> {
+    token.parse().map_err(|_| GrParseError::InvalidNumber {
+        line,
+        column,
+        token: token.to_string(),
+    })
+}
+
+fn parse_problem_line(tokens: &[(usize, &str)], line: usize) -> Result<(u32, usize), GrParseError> {
+    match tokens {
+        [_, (column, descriptor), ..] if *descriptor != "tww" => {
+            Err(GrParseError::WrongProblemDescriptor {
+                line,
+                column: *column,
+                found: descriptor.to_string(),
+            })
+        }
+        [_, _, n, m] => Ok((parse_number(*n, line)?, parse_number(*m, line)?)),
+        [_, _, _, _, (column, _), ..] => Err(GrParseError::TrailingGarbage {
+            line,
+            column: *column,
+        }),
+        _ => Err(GrParseError::InvalidProblemLine { line }),
+    }
+}
+
+fn parse_edge(tokens: &[(usize, &str)], line: usize, n: u32) -> Result<(u32, u32), GrParseError> {
+    let (node_a, node_b) = match tokens {
+        [a, b] => (*a, *b),
+        [_, _, (column, _), ..] => {
+            return Err(GrParseError::TrailingGarbage {
+                line,
+                column: *column,
+            })
+        }
+        _ => return Err(GrParseError::IncompleteEdge { line }),
+    };
+
+    let mut edge = [0; 2];
+    for (vertex, token) in edge.iter_mut().zip([node_a, node_b]) {
+        *vertex = parse_number(token, line)?;
+        if !(1..=n).contains(vertex) {
+            return Err(GrParseError::VertexOutOfRange {
+                line,
+                column: token.0,
+                vertex: *vertex,
+                n,
+            });
+        }
+    }
+
+    if edge[0] == edge[1] {
+        return Err(GrParseError::SelfLoop {
+            line,
+            vertex: edge[0],
+        });
+    }
+    Ok((edge[0], edge[1]))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,189 @@
+use super::GrParseError;
+use crate::graph::Graph;
+use std::collections::HashSet;
+
+#[test]
+fn test_try_from_gr() {
+    let gr = "c a comment\n\
+              p tww 4 2\n\
+              1 2\n\
+              \n\
+              c another comment\n\
+              2 3\n";
+
+    let graph = Graph::try_from_gr(gr).unwrap();
+
+    assert_eq!(graph.get_all_nodes().len(), 4);
+    assert_eq!(graph.get_neighbours(2), &HashSet::from([1, 3]));
+    assert_eq!(graph.get_neighbours(4), &HashSet::new());
+}
+
+#[test]
+fn test_try_from_gr_reader() {
+    let gr = "p tww 2 1\n1 2\n";
+
+    let graph = Graph::try_from_gr_reader(gr.as_bytes()).unwrap();
+
+    assert_eq!(graph, Graph::from_edges(vec![(1, 2)]));
+}
+
+#[test]
+fn test_try_from_gr_missing_problem_line() {
+    assert!(matches!(
+        Graph::try_from_gr("c only a comment\n"),
+        Err(GrParseError::MissingProblemLine)
+    ));
+    assert!(matches!(
+        Graph::try_from_gr("1 2\np tww 2 1\n"),
+        Err(GrParseError::EdgeBeforeProblemLine { line: 1 })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_duplicate_problem_line() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\np tww 2 1\n1 2\n"),
+        Err(GrParseError::DuplicateProblemLine {
+            line: 2,
+            first_line: 1
+        })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_wrong_problem_descriptor() {
+    assert!(matches!(
+        Graph::try_from_gr("p td 2 1\n1 2\n"),
+        Err(GrParseError::WrongProblemDescriptor { line: 1, column: 3, found }) if found == "td"
+    ));
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2\n1 2\n"),
+        Err(GrParseError::InvalidProblemLine { line: 1 })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_invalid_number() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n1 x\n"),
+        Err(GrParseError::InvalidNumber { line: 2, column: 3, token }) if token == "x"
+    ));
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n1\n"),
+        Err(GrParseError::IncompleteEdge { line: 2 })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_vertex_out_of_range() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n1  3\n"),
+        Err(GrParseError::VertexOutOfRange {
+            line: 2,
+            column: 4,
+            vertex: 3,
+            n: 2
+        })
+    ));
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n0 1\n"),
+        Err(GrParseError::VertexOutOfRange { vertex: 0, .. })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_self_loop() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n2 2\n"),
+        Err(GrParseError::SelfLoop { line: 2, vertex: 2 })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_duplicate_edge() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 2\n1 2\n2 1\n"),
+        Err(GrParseError::DuplicateEdge {
+            line: 3,
+            node_a: 2,
+            node_b: 1
+        })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_trailing_garbage() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 3 1\n1 2 3\n"),
+        Err(GrParseError::TrailingGarbage { line: 2, column: 5 })
+    ));
+    assert!(matches!(
+        Graph::try_from_gr("p tww 3 1 1\n1 2\n"),
+        Err(GrParseError::TrailingGarbage {
+            line: 1,
+            column: 11
+        })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_edge_count_mismatch() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 3 2\n1 2\n"),
+        Err(GrParseError::EdgeCountMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_huge_problem_line() {
+    assert!(matches!(
+        Graph::try_from_gr("p tww 4294967295 2\n1 2\n"),
+        Err(GrParseError::EdgeCountMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+}
+
+#[test]
+fn test_try_from_gr_comment_line() {
+    //Every line which starts with a c is a comment, like in the official verifier
+    let graph = Graph::try_from_gr("p tww 2 1\ncomment line\nc\n1 2\n").unwrap();
+    assert_eq!(graph.get_edges().len(), 1);
+
+    assert!(matches!(
+        Graph::try_from_gr("p tww 2 1\n c indented\n1 2\n"),
+        Err(GrParseError::InvalidNumber { line: 2, column: 2, token }) if token == "c"
+    ));
+}
+
+#[test]
+fn test_try_from_gr_tiny_set() {
+    let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("pacechallenge/tiny-set");
+
+    for file in std::fs::read_dir(path).unwrap() {
+        let content = std::fs::read_to_string(file.unwrap().path()).unwrap();
+
+        assert_eq!(
+            Graph::try_from_gr(&content).unwrap(),
+            Graph::from_gr(&content)
+        );
+    }
+}
+
+#[test]
+fn test_to_gr() {
+    let mut graph = Graph::from_edges(vec![(4, 2), (1, 2), (2, 3)]);
+    graph.add_node(6);
+
+    let gr = graph.to_gr();
+
+    assert_eq!(gr, "p tww 6 3\n1 2\n2 3\n2 4\n");
+    let read = Graph::try_from_gr(&gr).unwrap();
+    assert_eq!(read.get_all_nodes().len(), 6);
+    assert_eq!(read.get_edges(), graph.get_edges());
+}
@@ -37,6 +37,31 @@ fn test_newgraph_from_gr() {
     }
 }
 
+#[test]
+fn test_newgraph_from_gr_with_isolated_nodes() {
+    let gr = "c comment\n\
+              p tww 5 2\n\
+              1 2\n\
+              2 3";
+
+    let graph = Graph::from_gr(gr);
+
+    assert_eq!(graph.adj_set.len(), 5);
+    assert_eq!(graph.get_neighbours(2), &HashSet::from([1, 3]));
+    assert_eq!(graph.get_neighbours(4), &HashSet::new());
+    assert_eq!(graph.get_neighbours(5), &HashSet::new());
+}
+
+#[test]
+#[should_panic]
+fn test_newgraph_from_gr_panic_edge_count() {
+    let gr = "p tww 3 3\n\
+              1 2\n\
+              2 3";
+
+    Graph::from_gr(gr);
+}
+
 #[test]
 fn test_add_one_node() {
     let mut graph = Graph::new();
@@ -180,3 +205,44 @@ fn test_get_all_nodes() {
 
     assert!(nodes.iter().all(|item| [1, 2].contains(item)));
 }
+
+#[test]
+fn test_induced_subgraph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1), (1, 3)]);
+
+    let subgraph = graph.induced_subgraph(&[1, 2, 4]);
+
+    let mut nodes = subgraph.get_all_nodes();
+    nodes.sort();
+    assert_eq!(nodes, vec![1, 2, 4]);
+    assert_eq!(subgraph.get_neighbours(1), &HashSet::from([2, 4]));
+    assert_eq!(subgraph.get_neighbours(2), &HashSet::from([1]));
+    assert_eq!(subgraph.get_neighbours(4), &HashSet::from([1]));
+}
+
+#[test]
+#[should_panic(expected = "Node does not exist")]
+fn test_induced_subgraph_panic() {
+    let graph = Graph::from_edges(vec![(1, 2)]);
+
+    graph.induced_subgraph(&[1, 3]);
+}
+
+#[test]
+fn test_remove_node() {
+    let mut graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 1)]);
+
+    graph.remove_node(3);
+
+    assert!(!graph.adj_set.contains_key(&3));
+    assert_eq!(graph.get_neighbours(1), &HashSet::from([2]));
+    assert_eq!(graph.get_neighbours(2), &HashSet::from([1]));
+}
+
+#[test]
+#[should_panic(expected = "Node does not exist")]
+fn test_remove_node_panic() {
+    let mut graph = Graph::from_edges(vec![(1, 2)]);
+
+    graph.remove_node(3);
+}
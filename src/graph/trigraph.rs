@@ -0,0 +1,455 @@
+//! This module contains the trigraph, a graph with black and red edges
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
+
+use super::Graph;
+
+/// The color of an edge in a `Trigraph`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EdgeColor {
+    Black,
+    Red,
+}
+
+/// A graph where every edge is either black or red.
+///
+/// Contracting two vertices follows the rule of the twin-width definition:
+/// A neighbour stays black if it was black to both vertices.
+/// Every other neighbour, i.e. one on which both vertices disagree or which was red to one of them,
+/// becomes red. Red edges are never turned black again.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Trigraph {
+    black: HashMap<u32, HashSet<u32>>,
+    red: HashMap<u32, HashSet<u32>>,
+}
+
+impl Trigraph {
+    /// Creates an empty `Trigraph`.
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Trigraph;
+    /// let trigraph = Trigraph::new();
+    /// ```
+    pub fn new() -> Self {
+        Trigraph {
+            black: HashMap::new(),
+            red: HashMap::new(),
+        }
+    }
+
+    /// Adds a new node without any edges
+    ///
+    /// # Parameters
+    /// * node: The node to add to the trigraph
+    pub fn add_node(&mut self, node: u32) {
+        self.black.entry(node).or_default();
+        self.red.entry(node).or_default();
+    }
+
+    /// Adds a new edge between two nodes, an existing edge gets the new color
+    ///
+    /// # Parameters
+    /// * node_a: the first node of the edge
+    /// * node_b: the second node of the edge
+    /// * color: the color of the edge
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{EdgeColor, Trigraph};
+    /// let mut trigraph = Trigraph::new();
+    /// trigraph.add_node(1);
+    /// trigraph.add_node(2);
+    /// trigraph.add_edge(1, 2, EdgeColor::Red);
+    /// assert_eq!(trigraph.get_edge(1, 2), Some(EdgeColor::Red));
+    /// ```
+    pub fn add_edge(&mut self, node_a: u32, node_b: u32, color: EdgeColor) {
+        self.remove_edge(node_a, node_b);
+        let sets = match color {
+            EdgeColor::Black => &mut self.black,
+            EdgeColor::Red => &mut self.red,
+        };
+        Self::neighbours_mut(sets, node_a).insert(node_b);
+        Self::neighbours_mut(sets, node_b).insert(node_a);
+    }
+
+    /// Gets the color of the edge between two nodes
+    ///
+    /// # Returns
+    /// * The color of the edge, None if the nodes are not adjacent
+    ///
+    /// # Panics
+    /// If the first node does not exist
+    pub fn get_edge(&self, node_a: u32, node_b: u32) -> Option<EdgeColor> {
+        if self.get_black_neighbours(node_a).contains(&node_b) {
+            Some(EdgeColor::Black)
+        } else if self.get_red_neighbours(node_a).contains(&node_b) {
+            Some(EdgeColor::Red)
+        } else {
+            None
+        }
+    }
+
+    /// Gets a reference to the HashSet of nodes adjacent by a black edge
+    ///
+    /// # Panics
+    /// If the node does not exist
+    pub fn get_black_neighbours(&self, node: u32) -> &HashSet<u32> {
+        match self.black.get(&node) {
+            Some(set) => set,
+            None => panic!("Node does not exist"),
+        }
+    }
+
+    /// Gets a reference to the HashSet of nodes adjacent by a red edge
+    ///
+    /// # Panics
+    /// If the node does not exist
+    pub fn get_red_neighbours(&self, node: u32) -> &HashSet<u32> {
+        match self.red.get(&node) {
+            Some(set) => set,
+            None => panic!("Node does not exist"),
+        }
+    }
+
+    /// Gets the number of red edges of a node
+    ///
+    /// # Panics
+    /// If the node does not exist
+    pub fn get_red_degree(&self, node: u32) -> usize {
+        self.get_red_neighbours(node).len()
+    }
+
+    /// Gets the max red degree of the trigraph, 0 for an empty trigraph
+    pub fn get_max_red_degree(&self) -> usize {
+        self.red.values().map(|set| set.len()).max().unwrap_or(0)
+    }
+
+    /// Checks whether a node is part of the trigraph
+    pub fn contains_node(&self, node: u32) -> bool {
+        self.black.contains_key(&node)
+    }
+
+    /// Gets the number of nodes
+    pub fn get_node_count(&self) -> usize {
+        self.black.len()
+    }
+
+    /// Gets all existing nodes from the trigraph
+    ///
+    /// # Return
+    /// * Returns a vector containig all nodes
+    pub fn get_all_nodes(&self) -> Vec<u32> {
+        self.black.keys().cloned().collect()
+    }
+
+    /// Contracts two nodes with respect to the twin-width contraction rule.
+    /// The emerging node will be saved under node_a.
+    /// node_b will be deleted.
+    ///
+    /// # Parameters
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Returns
+    /// * The max red degree among the merged node and its red neighbours.
+    ///   These are the only nodes whose red degree can grow by the contraction.
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{EdgeColor, Graph, Trigraph};
+    /// let mut trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]));
+    /// assert_eq!(trigraph.contract(2, 3), 2);
+    /// assert_eq!(trigraph.get_edge(2, 1), Some(EdgeColor::Red));
+    /// assert_eq!(trigraph.get_edge(2, 4), Some(EdgeColor::Red));
+    /// ```
+    pub fn contract(&mut self, node_a: u32, node_b: u32) -> usize {
+        let (new_black, new_red) = self.merged_neighbours(node_a, node_b);
+
+        for node in self
+            .get_all_neighbours(node_a)
+            .chain(self.get_all_neighbours(node_b))
+            .collect::<Vec<_>>()
+        {
+            for sets in [&mut self.black, &mut self.red] {
+                if let Some(set) = sets.get_mut(&node) {
+                    set.remove(&node_a);
+                    set.remove(&node_b);
+                }
+            }
+        }
+        for node in &new_black {
+            Self::neighbours_mut(&mut self.black, *node).insert(node_a);
+        }
+        for node in &new_red {
+            Self::neighbours_mut(&mut self.red, *node).insert(node_a);
+        }
+
+        let local_red_degree = new_red
+            .iter()
+            .map(|node| self.get_red_degree(*node))
+            .fold(new_red.len(), cmp::max);
+
+        self.black.remove(&node_b);
+        self.red.remove(&node_b);
+        self.black.insert(node_a, new_black);
+        self.red.insert(node_a, new_red);
+
+        local_red_degree
+    }
+
+    /// Contracts two nodes like `contract`, but returns a record to restore the previous state.
+    ///
+    /// # Parameters
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Returns
+    /// * A `ContractionRecord` which can be passed to `undo`
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{Graph, Trigraph};
+    /// let mut trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]));
+    /// let original = trigraph.clone();
+    ///
+    /// let record = trigraph.contract_with_undo(1, 4);
+    /// assert_eq!(record.get_red_degree(), 2);
+    ///
+    /// trigraph.undo(record);
+    /// assert_eq!(trigraph, original);
+    /// ```
+    pub fn contract_with_undo(&mut self, node_a: u32, node_b: u32) -> ContractionRecord {
+        let (_, new_red) = self.merged_neighbours(node_a, node_b);
+        let black_a = self.get_black_neighbours(node_a);
+        let red_a = self.get_red_neighbours(node_a);
+
+        //The merged node keeps all neighbours of node_a, so it is enough to remember
+        //which black edges turned red and which red edges are new
+        let recoloured: Vec<u32> = new_red
+            .iter()
+            .filter(|node| black_a.contains(node))
+            .copied()
+            .collect();
+        let added: Vec<u32> = new_red
+            .iter()
+            .filter(|node| !black_a.contains(node) && !red_a.contains(node))
+            .copied()
+            .collect();
+        let black_b: Vec<u32> = self.get_black_neighbours(node_b).iter().copied().collect();
+        let red_b: Vec<u32> = self.get_red_neighbours(node_b).iter().copied().collect();
+
+        let red_degree = self.contract(node_a, node_b);
+
+        ContractionRecord {
+            merged: node_a,
+            removed: node_b,
+            black_removed: black_b,
+            red_removed: red_b,
+            recoloured,
+            added,
+            red_degree,
+        }
+    }
+
+    /// Restores the state before a contraction.
+    /// Records have to be undone in the reverse order of their contractions.
+    ///
+    /// # Parameters
+    /// * record: The record returned by `contract_with_undo`
+    ///
+    /// # Panics
+    /// If the merged node does not exist anymore
+    pub fn undo(&mut self, record: ContractionRecord) {
+        let merged = record.merged;
+        for node in record.added {
+            Self::neighbours_mut(&mut self.red, merged).remove(&node);
+            Self::neighbours_mut(&mut self.red, node).remove(&merged);
+        }
+        for node in record.recoloured {
+            self.add_edge(merged, node, EdgeColor::Black);
+        }
+
+        self.add_node(record.removed);
+        for node in record.black_removed {
+            self.add_edge(record.removed, node, EdgeColor::Black);
+        }
+        for node in record.red_removed {
+            self.add_edge(record.removed, node, EdgeColor::Red);
+        }
+    }
+
+    /// Computes the max red degree of the whole trigraph after contracting two nodes,
+    /// without performing the contraction.
+    ///
+    /// # Parameters
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Returns
+    /// * The max red degree the trigraph would have after `contract(node_a, node_b)`
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::{Graph, Trigraph};
+    /// let trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]));
+    /// assert_eq!(trigraph.get_max_red_degree_after(1, 4), 2);
+    /// ```
+    pub fn get_max_red_degree_after(&self, node_a: u32, node_b: u32) -> usize {
+        let (_, new_red) = self.merged_neighbours(node_a, node_b);
+        let red_a = self.get_red_neighbours(node_a);
+        let red_b = self.get_red_neighbours(node_b);
+
+        //Red neighbours lose their edges to a and b and gain one to the merged node
+        let affected = new_red
+            .iter()
+            .map(|node| {
+                self.get_red_degree(*node) + 1
+                    - usize::from(red_a.contains(node))
+                    - usize::from(red_b.contains(node))
+            })
+            .fold(new_red.len(), cmp::max);
+
+        //All other nodes keep their red degree
+        self.red
+            .iter()
+            .filter(|(node, _)| **node != node_a && **node != node_b && !new_red.contains(node))
+            .map(|(_, set)| set.len())
+            .fold(affected, cmp::max)
+    }
+
+    /// Gets the red neighbours the merged node would have after contracting two nodes,
+    /// without performing the contraction.
+    ///
+    /// # Parameters
+    /// * node_a: The frist node of the contraction
+    /// * node_b: The second node of the contraction
+    ///
+    /// # Panics
+    /// If one node does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashSet;
+    /// use twinwidth::graph::{Graph, Trigraph};
+    /// let trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]));
+    /// assert_eq!(trigraph.get_red_neighbours_after(2, 3), HashSet::from([1, 4]));
+    /// ```
+    pub fn get_red_neighbours_after(&self, node_a: u32, node_b: u32) -> HashSet<u32> {
+        self.merged_neighbours(node_a, node_b).1
+    }
+
+    /// Computes the black and red neighbourhood of the node emerging from a contraction
+    fn merged_neighbours(&self, node_a: u32, node_b: u32) -> (HashSet<u32>, HashSet<u32>) {
+        let black_a = self.get_black_neighbours(node_a);
+        let black_b = self.get_black_neighbours(node_b);
+
+        let mut new_red: HashSet<u32> = self
+            .get_red_neighbours(node_a)
+            .union(self.get_red_neighbours(node_b))
+            .copied()
+            .collect();
+        new_red.extend(black_a.symmetric_difference(black_b));
+        new_red.remove(&node_a);
+        new_red.remove(&node_b);
+
+        let new_black: HashSet<u32> = black_a
+            .intersection(black_b)
+            .filter(|node| !new_red.contains(node))
+            .copied()
+            .collect();
+
+        (new_black, new_red)
+    }
+
+    fn get_all_neighbours(&self, node: u32) -> impl Iterator<Item = u32> + '_ {
+        self.get_black_neighbours(node)
+            .iter()
+            .chain(self.get_red_neighbours(node))
+            .copied()
+    }
+
+    fn remove_edge(&mut self, node_a: u32, node_b: u32) {
+        for sets in [&mut self.black, &mut self.red] {
+            Self::neighbours_mut(sets, node_a).remove(&node_b);
+            Self::neighbours_mut(sets, node_b).remove(&node_a);
+        }
+    }
+
+    fn neighbours_mut(sets: &mut HashMap<u32, HashSet<u32>>, node: u32) -> &mut HashSet<u32> {
+        match sets.get_mut(&node) {
+            Some(set) => set,
+            None => panic!("Node does not exist"),
+        }
+    }
+}
+
+/// Everything which is necessary to undo a contraction of a `Trigraph`
+#[derive(PartialEq, Debug, Clone)]
+pub struct ContractionRecord {
+    merged: u32,
+    removed: u32,
+    black_removed: Vec<u32>,
+    red_removed: Vec<u32>,
+    recoloured: Vec<u32>,
+    added: Vec<u32>,
+    red_degree: usize,
+}
+
+impl ContractionRecord {
+    /// Gets the node which emerged from the contraction
+    pub fn get_merged(&self) -> u32 {
+        self.merged
+    }
+
+    /// Gets the node which was removed by the contraction
+    pub fn get_removed(&self) -> u32 {
+        self.removed
+    }
+
+    /// Gets the neighbours of the merged node whose black edge was recoloured red
+    pub fn get_recoloured(&self) -> &[u32] {
+        &self.recoloured
+    }
+
+    /// Gets the neighbours which got a new red edge to the merged node
+    pub fn get_added(&self) -> &[u32] {
+        &self.added
+    }
+
+    /// Gets the max red degree among the merged node and its red neighbours, see `Trigraph::contract`
+    pub fn get_red_degree(&self) -> usize {
+        self.red_degree
+    }
+}
+
+impl From<&Graph> for Trigraph {
+    /// Creates a trigraph where every edge of the graph is black
+    fn from(graph: &Graph) -> Self {
+        let mut trigraph = Trigraph::new();
+        for node in graph.get_all_nodes() {
+            trigraph
+                .black
+                .insert(node, graph.get_neighbours(node).clone());
+            trigraph.red.insert(node, HashSet::new());
+        }
+        trigraph
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,217 @@
+use super::{EdgeColor, Trigraph};
+use crate::{graph::Graph, random::Rng};
+use std::collections::HashSet;
+
+fn trigraph_with_nodes(nodes: &[u32]) -> Trigraph {
+    let mut trigraph = Trigraph::new();
+    nodes.iter().for_each(|node| trigraph.add_node(*node));
+    trigraph
+}
+
+#[test]
+fn test_from_graph() {
+    let trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3)]));
+
+    assert_eq!(trigraph.get_node_count(), 3);
+    assert_eq!(trigraph.get_black_neighbours(2), &HashSet::from([1, 3]));
+    assert_eq!(trigraph.get_max_red_degree(), 0);
+}
+
+#[test]
+fn test_add_edge_recolors() {
+    let mut trigraph = trigraph_with_nodes(&[1, 2]);
+
+    trigraph.add_edge(1, 2, EdgeColor::Black);
+    trigraph.add_edge(2, 1, EdgeColor::Red);
+
+    assert_eq!(trigraph.get_edge(1, 2), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_black_neighbours(1), &HashSet::new());
+    assert_eq!(trigraph.get_red_degree(2), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_add_edge_panic() {
+    let mut trigraph = trigraph_with_nodes(&[1]);
+    trigraph.add_edge(1, 2, EdgeColor::Black);
+}
+
+#[test]
+fn test_contract_twins_stay_black() {
+    let mut trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 3), (2, 3), (1, 4), (2, 4)]));
+
+    assert_eq!(trigraph.contract(1, 2), 0);
+
+    assert_eq!(trigraph.get_node_count(), 3);
+    assert!(!trigraph.contains_node(2));
+    assert_eq!(trigraph.get_black_neighbours(1), &HashSet::from([3, 4]));
+    assert_eq!(trigraph.get_black_neighbours(3), &HashSet::from([1]));
+    assert_eq!(trigraph.get_max_red_degree(), 0);
+}
+
+#[test]
+fn test_contract_disagreeing_neighbours_become_red() {
+    let mut trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 3), (2, 4), (1, 5), (2, 5)]));
+
+    assert_eq!(trigraph.contract(1, 2), 2);
+
+    assert_eq!(trigraph.get_edge(1, 3), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_edge(1, 4), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_edge(1, 5), Some(EdgeColor::Black));
+    assert_eq!(trigraph.get_red_neighbours(3), &HashSet::from([1]));
+}
+
+#[test]
+fn test_contract_red_dominates() {
+    let mut trigraph = trigraph_with_nodes(&[1, 2, 3, 4]);
+    trigraph.add_edge(1, 3, EdgeColor::Red);
+    trigraph.add_edge(2, 3, EdgeColor::Black);
+    trigraph.add_edge(2, 4, EdgeColor::Red);
+    trigraph.add_edge(1, 4, EdgeColor::Red);
+
+    assert_eq!(trigraph.contract(1, 2), 2);
+
+    assert_eq!(trigraph.get_edge(1, 3), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_edge(1, 4), Some(EdgeColor::Red));
+    //4 was red to both, the two red edges are merged into one
+    assert_eq!(trigraph.get_red_degree(4), 1);
+}
+
+#[test]
+fn test_contract_adjacent_nodes() {
+    let mut trigraph = Trigraph::from(&Graph::from_edges(vec![(1, 2), (2, 3)]));
+
+    assert_eq!(trigraph.contract(1, 2), 1);
+
+    assert_eq!(trigraph.get_edge(1, 3), Some(EdgeColor::Red));
+    assert_eq!(trigraph.get_edge(3, 1), Some(EdgeColor::Red));
+}
+
+#[test]
+#[should_panic]
+fn test_contract_panic() {
+    let mut trigraph = trigraph_with_nodes(&[1]);
+    trigraph.contract(1, 2);
+}
+
+#[test]
+fn test_max_red_degree_after_matches_contract() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6), (6, 3)]);
+    let mut trigraph = Trigraph::from(&graph);
+    trigraph.contract(2, 4);
+
+    let mut nodes = trigraph.get_all_nodes();
+    nodes.sort();
+    for (index, node_a) in nodes.iter().enumerate() {
+        for node_b in &nodes[index + 1..] {
+            let mut contracted = trigraph.clone();
+            contracted.contract(*node_a, *node_b);
+
+            assert_eq!(
+                trigraph.get_max_red_degree_after(*node_a, *node_b),
+                contracted.get_max_red_degree()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_red_neighbours_after_matches_contract() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6), (6, 3)]);
+    let mut trigraph = Trigraph::from(&graph);
+    trigraph.contract(1, 3);
+
+    for (node_a, node_b) in [(1, 2), (2, 4), (4, 6), (5, 6)] {
+        let mut contracted = trigraph.clone();
+        contracted.contract(node_a, node_b);
+
+        assert_eq!(
+            &trigraph.get_red_neighbours_after(node_a, node_b),
+            contracted.get_red_neighbours(node_a)
+        );
+    }
+}
+
+#[test]
+fn test_contract_with_undo_record() {
+    let mut trigraph = trigraph_with_nodes(&[1, 2, 3, 4, 5]);
+    trigraph.add_edge(1, 2, EdgeColor::Black);
+    trigraph.add_edge(1, 3, EdgeColor::Black);
+    trigraph.add_edge(2, 4, EdgeColor::Red);
+    trigraph.add_edge(1, 5, EdgeColor::Black);
+    trigraph.add_edge(2, 5, EdgeColor::Black);
+
+    let record = trigraph.contract_with_undo(1, 2);
+
+    assert_eq!(record.get_merged(), 1);
+    assert_eq!(record.get_removed(), 2);
+    assert_eq!(record.get_recoloured(), &[3]);
+    assert_eq!(record.get_added(), &[4]);
+    assert_eq!(record.get_red_degree(), 2);
+}
+
+#[test]
+fn test_undo_restores_edge_between_contracted_nodes() {
+    let mut trigraph = trigraph_with_nodes(&[1, 2, 3]);
+    trigraph.add_edge(1, 2, EdgeColor::Red);
+    trigraph.add_edge(2, 3, EdgeColor::Black);
+    let original = trigraph.clone();
+
+    let record = trigraph.contract_with_undo(1, 2);
+    trigraph.undo(record);
+
+    assert_eq!(trigraph, original);
+}
+
+/// Small xorshift generator, so the tests are reproducible without further dependencies
+#[test]
+fn test_random_contract_undo_round_trip() {
+    let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..20 {
+        let mut trigraph = trigraph_with_nodes(&(1..=12).collect::<Vec<u32>>());
+        for node_a in 1..=12 {
+            for node_b in node_a + 1..=12 {
+                match rng.below(6) {
+                    0 | 1 => trigraph.add_edge(node_a, node_b, EdgeColor::Black),
+                    2 => trigraph.add_edge(node_a, node_b, EdgeColor::Red),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut states = vec![trigraph.clone()];
+        let mut records = Vec::new();
+        while trigraph.get_node_count() > 1 {
+            let mut nodes = trigraph.get_all_nodes();
+            nodes.sort();
+            let node_a = nodes[rng.below(nodes.len())];
+            nodes.retain(|node| *node != node_a);
+            let node_b = nodes[rng.below(nodes.len())];
+
+            let mut expected = trigraph.clone();
+            let red_degree = expected.contract(node_a, node_b);
+            let record = trigraph.contract_with_undo(node_a, node_b);
+
+            assert_eq!(trigraph, expected);
+            assert_eq!(record.get_red_degree(), red_degree);
+            records.push(record);
+            states.push(trigraph.clone());
+
+            //Sometimes take back a few steps in between
+            if rng.below(4) == 0 {
+                for _ in 0..rng.below(3).min(records.len()) {
+                    trigraph.undo(records.pop().unwrap());
+                    states.pop();
+                    assert_eq!(&trigraph, states.last().unwrap());
+                }
+            }
+        }
+
+        while let Some(record) = records.pop() {
+            trigraph.undo(record);
+            states.pop();
+            assert_eq!(&trigraph, states.last().unwrap());
+        }
+    }
+}
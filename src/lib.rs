@@ -1,3 +1,14 @@
 pub mod algo;
 pub mod analyzer;
+pub mod anytime;
+pub mod bounds;
+pub mod cli;
+pub mod cograph;
+pub mod generate;
 pub mod graph;
+pub mod local_search;
+pub mod preprocessing;
+pub mod random;
+pub mod sat;
+pub mod sequence;
+pub mod verify;
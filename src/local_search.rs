@@ -0,0 +1,371 @@
+//! This module contains a local search which improves existing contraction sequences
+//!
+//! The sequence can come from any algorithm or from a .tww file. Every move changes the sequence
+//! slightly and is evaluated by replaying the sequence from the first changed contraction on,
+//! starting at a stored copy of the trigraph before it. The replay stops as soon as the move can
+//! not be accepted anymore.
+use std::{
+    cmp::{self, Ordering},
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use crate::algo::solver::{SolveError, SolveResult, Solver};
+use crate::anytime::{BestSoFar, CancellationToken};
+use crate::graph::{Graph, Trigraph};
+use crate::random::Rng;
+use crate::sequence::ContractionSequence;
+use crate::verify::{verify, VerifyError};
+
+/// Settings for `LocalSearch`
+#[derive(PartialEq, Debug, Clone)]
+pub struct LocalSearchConfig {
+    /// Seed of the moves and of the simulated annealing
+    pub seed: u64,
+    /// Maximum number of moves, `None` means unlimited
+    pub iterations: Option<usize>,
+    /// Maximum time, `None` means unlimited
+    pub time: Option<Duration>,
+    /// Initial temperature of the simulated annealing, 0 only accepts moves which are not worse
+    pub temperature: f64,
+    /// Factor by which the temperature is multiplied after every move
+    pub cooling: f64,
+}
+
+impl Default for LocalSearchConfig {
+    fn default() -> Self {
+        LocalSearchConfig {
+            seed: 0,
+            iterations: Some(1000),
+            time: Some(Duration::from_secs(10)),
+            temperature: 0.0,
+            cooling: 0.99,
+        }
+    }
+}
+
+/// Local search over contraction sequences of a graph.
+///
+/// The moves are
+/// * swapping two adjacent contractions,
+/// * merging the second node of a contraction into another remaining node,
+/// * moving a contraction to another position.
+///
+/// Sequences are compared by their width and then by the number of contractions which reach
+/// the width. Moves which are not worse are always accepted, worse moves are accepted with the
+/// probability of the simulated annealing at the current temperature. The best sequence ever
+/// seen is kept.
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::local_search::{LocalSearch, LocalSearchConfig};
+/// use twinwidth::sequence::ContractionSequence;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+/// let sequence = ContractionSequence::from_tww("1 6\n1 2\n1 3\n1 4\n1 5\n").unwrap();
+///
+/// let mut search = LocalSearch::new(graph, &sequence, LocalSearchConfig::default()).unwrap();
+/// assert_eq!(search.get_twin_width(), 2);
+/// let (_, twin_width) = search.improve();
+/// assert_eq!(twin_width, 1);
+/// ```
+pub struct LocalSearch {
+    config: LocalSearchConfig,
+    nodes: Vec<u32>,
+    sequence: Vec<(u32, u32)>,
+    /// The width and the number of contractions which reach it, before every contraction and
+    /// after the last one
+    prefix: Vec<(usize, usize)>,
+    /// The trigraph before every `interval`-th contraction
+    checkpoints: Vec<Trigraph>,
+    interval: usize,
+    best_sequence: Vec<(u32, u32)>,
+    best_cost: (usize, usize),
+}
+
+/// The part of a sequence which was replayed, starting at the checkpoint `block`
+struct Replay {
+    block: usize,
+    prefix: Vec<(usize, usize)>,
+    checkpoints: Vec<Trigraph>,
+}
+
+impl LocalSearch {
+    /// Creates a new `LocalSearch` instance
+    ///
+    /// # Parameters
+    /// * graph: The graph on wich the search should be performed.
+    /// * sequence: A contraction sequence of the graph to start from
+    /// * config: The seed, the limits and the temperature
+    ///
+    /// # Returns
+    /// * A `VerifyError` if the sequence is not a valid contraction sequence of the graph
+    pub fn new(
+        graph: Graph,
+        sequence: &[(u32, u32)],
+        config: LocalSearchConfig,
+    ) -> Result<Self, VerifyError> {
+        verify(&graph, sequence)?;
+
+        let mut nodes = graph.get_all_nodes();
+        nodes.sort();
+        //Replays start at most `interval` contractions before the first change
+        let interval = cmp::max(1, (sequence.len() as f64).sqrt() as usize);
+        let mut search = LocalSearch {
+            checkpoints: vec![Trigraph::from(&graph)],
+            config,
+            nodes,
+            sequence: Vec::new(),
+            prefix: vec![(0, 0)],
+            interval,
+            best_sequence: sequence.to_vec(),
+            best_cost: (0, 0),
+        };
+
+        let replay = search
+            .replay(sequence, 0, None)
+            .expect("Replays without limit always finish");
+        search.commit(sequence.to_vec(), replay);
+        search.best_cost = search.get_cost();
+        Ok(search)
+    }
+
+    /// Gets the width of the best sequence so far
+    pub fn get_twin_width(&self) -> usize {
+        self.best_cost.0
+    }
+
+    /// Performs moves until a limit of the configuration is reached
+    ///
+    /// # Returns
+    /// * The best contraction sequence so far and its width
+    ///
+    /// # Panics
+    /// If neither the number of iterations nor the time is limited
+    pub fn improve(&mut self) -> (ContractionSequence, usize) {
+        if self.config.iterations.is_none() && self.config.time.is_none() {
+            panic!("Local search needs an iteration or time limit");
+        }
+        self.improve_anytime(&BestSoFar::new(), &CancellationToken::new())
+    }
+
+    /// Performs moves like `improve` until a limit of the configuration is reached or the token
+    /// is cancelled, and publishes every improvement of the best sequence
+    ///
+    /// # Returns
+    /// * The best contraction sequence so far and its width
+    pub fn improve_anytime(
+        &mut self,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> (ContractionSequence, usize) {
+        best_so_far.publish(&self.best_sequence, self.best_cost.0);
+        let start = Instant::now();
+        let mut rng = Rng::new(self.config.seed);
+        let mut temperature = self.config.temperature;
+        let mut iterations = 0;
+
+        while self.sequence.len() >= 2 {
+            let out_of_iterations = self
+                .config
+                .iterations
+                .is_some_and(|limit| iterations >= limit);
+            let out_of_time = self.config.time.is_some_and(|time| start.elapsed() >= time);
+            if out_of_iterations || out_of_time || token.is_cancelled() {
+                break;
+            }
+            iterations += 1;
+
+            if let Some((candidate, from)) = self.random_move(&mut rng) {
+                //Worse moves are accepted with probability exp(-difference / temperature)
+                let tolerance = if temperature > 0.0 {
+                    -temperature * (1.0 - rng.next_f64()).ln()
+                } else {
+                    0.0
+                };
+                let limit = self.energy(self.get_cost()) + tolerance;
+                if let Some(replay) = self.replay(&candidate, from, Some(limit)) {
+                    self.commit(candidate, replay);
+                    if self.get_cost() < self.best_cost {
+                        self.best_cost = self.get_cost();
+                        self.best_sequence = self.sequence.clone();
+                        best_so_far.publish(&self.best_sequence, self.best_cost.0);
+                    }
+                }
+            }
+            temperature *= self.config.cooling;
+        }
+
+        (
+            ContractionSequence::from(self.best_sequence.clone()),
+            self.best_cost.0,
+        )
+    }
+
+    /// The width and the number of contractions which reach it of the current sequence
+    fn get_cost(&self) -> (usize, usize) {
+        self.prefix[self.sequence.len()]
+    }
+
+    /// Combines both parts of the cost into one number for the simulated annealing
+    fn energy(&self, (width, count): (usize, usize)) -> f64 {
+        (width * (self.sequence.len() + 1) + count) as f64
+    }
+
+    /// Changes the current sequence randomly
+    ///
+    /// # Returns
+    /// * The changed sequence and its first changed contraction, None if the move is not valid
+    fn random_move(&self, rng: &mut Rng) -> Option<(Vec<(u32, u32)>, usize)> {
+        let mut candidate = self.sequence.clone();
+        let length = candidate.len();
+
+        let from = match rng.below(3) {
+            0 => {
+                let index = rng.below(length - 1);
+                candidate.swap(index, index + 1);
+                index
+            }
+            1 => {
+                let index = rng.below(length);
+                let (node_a, node_b) = candidate[index];
+                let removed: HashSet<u32> = candidate[..index].iter().map(|(_, b)| *b).collect();
+                let remaining: Vec<u32> = self
+                    .nodes
+                    .iter()
+                    .filter(|node| **node != node_a && **node != node_b && !removed.contains(node))
+                    .copied()
+                    .collect();
+                if remaining.is_empty() {
+                    return None;
+                }
+                candidate[index] = (remaining[rng.below(remaining.len())], node_b);
+                index
+            }
+            _ => {
+                let index = rng.below(length);
+                let target = rng.below(length);
+                if index == target {
+                    return None;
+                }
+                let contraction = candidate.remove(index);
+                candidate.insert(target, contraction);
+                cmp::min(index, target)
+            }
+        };
+
+        is_valid(&candidate).then_some((candidate, from))
+    }
+
+    /// Replays a sequence which equals the current one before `from`
+    ///
+    /// # Returns
+    /// * The new widths and checkpoints, None if the energy exceeds the limit
+    fn replay(&self, sequence: &[(u32, u32)], from: usize, limit: Option<f64>) -> Option<Replay> {
+        let block = cmp::min(from / self.interval, self.checkpoints.len() - 1);
+        let start = block * self.interval;
+        let mut trigraph = self.checkpoints[block].clone();
+        let (mut width, mut count) = self.prefix[start];
+        let mut replay = Replay {
+            block,
+            prefix: Vec::new(),
+            checkpoints: Vec::new(),
+        };
+
+        for (index, &(node_a, node_b)) in sequence.iter().enumerate().skip(start) {
+            if index > start && index % self.interval == 0 {
+                replay.checkpoints.push(trigraph.clone());
+            }
+            let red_degree = trigraph.contract(node_a, node_b);
+            match red_degree.cmp(&width) {
+                Ordering::Greater => (width, count) = (red_degree, 1),
+                Ordering::Equal => count += 1,
+                Ordering::Less => {}
+            }
+            replay.prefix.push((width, count));
+
+            //Neither the width nor the count can decrease later on
+            if limit.is_some_and(|limit| self.energy((width, count)) > limit) {
+                return None;
+            }
+        }
+        Some(replay)
+    }
+
+    /// Makes a replayed sequence the current one
+    fn commit(&mut self, sequence: Vec<(u32, u32)>, replay: Replay) {
+        self.sequence = sequence;
+        self.prefix.truncate(replay.block * self.interval + 1);
+        self.prefix.extend(replay.prefix);
+        self.checkpoints.truncate(replay.block + 1);
+        self.checkpoints.extend(replay.checkpoints);
+    }
+}
+
+/// A solver whose sequence is improved by `LocalSearch` afterwards
+///
+/// # Examples
+/// ```
+/// use twinwidth::algo::{greedy::GreedyConfig, solver::Solver};
+/// use twinwidth::graph::Graph;
+/// use twinwidth::local_search::{Improved, LocalSearchConfig};
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (1, 6)]);
+/// let improved = Improved {
+///     solver: Box::new(GreedyConfig::default()),
+///     config: LocalSearchConfig::default(),
+/// };
+/// assert_eq!(improved.name(), "greedy+improve");
+/// assert_eq!(improved.solve(graph).unwrap().width, 2);
+/// ```
+pub struct Improved {
+    pub solver: Box<dyn Solver>,
+    pub config: LocalSearchConfig,
+}
+
+impl Solver for Improved {
+    fn name(&self) -> String {
+        format!("{}+improve", self.solver.name())
+    }
+
+    /// Improves the sequence of the solver until a limit of the configuration is reached or the
+    /// token is cancelled. Optimal sequences are not searched any further.
+    fn solve_anytime(
+        &self,
+        graph: Graph,
+        best_so_far: &BestSoFar,
+        token: &CancellationToken,
+    ) -> Result<SolveResult, SolveError> {
+        let start = Instant::now();
+        let result = self
+            .solver
+            .solve_anytime(graph.clone(), best_so_far, token)?;
+        if result.optimal {
+            return Ok(result);
+        }
+
+        let mut search = LocalSearch::new(graph.clone(), &result.sequence, self.config.clone())
+            .map_err(SolveError::InvalidSequence)?;
+        let (sequence, _) = search.improve_anytime(best_so_far, token);
+        let mut improved = SolveResult::new(
+            &graph,
+            &sequence,
+            result.lower_bound,
+            start,
+            result.stats.iterations,
+        )?;
+        //Together with the seed of the configuration it reproduces the improved sequence
+        improved.stats.seed = result.stats.seed;
+        Ok(improved)
+    }
+}
+
+/// Whether no contraction uses a node which was already merged into another one
+fn is_valid(sequence: &[(u32, u32)]) -> bool {
+    let mut removed: HashSet<u32> = HashSet::new();
+    sequence.iter().all(|(node_a, node_b)| {
+        node_a != node_b && !removed.contains(node_a) && removed.insert(*node_b)
+    })
+}
+
+#[cfg(test)]
+mod tests;
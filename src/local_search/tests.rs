@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use crate::{
+    algo::{greedy::Greedy, Algo},
+    anytime::{BestSoFar, CancellationToken},
+    graph::Graph,
+    sequence::ContractionSequence,
+    verify::{verify, VerifyError},
+};
+
+use super::{is_valid, LocalSearch, LocalSearchConfig};
+
+fn path() -> Graph {
+    Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 8)])
+}
+
+/// The 5x5 grid, where `Greedy` finds width 4
+fn grid() -> Graph {
+    let mut edges = Vec::new();
+    for node in 1..=25 {
+        if node % 5 != 0 {
+            edges.push((node, node + 1));
+        }
+        if node <= 20 {
+            edges.push((node, node + 5));
+        }
+    }
+    Graph::from_edges(edges)
+}
+
+fn config() -> LocalSearchConfig {
+    LocalSearchConfig {
+        iterations: Some(2000),
+        time: None,
+        ..LocalSearchConfig::default()
+    }
+}
+
+#[test]
+fn test_is_valid() {
+    assert!(!is_valid(&[(1, 2), (1, 3), (3, 4)]));
+    assert!(is_valid(&[(1, 2), (1, 3), (1, 4)]));
+    assert!(!is_valid(&[(1, 2), (2, 3)]));
+    assert!(!is_valid(&[(1, 1)]));
+}
+
+#[test]
+fn test_improve_bad_sequence() {
+    let graph = path();
+    //Contracting both ends first gives two red edges
+    let sequence = vec![(1, 8), (1, 2), (1, 3), (1, 4), (1, 5), (1, 6), (1, 7)];
+    let mut search = LocalSearch::new(graph.clone(), &sequence, config()).unwrap();
+    assert_eq!(search.get_twin_width(), 2);
+
+    let (improved, twin_width) = search.improve();
+
+    assert_eq!(twin_width, 1);
+    assert_eq!(verify(&graph, &improved), Ok(1));
+}
+
+#[test]
+fn test_improve_greedy_sequence() {
+    let graph = grid();
+    let (sequence, greedy_width) = Greedy::new_with_graph(graph.clone()).solve();
+    let mut search = LocalSearch::new(graph.clone(), &sequence, config()).unwrap();
+
+    let (improved, twin_width) = search.improve();
+
+    assert!(twin_width <= greedy_width);
+    assert_eq!(verify(&graph, &improved), Ok(twin_width));
+}
+
+#[test]
+fn test_improve_with_annealing() {
+    let graph = grid();
+    let (sequence, greedy_width) = Greedy::new_with_graph(graph.clone()).solve();
+    let config = LocalSearchConfig {
+        temperature: 30.0,
+        cooling: 0.995,
+        ..config()
+    };
+    let mut search = LocalSearch::new(graph.clone(), &sequence, config.clone()).unwrap();
+    let mut again = LocalSearch::new(graph.clone(), &sequence, config).unwrap();
+
+    let (improved, twin_width) = search.improve();
+
+    assert!(twin_width <= greedy_width);
+    assert_eq!(verify(&graph, &improved), Ok(twin_width));
+    assert_eq!(again.improve(), (improved, twin_width));
+}
+
+#[test]
+fn test_improve_tww_sequence() {
+    let sequence =
+        ContractionSequence::from_tww("c from a file\n1 8\n1 2\n1 3\n1 4\n1 5\n1 6\n1 7\n")
+            .unwrap();
+    let mut search = LocalSearch::new(path(), &sequence, config()).unwrap();
+
+    assert_eq!(search.improve().1, 1);
+}
+
+#[test]
+fn test_new_invalid_sequence() {
+    let result = LocalSearch::new(path(), &[(1, 2), (1, 3)], config());
+
+    assert_eq!(
+        result.err(),
+        Some(VerifyError::SequenceTooShort { remaining: 6 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Local search needs an iteration or time limit")]
+fn test_improve_without_limit() {
+    let config = LocalSearchConfig {
+        iterations: None,
+        time: None,
+        ..LocalSearchConfig::default()
+    };
+    let sequence = [(1, 2), (1, 3), (1, 4), (1, 5), (1, 6), (1, 7), (1, 8)];
+    LocalSearch::new(path(), &sequence, config)
+        .unwrap()
+        .improve();
+}
+
+#[test]
+fn test_improve_anytime_publishes() {
+    let graph = path();
+    let sequence = vec![(1, 8), (1, 2), (1, 3), (1, 4), (1, 5), (1, 6), (1, 7)];
+    let mut search = LocalSearch::new(graph.clone(), &sequence, config()).unwrap();
+    let best_so_far = BestSoFar::new();
+
+    let (improved, twin_width) = search.improve_anytime(&best_so_far, &CancellationToken::new());
+
+    assert_eq!(twin_width, 1);
+    assert_eq!(best_so_far.get(), Some((improved, 1)));
+}
+
+#[test]
+fn test_improve_time_limit() {
+    let graph = grid();
+    let (sequence, _) = Greedy::new_with_graph(graph.clone()).solve();
+    let config = LocalSearchConfig {
+        iterations: None,
+        time: Some(Duration::from_millis(50)),
+        ..LocalSearchConfig::default()
+    };
+    let mut search = LocalSearch::new(graph.clone(), &sequence, config).unwrap();
+
+    let (improved, twin_width) = search.improve();
+
+    assert_eq!(verify(&graph, &improved), Ok(twin_width));
+}
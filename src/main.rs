@@ -1,14 +1,30 @@
-use std::path::PathBuf;
-use twinwidth::algo::greedy::Greedy;
-use twinwidth::analyzer::Analyzer;
+use std::{env, io, process};
+
+use twinwidth::{
+    anytime::{cancel_on_sigterm, CancellationToken},
+    cli::{self, CliError, Command, USAGE},
+};
 
-//use twinwidth::{algo::Greedy, graph::Graph};
 fn main() {
-    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    path.push("pacechallenge/tiny-set");
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            let error = CliError::from(error);
+            eprintln!("error: {error}\n\n{USAGE}");
+            process::exit(error.exit_code());
+        }
+    };
 
-    let mut analyzer: Analyzer<Greedy> = Analyzer::new();
-    analyzer.run(path);
+    let token = CancellationToken::new();
+    cancel_on_sigterm(&token);
 
-    analyzer.show_result();
+    let code = match cli::execute(command, &token, &mut io::stdout().lock()) {
+        Ok(()) => 0,
+        Err(error) => {
+            eprintln!("error: {error}");
+            error.exit_code()
+        }
+    };
+    //The solver thread may still be running after a cancellation
+    process::exit(code);
 }
@@ -0,0 +1,87 @@
+//! This module contains reductions which shrink a graph before solving it
+//!
+//! Two vertices are false twins if they have the same neighbourhood and true twins if they have
+//! the same closed neighbourhood. Contracting twins creates no red edge and leaves a graph,
+//! so it never increases the twin-width and can be done before any algorithm.
+//! Contracting twins can create new twins, e.g. in cographs, so the reduction is repeated until
+//! the graph has no twins left. Modules which are not twin classes are not collapsed.
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::graph::Graph;
+use crate::sequence::ContractionSequence;
+
+/// Contracts twins until no twins are left.
+///
+/// Every twin class is contracted into its smallest vertex, which stays in the reduced graph.
+///
+/// # Parameters
+/// * graph: The graph to reduce
+///
+/// # Returns
+/// * The reduced graph and the contractions which lead to it, all of them of width 0
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::preprocessing::collapse_twins;
+/// let graph = Graph::from_edges(vec![(1, 2), (1, 3), (1, 4), (2, 5), (3, 5), (4, 5)]);
+/// let (reduced, prefix) = collapse_twins(&graph);
+/// assert_eq!(reduced.get_all_nodes().len(), 1);
+/// assert_eq!(prefix.len(), 4);
+/// ```
+pub fn collapse_twins(graph: &Graph) -> (Graph, ContractionSequence) {
+    let mut reduced = graph.clone();
+    let mut prefix = ContractionSequence::new();
+
+    loop {
+        let found = contract_twin_classes(&mut reduced, &mut prefix, false)
+            + contract_twin_classes(&mut reduced, &mut prefix, true);
+        if found == 0 {
+            break;
+        }
+    }
+    (reduced, prefix)
+}
+
+/// Contracts every class of false or true twins of the current graph once.
+///
+/// Removing a twin does not change whether other vertices are twins, so all classes can be
+/// computed before contracting them. A twin has the same neighbours as the vertex it is
+/// contracted into, so the contraction only removes it.
+///
+/// # Returns
+/// * The number of contractions
+fn contract_twin_classes(
+    graph: &mut Graph,
+    prefix: &mut ContractionSequence,
+    closed: bool,
+) -> usize {
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort();
+
+    let mut representatives: HashMap<Vec<u32>, u32> = HashMap::new();
+    let mut contractions = Vec::new();
+    for node in nodes {
+        let mut neighbourhood: Vec<u32> = graph.get_neighbours(node).iter().copied().collect();
+        if closed {
+            neighbourhood.push(node);
+        }
+        neighbourhood.sort_unstable();
+
+        match representatives.entry(neighbourhood) {
+            Entry::Occupied(entry) => contractions.push((*entry.get(), node)),
+            Entry::Vacant(entry) => {
+                entry.insert(node);
+            }
+        }
+    }
+
+    for &(node_a, node_b) in &contractions {
+        graph.remove_node(node_b);
+        prefix.push(node_a, node_b);
+    }
+    contractions.len()
+}
+
+#[cfg(test)]
+mod tests;
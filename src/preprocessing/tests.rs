@@ -0,0 +1,75 @@
+use super::collapse_twins;
+use crate::{
+    graph::{Graph, Trigraph},
+    random::Rng,
+};
+
+#[test]
+fn test_collapse_twins_cograph() {
+    //Complete bipartite graph K_{2,3}
+    let graph = Graph::from_edges(vec![(1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)]);
+
+    let (reduced, prefix) = collapse_twins(&graph);
+
+    assert_eq!(reduced.get_all_nodes(), vec![1]);
+    assert_eq!(prefix.to_vec(), vec![(1, 2), (3, 4), (3, 5), (1, 3)]);
+}
+
+#[test]
+fn test_collapse_twins_without_twins() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+
+    let (reduced, prefix) = collapse_twins(&graph);
+
+    assert_eq!(reduced, graph);
+    assert!(prefix.is_empty());
+}
+
+#[test]
+fn test_collapse_twins_true_twins() {
+    //1 and 2 are adjacent and both adjacent to 3, 4 is only adjacent to 3
+    let graph = Graph::from_edges(vec![(1, 2), (1, 3), (2, 3), (3, 4)]);
+
+    let (reduced, prefix) = collapse_twins(&graph);
+
+    assert_eq!(prefix.to_vec(), vec![(1, 2), (1, 4), (1, 3)]);
+    assert_eq!(reduced.get_all_nodes(), vec![1]);
+}
+
+#[test]
+fn test_collapse_twins_creates_no_red_edges() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for density in [2, 3, 5] {
+        for _ in 0..5 {
+            let mut graph = Graph::new();
+            (1..=20).for_each(|node| graph.add_node(node));
+            for node_a in 1..=20 {
+                for node_b in node_a + 1..=20 {
+                    if rng.below(density) == 0 {
+                        graph.add_edge(node_a, node_b);
+                    }
+                }
+            }
+
+            let (reduced, prefix) = collapse_twins(&graph);
+
+            let mut trigraph = Trigraph::from(&graph);
+            for &(node_a, node_b) in prefix.iter() {
+                trigraph.contract(node_a, node_b);
+                assert_eq!(trigraph.get_max_red_degree(), 0);
+            }
+            let mut nodes = reduced.get_all_nodes();
+            nodes.sort();
+            let mut remaining = trigraph.get_all_nodes();
+            remaining.sort();
+            assert_eq!(nodes, remaining);
+            for node in nodes {
+                assert_eq!(
+                    trigraph.get_black_neighbours(node),
+                    reduced.get_neighbours(node)
+                );
+            }
+        }
+    }
+}
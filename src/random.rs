@@ -0,0 +1,74 @@
+//! This module contains a small seeded random number generator
+//!
+//! Randomised algorithms take a seed, so every result can be reproduced by running the
+//! algorithm again with the same seed. The generator is SplitMix64, which is fast, accepts
+//! every seed and is good enough for tie-breaking and sampling, but not for cryptography.
+
+/// A seeded random number generator
+///
+/// # Examples
+/// ```
+/// use twinwidth::random::Rng;
+/// let mut rng = Rng::new(42);
+/// let mut other = Rng::new(42);
+/// assert_eq!(rng.next_u64(), other.next_u64());
+/// assert!(rng.below(6) < 6);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new generator, equal seeds give equal sequences of numbers
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Gets the next random number
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix(self.state)
+    }
+
+    /// Gets a random number which is smaller than the bound
+    ///
+    /// # Panics
+    /// If the bound is 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            panic!("Bound has to be positive");
+        }
+        //The high half of the product is almost uniform without a slow division
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+
+    /// Gets a random number in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        //The 53 high bits fill the mantissa of a double exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the items uniformly
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Scrambles a number, equal to the first number of a generator with this seed
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    mix(seed.wrapping_add(GOLDEN_GAMMA))
+}
+
+fn mix(value: u64) -> u64 {
+    let value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod tests;
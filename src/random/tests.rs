@@ -0,0 +1,66 @@
+use super::{splitmix64, Rng};
+
+#[test]
+fn test_same_seed_same_numbers() {
+    let mut rng = Rng::new(7);
+    let mut other = Rng::new(7);
+    let mut different = Rng::new(8);
+
+    let numbers: Vec<u64> = (0..10).map(|_| rng.next_u64()).collect();
+
+    assert_eq!(
+        numbers,
+        (0..10).map(|_| other.next_u64()).collect::<Vec<_>>()
+    );
+    assert_ne!(
+        numbers,
+        (0..10).map(|_| different.next_u64()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_splitmix64_is_first_number() {
+    assert_eq!(splitmix64(0), Rng::new(0).next_u64());
+    assert_eq!(splitmix64(123), Rng::new(123).next_u64());
+}
+
+#[test]
+fn test_below() {
+    let mut rng = Rng::new(0);
+    let mut counts = [0; 6];
+
+    for _ in 0..6000 {
+        counts[rng.below(6)] += 1;
+    }
+
+    assert!(counts.iter().all(|count| (800..1200).contains(count)));
+    assert_eq!(rng.below(1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Bound has to be positive")]
+fn test_below_zero() {
+    Rng::new(0).below(0);
+}
+
+#[test]
+fn test_shuffle() {
+    let mut rng = Rng::new(3);
+    let mut items: Vec<u32> = (0..20).collect();
+
+    rng.shuffle(&mut items);
+
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_next_f64() {
+    let mut rng = Rng::new(3);
+    let numbers: Vec<f64> = (0..1000).map(|_| rng.next_f64()).collect();
+
+    assert!(numbers.iter().all(|number| (0.0..1.0).contains(number)));
+    assert!(numbers.iter().any(|number| *number < 0.1));
+    assert!(numbers.iter().any(|number| *number > 0.9));
+}
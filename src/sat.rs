@@ -0,0 +1,12 @@
+//! This module contains the SAT encoding of twin-width and the solvers to decide it
+//!
+//! An `Encoding` states "the graph has a contraction sequence of width at most d" as a `Cnf`.
+//! Any `SatSolver` can decide it, either the embedded `Cdcl` solver or an `ExternalSolver`
+//! binary which reads DIMACS. See `algo::sat::Sat` for the search over d.
+mod cnf;
+mod encoding;
+mod solver;
+
+pub use cnf::{Cnf, Lit};
+pub use encoding::Encoding;
+pub use solver::{Cdcl, ExternalSolver, SatError, SatResult, SatSolver};
@@ -0,0 +1,136 @@
+//! This module contains a formula in conjunctive normal form
+use std::{fmt::Write as _, io, io::Write};
+
+/// A literal in DIMACS convention: a positive variable or its negation.
+/// Variables start at 1.
+pub type Lit = i32;
+
+/// A formula in conjunctive normal form
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Cnf {
+    variables: usize,
+    clauses: Vec<Vec<Lit>>,
+}
+
+impl Cnf {
+    /// Creates an empty formula without variables
+    pub fn new() -> Self {
+        Cnf {
+            variables: 0,
+            clauses: Vec::new(),
+        }
+    }
+
+    /// Creates a new variable
+    ///
+    /// # Returns
+    /// * The positive literal of the new variable
+    pub fn new_var(&mut self) -> Lit {
+        self.variables += 1;
+        self.variables as Lit
+    }
+
+    /// Adds a clause, i.e. a disjunction of literals
+    ///
+    /// # Panics
+    /// If a literal refers to a variable which was not created by `new_var`
+    pub fn add_clause(&mut self, clause: Vec<Lit>) {
+        assert!(
+            clause
+                .iter()
+                .all(|lit| *lit != 0 && lit.unsigned_abs() as usize <= self.variables),
+            "Variable does not exist"
+        );
+        self.clauses.push(clause);
+    }
+
+    /// Adds clauses which allow at most `bound` of the literals to be true.
+    /// Uses the sequential counter encoding of Sinz with `literals.len() * bound` new variables.
+    ///
+    /// # Parameters
+    /// * literals: The literals to count
+    /// * bound: The maximum number of true literals
+    pub fn add_at_most(&mut self, literals: &[Lit], bound: usize) {
+        if literals.len() <= bound {
+            return;
+        }
+        if bound == 0 {
+            literals.iter().for_each(|lit| self.add_clause(vec![-lit]));
+            return;
+        }
+
+        //counter[i][l]: at least l + 1 of the first i + 1 literals are true
+        let counter: Vec<Vec<Lit>> = (0..literals.len() - 1)
+            .map(|_| (0..bound).map(|_| self.new_var()).collect())
+            .collect();
+
+        self.add_clause(vec![-literals[0], counter[0][0]]);
+        for lit in &counter[0][1..] {
+            self.add_clause(vec![-lit]);
+        }
+        for i in 1..literals.len() - 1 {
+            self.add_clause(vec![-literals[i], counter[i][0]]);
+            self.add_clause(vec![-counter[i - 1][0], counter[i][0]]);
+            for l in 1..bound {
+                self.add_clause(vec![-literals[i], -counter[i - 1][l - 1], counter[i][l]]);
+                self.add_clause(vec![-counter[i - 1][l], counter[i][l]]);
+            }
+            self.add_clause(vec![-literals[i], -counter[i - 1][bound - 1]]);
+        }
+        let last = literals.len() - 1;
+        self.add_clause(vec![-literals[last], -counter[last - 1][bound - 1]]);
+    }
+
+    /// Gets the number of variables
+    pub fn get_variable_count(&self) -> usize {
+        self.variables
+    }
+
+    /// Gets all clauses
+    pub fn get_clauses(&self) -> &[Vec<Lit>] {
+        &self.clauses
+    }
+
+    /// Checks whether an assignment satisfies every clause
+    ///
+    /// # Parameters
+    /// * model: The value of every variable, `model[0]` belongs to variable 1
+    pub fn is_satisfied_by(&self, model: &[bool]) -> bool {
+        self.clauses.iter().all(|clause| {
+            clause
+                .iter()
+                .any(|lit| model[lit.unsigned_abs() as usize - 1] == (*lit > 0))
+        })
+    }
+
+    /// Constructs a string with respect to the DIMACS CNF format
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::sat::Cnf;
+    /// let mut cnf = Cnf::new();
+    /// let a = cnf.new_var();
+    /// let b = cnf.new_var();
+    /// cnf.add_clause(vec![a, -b]);
+    /// assert_eq!(cnf.to_dimacs(), "p cnf 2 1\n1 -2 0\n");
+    /// ```
+    pub fn to_dimacs(&self) -> String {
+        let mut dimacs = String::new();
+        writeln!(dimacs, "p cnf {} {}", self.variables, self.clauses.len()).unwrap();
+        for clause in &self.clauses {
+            for lit in clause {
+                write!(dimacs, "{} ", lit).unwrap();
+            }
+            dimacs.push_str("0\n");
+        }
+        dimacs
+    }
+
+    /// Writes the formula in DIMACS CNF format, e.g. to a file or the stdin of a solver
+    pub fn write_dimacs<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_dimacs().as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,65 @@
+use super::Cnf;
+
+/// Checks by brute force whether the formula is satisfiable with the first variables fixed
+fn satisfiable_with(cnf: &Cnf, fixed: &[bool]) -> bool {
+    let free = cnf.get_variable_count() - fixed.len();
+    (0..1u64 << free).any(|bits| {
+        let mut model = fixed.to_vec();
+        model.extend((0..free).map(|bit| bits >> bit & 1 == 1));
+        cnf.is_satisfied_by(&model)
+    })
+}
+
+#[test]
+fn test_new_var() {
+    let mut cnf = Cnf::new();
+
+    assert_eq!(cnf.new_var(), 1);
+    assert_eq!(cnf.new_var(), 2);
+    assert_eq!(cnf.get_variable_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Variable does not exist")]
+fn test_add_clause_unknown_variable() {
+    let mut cnf = Cnf::new();
+    cnf.new_var();
+    cnf.add_clause(vec![1, -2]);
+}
+
+#[test]
+fn test_add_at_most() {
+    for count in 1..=5 {
+        for bound in 0..=count {
+            let mut cnf = Cnf::new();
+            let literals: Vec<i32> = (0..count).map(|_| cnf.new_var()).collect();
+            cnf.add_at_most(&literals, bound);
+
+            for bits in 0..1u32 << count {
+                let fixed: Vec<bool> = (0..count).map(|bit| bits >> bit & 1 == 1).collect();
+                let expected = bits.count_ones() as usize <= bound;
+                assert_eq!(
+                    satisfiable_with(&cnf, &fixed),
+                    expected,
+                    "{count} literals, bound {bound}, assignment {bits:b}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_to_dimacs() {
+    let mut cnf = Cnf::new();
+    let a = cnf.new_var();
+    let b = cnf.new_var();
+    let c = cnf.new_var();
+    cnf.add_clause(vec![a, b]);
+    cnf.add_clause(vec![-c]);
+
+    let mut written = Vec::new();
+    cnf.write_dimacs(&mut written).unwrap();
+
+    assert_eq!(cnf.to_dimacs(), "p cnf 3 2\n1 2 0\n-3 0\n");
+    assert_eq!(String::from_utf8(written).unwrap(), cnf.to_dimacs());
+}
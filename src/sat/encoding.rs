@@ -0,0 +1,234 @@
+//! This module contains the encoding of "twin-width ≤ d" as a formula
+use std::collections::HashSet;
+
+use super::cnf::{Cnf, Lit};
+use super::SatError;
+use crate::graph::Graph;
+use crate::sequence::ContractionSequence;
+
+/// The formula "the graph has a contraction sequence of width at most d".
+///
+/// The n - 1 contractions are modelled as time steps, the last node (in sorted order) is the one
+/// which is left at the end. The variables are
+/// * ordering: `eliminated[t][i]` node i is contracted away in step t (exactly one per step and node)
+///   and `dead[t][i]` node i is contracted away in step t or before
+/// * merge: `parent[i][j]` node i is contracted into node j and `merged[t][j]` node j receives a node in step t
+/// * red edges: `red[t][j][k]` the edge jk is red after step t
+///
+/// In the trigraph an edge between two parts which is not red exists if and only if the
+/// representatives are adjacent in the original graph. So a contraction of i into j creates a red
+/// edge jk if i and j differ in their original adjacency to k, and inherits the red edges of i and j.
+/// The red degree of every node is limited by a sequential counter.
+/// Steps after which at most d + 1 nodes are left cannot exceed the width and are not constrained.
+pub struct Encoding {
+    cnf: Cnf,
+    nodes: Vec<u32>,
+    eliminated: Vec<Vec<Lit>>,
+    parent: Vec<Vec<Option<Lit>>>,
+}
+
+impl Encoding {
+    /// Encodes "twin-width ≤ width" for a graph
+    ///
+    /// # Parameters
+    /// * graph: The graph to encode
+    /// * width: The maximum red degree allowed
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::graph::Graph;
+    /// use twinwidth::sat::{Cdcl, Encoding, SatResult, SatSolver};
+    /// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+    /// let encoding = Encoding::new(&graph, 1);
+    /// let result = Cdcl::new().solve(encoding.get_cnf()).unwrap();
+    /// assert_eq!(result, SatResult::Unsatisfiable);
+    /// ```
+    pub fn new(graph: &Graph, width: usize) -> Self {
+        let mut nodes = graph.get_all_nodes();
+        nodes.sort();
+        let n = nodes.len();
+        let steps = n.saturating_sub(1);
+        let mut cnf = Cnf::new();
+
+        let adjacent: Vec<HashSet<usize>> = nodes
+            .iter()
+            .map(|node| {
+                graph
+                    .get_neighbours(*node)
+                    .iter()
+                    .map(|neighbour| nodes.binary_search(neighbour).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        //Ordering: every step eliminates one node and every node but the last is eliminated once
+        let eliminated: Vec<Vec<Lit>> = (0..steps)
+            .map(|_| (0..steps).map(|_| cnf.new_var()).collect())
+            .collect();
+        for row in &eliminated {
+            cnf.add_clause(row.clone());
+            cnf.add_at_most(row, 1);
+        }
+        for i in 0..steps {
+            let column: Vec<Lit> = eliminated.iter().map(|row| row[i]).collect();
+            cnf.add_clause(column.clone());
+            cnf.add_at_most(&column, 1);
+        }
+
+        //dead[t][i] <-> dead[t - 1][i] or eliminated[t][i]
+        let mut dead: Vec<Vec<Lit>> = Vec::with_capacity(steps);
+        for t in 0..steps {
+            let row: Vec<Lit> = (0..steps).map(|_| cnf.new_var()).collect();
+            for i in 0..steps {
+                cnf.add_clause(vec![-eliminated[t][i], row[i]]);
+                if t == 0 {
+                    cnf.add_clause(vec![-row[i], eliminated[t][i]]);
+                } else {
+                    cnf.add_clause(vec![-dead[t - 1][i], row[i]]);
+                    cnf.add_clause(vec![-row[i], dead[t - 1][i], eliminated[t][i]]);
+                }
+            }
+            dead.push(row);
+        }
+        //The literal "node k is alive after step t", None for the last node which is always alive
+        let alive = |t: usize, k: usize| -> Option<Lit> { (k < steps).then(|| -dead[t][k]) };
+
+        //Merge: every eliminated node has a parent which is alive after the step
+        let parent: Vec<Vec<Option<Lit>>> = (0..steps)
+            .map(|i| (0..n).map(|j| (i != j).then(|| cnf.new_var())).collect())
+            .collect();
+        for row in &parent {
+            let parents: Vec<Lit> = row.iter().flatten().copied().collect();
+            cnf.add_clause(parents.clone());
+            cnf.add_at_most(&parents, 1);
+        }
+        let merged: Vec<Vec<Lit>> = (0..steps)
+            .map(|_| (0..n).map(|_| cnf.new_var()).collect())
+            .collect();
+        for t in 0..steps {
+            for i in 0..steps {
+                for j in (0..n).filter(|j| *j != i) {
+                    let p = parent[i][j].unwrap();
+                    cnf.add_clause(vec![-eliminated[t][i], -p, merged[t][j]]);
+                    if let Some(alive) = alive(t, j) {
+                        cnf.add_clause(vec![-eliminated[t][i], -p, alive]);
+                    }
+                }
+            }
+        }
+
+        //Red edges, only for steps after which more than width + 1 nodes are left
+        let constrained = steps.saturating_sub(width + 1);
+        let mut red: Vec<Vec<Vec<Option<Lit>>>> = Vec::with_capacity(constrained);
+        for t in 0..constrained {
+            let current: Vec<Vec<Option<Lit>>> = (0..n)
+                .map(|j| (0..n).map(|k| (j < k).then(|| cnf.new_var())).collect())
+                .collect();
+            let edge = |j: usize, k: usize| current[j.min(k)][j.max(k)].unwrap();
+
+            //Original adjacency of the eliminated node and of the node it is merged into
+            let adjacent_eliminated: Vec<Lit> = (0..n).map(|_| cnf.new_var()).collect();
+            let adjacent_merged: Vec<Lit> = (0..n).map(|_| cnf.new_var()).collect();
+            for k in 0..n {
+                for i in 0..steps {
+                    let sign = if adjacent[i].contains(&k) { 1 } else { -1 };
+                    cnf.add_clause(vec![-eliminated[t][i], sign * adjacent_eliminated[k]]);
+                }
+                for j in 0..n {
+                    let sign = if adjacent[j].contains(&k) { 1 } else { -1 };
+                    cnf.add_clause(vec![-merged[t][j], sign * adjacent_merged[k]]);
+                }
+            }
+
+            //Red neighbours of the eliminated node before the step
+            let inherited: Vec<Lit> = (0..n).map(|_| cnf.new_var()).collect();
+            if let Some(previous) = red.last() {
+                let previous_edge =
+                    |j: usize, k: usize| -> Lit { previous[j.min(k)][j.max(k)].unwrap() };
+                for (i, eliminated) in eliminated[t].iter().enumerate() {
+                    for k in (0..n).filter(|k| *k != i) {
+                        cnf.add_clause(vec![-eliminated, -previous_edge(i, k), inherited[k]]);
+                    }
+                }
+                //Red edges between nodes which are still alive stay red
+                for j in 0..n {
+                    for k in j + 1..n {
+                        let mut clause = vec![-previous_edge(j, k), edge(j, k)];
+                        clause.extend(alive(t, j).map(|lit| -lit));
+                        clause.extend(alive(t, k).map(|lit| -lit));
+                        cnf.add_clause(clause);
+                    }
+                }
+            }
+
+            for (j, merged) in merged[t].iter().enumerate() {
+                for k in (0..n).filter(|k| *k != j) {
+                    let mut condition = vec![-merged];
+                    condition.extend(alive(t, k).map(|lit| -lit));
+
+                    for extra in [
+                        vec![-adjacent_eliminated[k], adjacent_merged[k]],
+                        vec![adjacent_eliminated[k], -adjacent_merged[k]],
+                        vec![-inherited[k]],
+                    ] {
+                        let mut clause = condition.clone();
+                        clause.extend(extra);
+                        clause.push(edge(j, k));
+                        cnf.add_clause(clause);
+                    }
+                }
+            }
+
+            for j in 0..n {
+                let edges: Vec<Lit> = (0..n).filter(|k| *k != j).map(|k| edge(j, k)).collect();
+                cnf.add_at_most(&edges, width);
+            }
+            red.push(current);
+        }
+
+        Encoding {
+            cnf,
+            nodes,
+            eliminated,
+            parent,
+        }
+    }
+
+    /// Gets the formula
+    pub fn get_cnf(&self) -> &Cnf {
+        &self.cnf
+    }
+
+    /// Constructs the contraction sequence of a satisfying assignment
+    ///
+    /// # Parameters
+    /// * model: The value of every variable, `model[0]` belongs to variable 1
+    ///
+    /// # Returns
+    /// * `SatError::InvalidOutput` if the model does not satisfy the ordering and merge
+    ///   constraints, e.g. a model of an external solver which is not a model of the formula
+    pub fn decode(&self, model: &[bool]) -> Result<ContractionSequence, SatError> {
+        //Variables which are missing in the model are false
+        let value = |lit: Lit| model.get(lit as usize - 1).copied().unwrap_or(false);
+        let mut sequence = ContractionSequence::new();
+        for (step, row) in self.eliminated.iter().enumerate() {
+            let i = row.iter().position(|lit| value(*lit)).ok_or_else(|| {
+                SatError::InvalidOutput(format!("model eliminates no node in step {step}"))
+            })?;
+            let j = self.parent[i]
+                .iter()
+                .position(|lit| lit.is_some_and(value))
+                .ok_or_else(|| {
+                    SatError::InvalidOutput(format!(
+                        "model contracts node {} into no node",
+                        self.nodes[i]
+                    ))
+                })?;
+            sequence.push(self.nodes[j], self.nodes[i]);
+        }
+        Ok(sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+use super::Encoding;
+use crate::{
+    algo::{branch_and_bound::BranchAndBound, Algo},
+    graph::Graph,
+    random::Rng,
+    sat::{Cdcl, SatError, SatResult, SatSolver},
+    verify::verify,
+};
+
+/// Solves the encoding and checks that a decoded model respects the width
+fn is_satisfiable(graph: &Graph, width: usize) -> bool {
+    let encoding = Encoding::new(graph, width);
+    match Cdcl::new().solve(encoding.get_cnf()).unwrap() {
+        SatResult::Satisfiable(model) => {
+            assert!(encoding.get_cnf().is_satisfied_by(&model));
+            let sequence = encoding.decode(&model).unwrap();
+            let twin_width = verify(graph, &sequence).unwrap();
+            assert!(twin_width <= width);
+            true
+        }
+        SatResult::Unsatisfiable => false,
+        SatResult::Unknown => panic!("Cdcl without limit gave up"),
+    }
+}
+
+#[test]
+fn test_single_node() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+    let encoding = Encoding::new(&graph, 0);
+
+    assert_eq!(encoding.get_cnf().get_variable_count(), 0);
+    assert!(encoding.decode(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_decode_invalid_model() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    let encoding = Encoding::new(&graph, 1);
+    let variables = encoding.get_cnf().get_variable_count();
+
+    assert!(matches!(
+        encoding.decode(&vec![false; variables]),
+        Err(SatError::InvalidOutput(_))
+    ));
+}
+
+#[test]
+fn test_cograph() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
+
+    assert!(is_satisfiable(&graph, 0));
+}
+
+#[test]
+fn test_path() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+
+    assert!(!is_satisfiable(&graph, 0));
+    assert!(is_satisfiable(&graph, 1));
+}
+
+#[test]
+fn test_cycle() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 1)]);
+
+    assert!(!is_satisfiable(&graph, 1));
+    assert!(is_satisfiable(&graph, 2));
+}
+
+#[test]
+fn test_same_as_branch_and_bound() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for density in [2, 3] {
+        for _ in 0..5 {
+            let mut graph = Graph::new();
+            (1..=7).for_each(|node| graph.add_node(node));
+            for node_a in 1..=7 {
+                for node_b in node_a + 1..=7 {
+                    if rng.below(density) == 0 {
+                        graph.add_edge(node_a, node_b);
+                    }
+                }
+            }
+
+            let (_, twin_width) = BranchAndBound::new_with_graph(graph.clone()).solve();
+
+            assert!(is_satisfiable(&graph, twin_width));
+            if twin_width > 0 {
+                assert!(!is_satisfiable(&graph, twin_width - 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,745 @@
+//! This module contains the interface to SAT solvers and its implementations
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead, BufReader},
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
+};
+
+use super::cnf::{Cnf, Lit};
+use crate::anytime::CancellationToken;
+
+/// The answer of a SAT solver
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SatResult {
+    /// The formula is satisfiable, the model holds the value of every variable (`model[0]` is variable 1)
+    Satisfiable(Vec<bool>),
+    /// The formula is unsatisfiable
+    Unsatisfiable,
+    /// The solver gave up, e.g. because of a limit
+    Unknown,
+}
+
+/// Reasons why a SAT solver could not be used
+#[derive(Debug)]
+pub enum SatError {
+    /// Starting or communicating with an external solver failed
+    Io(io::Error),
+    /// The output of an external solver could not be understood
+    InvalidOutput(String),
+}
+
+impl fmt::Display for SatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SatError::Io(err) => write!(f, "failed to run the sat solver: {err}"),
+            SatError::InvalidOutput(output) => {
+                write!(f, "unexpected output of the sat solver: {output}")
+            }
+        }
+    }
+}
+
+impl Error for SatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SatError::Io(err) => Some(err),
+            SatError::InvalidOutput(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for SatError {
+    fn from(err: io::Error) -> Self {
+        SatError::Io(err)
+    }
+}
+
+/// A backend which decides the satisfiability of a formula
+pub trait SatSolver {
+    fn solve(&mut self, cnf: &Cnf) -> Result<SatResult, SatError>;
+}
+
+/// A solver binary following the conventions of the SAT competition.
+///
+/// The formula is written in DIMACS format to the stdin of the process. The solver has to print
+/// `s SATISFIABLE` or `s UNSATISFIABLE` and the model in `v` lines, e.g. kissat or cadical.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExternalSolver {
+    program: String,
+    args: Vec<String>,
+    token: Option<CancellationToken>,
+}
+
+impl ExternalSolver {
+    /// Creates a new `ExternalSolver`
+    ///
+    /// # Parameters
+    /// * program: The solver binary, either a path or a name in PATH
+    /// * args: Additional arguments, e.g. to suppress statistics
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::sat::ExternalSolver;
+    /// let solver = ExternalSolver::new("kissat", vec!["-q".to_string()]);
+    /// ```
+    pub fn new(program: &str, args: Vec<String>) -> Self {
+        ExternalSolver {
+            program: program.to_string(),
+            args,
+            token: None,
+        }
+    }
+
+    /// Kills the process and answers `SatResult::Unknown` once the token is cancelled
+    pub fn with_token(self, token: CancellationToken) -> Self {
+        ExternalSolver {
+            token: Some(token),
+            ..self
+        }
+    }
+}
+
+impl SatSolver for ExternalSolver {
+    fn solve(&mut self, cnf: &Cnf) -> Result<SatResult, SatError> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        //Write in a separate thread, so a solver printing early cannot block us
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let dimacs = cnf.to_dimacs();
+        let writer = thread::spawn(move || {
+            let mut stdin = stdin;
+            io::Write::write_all(&mut stdin, dimacs.as_bytes())
+        });
+
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let variables = cnf.get_variable_count();
+        let reader = thread::spawn(move || parse_output(BufReader::new(stdout), variables));
+
+        while child.try_wait()?.is_none() {
+            if self
+                .token
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                //The process may have exited in the meantime, so killing it may fail
+                let _ = child.kill();
+                child.wait()?;
+                return Ok(SatResult::Unknown);
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        let result = reader.join().expect("The reader does not panic");
+        //A solver may stop reading once it knows the answer, so a broken pipe is fine
+        if let Ok(Err(err)) = writer.join() {
+            if err.kind() != io::ErrorKind::BrokenPipe {
+                return Err(SatError::Io(err));
+            }
+        }
+        result
+    }
+}
+
+/// Parses the output of a solver in the format of the SAT competition
+pub(crate) fn parse_output<R: BufRead>(reader: R, variables: usize) -> Result<SatResult, SatError> {
+    let mut status: Option<bool> = None;
+    let mut model = vec![false; variables];
+    let mut has_model = false;
+
+    for line in reader.lines() {
+        let line = line?;
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("s") => match tokens.collect::<Vec<_>>().join(" ").as_str() {
+                "SATISFIABLE" => status = Some(true),
+                "UNSATISFIABLE" => status = Some(false),
+                "UNKNOWN" => return Ok(SatResult::Unknown),
+                _ => return Err(SatError::InvalidOutput(line)),
+            },
+            Some("v") => {
+                has_model = true;
+                for token in tokens {
+                    let lit: Lit = token
+                        .parse()
+                        .map_err(|_| SatError::InvalidOutput(line.clone()))?;
+                    let var = lit.unsigned_abs() as usize;
+                    if var > variables {
+                        return Err(SatError::InvalidOutput(line.clone()));
+                    }
+                    if var > 0 {
+                        model[var - 1] = lit > 0;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match status {
+        Some(true) if has_model => Ok(SatResult::Satisfiable(model)),
+        Some(true) => Err(SatError::InvalidOutput("missing v line".to_string())),
+        Some(false) => Ok(SatResult::Unsatisfiable),
+        None => Err(SatError::InvalidOutput("missing s line".to_string())),
+    }
+}
+
+/// A small embedded CDCL solver.
+///
+/// It uses two watched literals, first UIP clause learning with minimisation, VSIDS branching
+/// with phase saving, Luby restarts and deletion of learnt clauses by their number of decision
+/// levels. It is fast enough for graphs with about 20 vertices, but no match for a competition
+/// solver on larger graphs, use an `ExternalSolver` there.
+#[derive(Debug, Clone, Default)]
+pub struct Cdcl {
+    conflict_limit: Option<u64>,
+    token: CancellationToken,
+}
+
+impl Cdcl {
+    /// Creates a new `Cdcl` solver without a limit
+    pub fn new() -> Self {
+        Cdcl::default()
+    }
+
+    /// Creates a new `Cdcl` solver which answers `SatResult::Unknown` after `limit` conflicts
+    pub fn with_conflict_limit(limit: u64) -> Self {
+        Cdcl {
+            conflict_limit: Some(limit),
+            ..Cdcl::default()
+        }
+    }
+
+    /// Creates a new `Cdcl` solver which answers `SatResult::Unknown` once the token is cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::anytime::CancellationToken;
+    /// use twinwidth::sat::{Cdcl, Cnf, SatResult, SatSolver};
+    /// let token = CancellationToken::new();
+    /// token.cancel();
+    /// let mut cnf = Cnf::new();
+    /// let a = cnf.new_var();
+    /// cnf.add_clause(vec![a]);
+    /// assert_eq!(Cdcl::with_token(token).solve(&cnf).unwrap(), SatResult::Unknown);
+    /// ```
+    pub fn with_token(token: CancellationToken) -> Self {
+        Cdcl {
+            token,
+            ..Cdcl::default()
+        }
+    }
+}
+
+impl SatSolver for Cdcl {
+    fn solve(&mut self, cnf: &Cnf) -> Result<SatResult, SatError> {
+        if self.token.is_cancelled() {
+            return Ok(SatResult::Unknown);
+        }
+        let mut state = CdclState::new(cnf.get_variable_count());
+        for clause in cnf.get_clauses() {
+            if !state.add_clause(clause) {
+                return Ok(SatResult::Unsatisfiable);
+            }
+        }
+        Ok(state.search(self.conflict_limit, &self.token))
+    }
+}
+
+const TRUE: i8 = 1;
+const FALSE: i8 = -1;
+const UNASSIGNED: i8 = 0;
+
+/// Literals are stored as `2 * variable + negated` with variables starting at 0
+fn to_internal(lit: Lit) -> usize {
+    (lit.unsigned_abs() as usize - 1) * 2 + usize::from(lit < 0)
+}
+
+/// A clause whose first two literals are watched
+struct Clause {
+    lits: Vec<usize>,
+    learnt: bool,
+    /// Number of decision levels in the clause when it was learnt
+    lbd: usize,
+}
+
+/// A watched clause with a literal of it which is checked first, if it is true nothing has to be done
+#[derive(Clone, Copy)]
+struct Watch {
+    clause: usize,
+    blocker: usize,
+}
+
+struct CdclState {
+    clauses: Vec<Clause>,
+    watches: Vec<Vec<Watch>>,
+    /// Value of every literal, the two literals of a variable always have opposite values
+    values: Vec<i8>,
+    levels: Vec<usize>,
+    reasons: Vec<Option<usize>>,
+    phases: Vec<bool>,
+    trail: Vec<usize>,
+    trail_limits: Vec<usize>,
+    propagated: usize,
+    activity: Vec<f64>,
+    increment: f64,
+    heap: VarHeap,
+    seen: Vec<bool>,
+}
+
+impl CdclState {
+    fn new(variables: usize) -> Self {
+        let mut heap = VarHeap::new(variables);
+        let activity = vec![0.0; variables];
+        (0..variables).for_each(|var| heap.insert(var, &activity));
+        CdclState {
+            clauses: Vec::new(),
+            watches: vec![Vec::new(); 2 * variables],
+            values: vec![UNASSIGNED; 2 * variables],
+            levels: vec![0; variables],
+            reasons: vec![None; variables],
+            phases: vec![false; variables],
+            trail: Vec::new(),
+            trail_limits: Vec::new(),
+            propagated: 0,
+            activity,
+            increment: 1.0,
+            heap,
+            seen: vec![false; variables],
+        }
+    }
+
+    fn level(&self) -> usize {
+        self.trail_limits.len()
+    }
+
+    /// Adds an original clause at level 0, returns false if the formula became unsatisfiable
+    fn add_clause(&mut self, clause: &[Lit]) -> bool {
+        let mut lits: Vec<usize> = clause.iter().map(|lit| to_internal(*lit)).collect();
+        lits.sort_unstable();
+        lits.dedup();
+        if lits.windows(2).any(|pair| pair[0] ^ 1 == pair[1]) {
+            return true;
+        }
+        lits.retain(|lit| self.values[*lit] != FALSE);
+        if lits.iter().any(|lit| self.values[*lit] == TRUE) {
+            return true;
+        }
+
+        match lits.len() {
+            0 => false,
+            1 => {
+                self.enqueue(lits[0], None);
+                self.propagate().is_none()
+            }
+            _ => {
+                self.attach(lits, false, 0);
+                true
+            }
+        }
+    }
+
+    fn attach(&mut self, lits: Vec<usize>, learnt: bool, lbd: usize) -> usize {
+        let index = self.clauses.len();
+        self.watches[lits[0]].push(Watch {
+            clause: index,
+            blocker: lits[1],
+        });
+        self.watches[lits[1]].push(Watch {
+            clause: index,
+            blocker: lits[0],
+        });
+        self.clauses.push(Clause { lits, learnt, lbd });
+        index
+    }
+
+    fn enqueue(&mut self, lit: usize, reason: Option<usize>) {
+        let var = lit / 2;
+        self.values[lit] = TRUE;
+        self.values[lit ^ 1] = FALSE;
+        self.levels[var] = self.level();
+        self.reasons[var] = reason;
+        self.trail.push(lit);
+    }
+
+    /// Propagates all unit clauses, returns a conflicting clause if there is one
+    fn propagate(&mut self) -> Option<usize> {
+        while self.propagated < self.trail.len() {
+            let false_lit = self.trail[self.propagated] ^ 1;
+            self.propagated += 1;
+
+            let mut watches = std::mem::take(&mut self.watches[false_lit]);
+            let mut kept = 0;
+            let mut position = 0;
+            let mut conflict = None;
+
+            while position < watches.len() {
+                let watch = watches[position];
+                position += 1;
+                if self.values[watch.blocker] == TRUE {
+                    watches[kept] = watch;
+                    kept += 1;
+                    continue;
+                }
+
+                let lits = &mut self.clauses[watch.clause].lits;
+                if lits[0] == false_lit {
+                    lits.swap(0, 1);
+                }
+                let first = lits[0];
+                let watch = Watch {
+                    clause: watch.clause,
+                    blocker: first,
+                };
+                if self.values[first] == TRUE {
+                    watches[kept] = watch;
+                    kept += 1;
+                    continue;
+                }
+
+                if let Some(k) = (2..lits.len()).find(|k| self.values[lits[*k]] != FALSE) {
+                    lits.swap(1, k);
+                    self.watches[lits[1]].push(watch);
+                    continue;
+                }
+
+                watches[kept] = watch;
+                kept += 1;
+                if self.values[first] == FALSE {
+                    conflict = Some(watch.clause);
+                    while position < watches.len() {
+                        watches[kept] = watches[position];
+                        kept += 1;
+                        position += 1;
+                    }
+                } else {
+                    self.enqueue(first, Some(watch.clause));
+                }
+            }
+
+            watches.truncate(kept);
+            self.watches[false_lit] = watches;
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+        None
+    }
+
+    /// Derives the first UIP clause of a conflict and removes literals implied by the others
+    ///
+    /// # Returns
+    /// * The learnt clause with the asserting literal first and the level to jump back to
+    fn analyze(&mut self, conflict: usize) -> (Vec<usize>, usize) {
+        let mut learnt = vec![0];
+        let mut pending = 0;
+        let mut reason = conflict;
+        let mut asserting: Option<usize> = None;
+        let mut index = self.trail.len();
+
+        loop {
+            let skip = usize::from(asserting.is_some());
+            for position in skip..self.clauses[reason].lits.len() {
+                let lit = self.clauses[reason].lits[position];
+                let var = lit / 2;
+                if !self.seen[var] && self.levels[var] > 0 {
+                    self.bump(var);
+                    self.seen[var] = true;
+                    if self.levels[var] == self.level() {
+                        pending += 1;
+                    } else {
+                        learnt.push(lit);
+                    }
+                }
+            }
+
+            loop {
+                index -= 1;
+                if self.seen[self.trail[index] / 2] {
+                    break;
+                }
+            }
+            let lit = self.trail[index];
+            self.seen[lit / 2] = false;
+            asserting = Some(lit);
+            pending -= 1;
+            if pending == 0 {
+                break;
+            }
+            reason = self.reasons[lit / 2].expect("Implied literals have a reason");
+        }
+        learnt[0] = asserting.expect("A conflict has a literal on the current level") ^ 1;
+
+        //A literal is redundant if its reason only contains literals of the clause
+        let collected = learnt.clone();
+        learnt.retain(|lit| match self.reasons[lit / 2] {
+            Some(reason) if *lit != collected[0] => self.clauses[reason].lits[1..]
+                .iter()
+                .any(|other| !self.seen[other / 2] && self.levels[other / 2] > 0),
+            _ => true,
+        });
+        collected[1..]
+            .iter()
+            .for_each(|lit| self.seen[lit / 2] = false);
+
+        let mut backtrack = 0;
+        if learnt.len() > 1 {
+            let (position, _) = learnt
+                .iter()
+                .enumerate()
+                .skip(1)
+                .max_by_key(|(_, lit)| self.levels[*lit / 2])
+                .expect("The learnt clause has more than one literal");
+            learnt.swap(1, position);
+            backtrack = self.levels[learnt[1] / 2];
+        }
+        (learnt, backtrack)
+    }
+
+    fn bump(&mut self, var: usize) {
+        self.activity[var] += self.increment;
+        if self.activity[var] > 1e100 {
+            self.activity
+                .iter_mut()
+                .for_each(|activity| *activity *= 1e-100);
+            self.increment *= 1e-100;
+        }
+        self.heap.increase(var, &self.activity);
+    }
+
+    fn cancel_until(&mut self, level: usize) {
+        if self.level() <= level {
+            return;
+        }
+        let limit = self.trail_limits[level];
+        for &lit in &self.trail[limit..] {
+            let var = lit / 2;
+            self.phases[var] = lit.is_multiple_of(2);
+            self.values[lit] = UNASSIGNED;
+            self.values[lit ^ 1] = UNASSIGNED;
+            self.reasons[var] = None;
+            self.heap.insert(var, &self.activity);
+        }
+        self.trail.truncate(limit);
+        self.trail_limits.truncate(level);
+        self.propagated = self.trail.len();
+    }
+
+    /// Deletes the half of the learnt clauses with the most decision levels.
+    /// Clauses with at most two levels and reasons of assigned literals are kept.
+    fn reduce(&mut self) {
+        let locked = |state: &Self, index: usize| {
+            let first = state.clauses[index].lits[0];
+            state.values[first] == TRUE && state.reasons[first / 2] == Some(index)
+        };
+        let mut candidates: Vec<usize> = (0..self.clauses.len())
+            .filter(|index| {
+                let clause = &self.clauses[*index];
+                clause.learnt && clause.lbd > 2 && !locked(self, *index)
+            })
+            .collect();
+        candidates.sort_by_key(|index| {
+            let clause = &self.clauses[*index];
+            std::cmp::Reverse((clause.lbd, clause.lits.len()))
+        });
+        let mut deleted = vec![false; self.clauses.len()];
+        candidates[..candidates.len() / 2]
+            .iter()
+            .for_each(|index| deleted[*index] = true);
+
+        let mut renamed = vec![None; self.clauses.len()];
+        let clauses = std::mem::take(&mut self.clauses);
+        for (index, clause) in clauses.into_iter().enumerate() {
+            if !deleted[index] {
+                renamed[index] = Some(self.clauses.len());
+                self.clauses.push(clause);
+            }
+        }
+        for reason in self.reasons.iter_mut() {
+            *reason = reason.and_then(|index| renamed[index]);
+        }
+
+        self.watches.iter_mut().for_each(|watches| watches.clear());
+        for (index, clause) in self.clauses.iter().enumerate() {
+            self.watches[clause.lits[0]].push(Watch {
+                clause: index,
+                blocker: clause.lits[1],
+            });
+            self.watches[clause.lits[1]].push(Watch {
+                clause: index,
+                blocker: clause.lits[0],
+            });
+        }
+    }
+
+    fn search(&mut self, conflict_limit: Option<u64>, token: &CancellationToken) -> SatResult {
+        let mut conflicts: u64 = 0;
+        let mut restart = 1;
+        let mut restart_conflicts: u64 = 0;
+        let mut next_reduce: u64 = 2000;
+        let mut reduce_increment: u64 = 2000;
+
+        loop {
+            match self.propagate() {
+                Some(conflict) => {
+                    conflicts += 1;
+                    restart_conflicts += 1;
+                    if self.level() == 0 {
+                        return SatResult::Unsatisfiable;
+                    }
+                    if conflict_limit.is_some_and(|limit| conflicts >= limit)
+                        || token.is_cancelled()
+                    {
+                        return SatResult::Unknown;
+                    }
+
+                    let (learnt, backtrack) = self.analyze(conflict);
+                    self.cancel_until(backtrack);
+                    if learnt.len() == 1 {
+                        self.enqueue(learnt[0], None);
+                    } else {
+                        let mut levels: Vec<usize> =
+                            learnt.iter().map(|lit| self.levels[lit / 2]).collect();
+                        levels.sort_unstable();
+                        levels.dedup();
+                        let asserting = learnt[0];
+                        let index = self.attach(learnt, true, levels.len());
+                        self.enqueue(asserting, Some(index));
+                    }
+                    self.increment /= 0.95;
+
+                    if conflicts >= next_reduce {
+                        reduce_increment += 300;
+                        next_reduce = conflicts + reduce_increment;
+                        self.reduce();
+                    }
+                }
+                None => {
+                    if restart_conflicts >= 100 * luby(restart) {
+                        restart += 1;
+                        restart_conflicts = 0;
+                        self.cancel_until(0);
+                    }
+
+                    let var = loop {
+                        match self.heap.pop(&self.activity) {
+                            Some(var) if self.values[2 * var] != UNASSIGNED => continue,
+                            other => break other,
+                        }
+                    };
+                    let Some(var) = var else {
+                        let model = (0..self.levels.len())
+                            .map(|var| self.values[2 * var] == TRUE)
+                            .collect();
+                        return SatResult::Satisfiable(model);
+                    };
+
+                    self.trail_limits.push(self.trail.len());
+                    let lit = 2 * var + usize::from(!self.phases[var]);
+                    self.enqueue(lit, None);
+                }
+            }
+        }
+    }
+}
+
+/// The i-th element (starting at 1) of the Luby sequence 1, 1, 2, 1, 1, 2, 4, ...
+fn luby(mut index: u64) -> u64 {
+    loop {
+        let mut power = 1;
+        while power * 2 - 1 < index {
+            power *= 2;
+        }
+        if power * 2 - 1 == index {
+            return power;
+        }
+        index -= power - 1;
+    }
+}
+
+/// Binary max heap of variables ordered by activity
+struct VarHeap {
+    heap: Vec<usize>,
+    positions: Vec<Option<usize>>,
+}
+
+impl VarHeap {
+    fn new(variables: usize) -> Self {
+        VarHeap {
+            heap: Vec::with_capacity(variables),
+            positions: vec![None; variables],
+        }
+    }
+
+    fn insert(&mut self, var: usize, activity: &[f64]) {
+        if self.positions[var].is_some() {
+            return;
+        }
+        self.heap.push(var);
+        self.positions[var] = Some(self.heap.len() - 1);
+        self.up(self.heap.len() - 1, activity);
+    }
+
+    fn increase(&mut self, var: usize, activity: &[f64]) {
+        if let Some(position) = self.positions[var] {
+            self.up(position, activity);
+        }
+    }
+
+    fn pop(&mut self, activity: &[f64]) -> Option<usize> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let top = self.heap.swap_remove(0);
+        self.positions[top] = None;
+        if !self.heap.is_empty() {
+            self.positions[self.heap[0]] = Some(0);
+            self.down(0, activity);
+        }
+        Some(top)
+    }
+
+    fn up(&mut self, mut position: usize, activity: &[f64]) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if activity[self.heap[parent]] >= activity[self.heap[position]] {
+                break;
+            }
+            self.swap(parent, position);
+            position = parent;
+        }
+    }
+
+    fn down(&mut self, mut position: usize, activity: &[f64]) {
+        loop {
+            let mut largest = position;
+            for child in [2 * position + 1, 2 * position + 2] {
+                if child < self.heap.len()
+                    && activity[self.heap[child]] > activity[self.heap[largest]]
+                {
+                    largest = child;
+                }
+            }
+            if largest == position {
+                break;
+            }
+            self.swap(largest, position);
+            position = largest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+}
+
+#[cfg(test)]
+mod tests;
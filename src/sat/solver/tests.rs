@@ -0,0 +1,167 @@
+use std::{
+    io::Cursor,
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{parse_output, Cdcl, ExternalSolver, SatError, SatResult, SatSolver};
+use crate::{anytime::CancellationToken, random::Rng, sat::Cnf};
+
+fn brute_force(cnf: &Cnf) -> bool {
+    let variables = cnf.get_variable_count();
+    (0..1u64 << variables).any(|bits| {
+        let model: Vec<bool> = (0..variables).map(|bit| bits >> bit & 1 == 1).collect();
+        cnf.is_satisfied_by(&model)
+    })
+}
+
+/// n + 1 pigeons in n holes
+fn pigeonhole(holes: usize) -> Cnf {
+    let mut cnf = Cnf::new();
+    let sits: Vec<Vec<i32>> = (0..=holes)
+        .map(|_| (0..holes).map(|_| cnf.new_var()).collect())
+        .collect();
+    for pigeon in &sits {
+        cnf.add_clause(pigeon.clone());
+    }
+    for hole in 0..holes {
+        let pigeons: Vec<i32> = sits.iter().map(|pigeon| pigeon[hole]).collect();
+        cnf.add_at_most(&pigeons, 1);
+    }
+    cnf
+}
+
+#[test]
+fn test_cdcl_empty_formula() {
+    assert_eq!(
+        Cdcl::new().solve(&Cnf::new()).unwrap(),
+        SatResult::Satisfiable(Vec::new())
+    );
+}
+
+#[test]
+fn test_cdcl_contradicting_units() {
+    let mut cnf = Cnf::new();
+    let a = cnf.new_var();
+    cnf.add_clause(vec![a]);
+    cnf.add_clause(vec![-a]);
+
+    assert_eq!(Cdcl::new().solve(&cnf).unwrap(), SatResult::Unsatisfiable);
+}
+
+#[test]
+fn test_cdcl_same_as_brute_force() {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..300 {
+        let mut cnf = Cnf::new();
+        let variables = 3 + rng.below(8);
+        (0..variables).for_each(|_| {
+            cnf.new_var();
+        });
+        let clauses = 2 + rng.below(5 * variables);
+        for _ in 0..clauses {
+            let clause = (0..3)
+                .map(|_| {
+                    let var = (1 + rng.below(variables)) as i32;
+                    if rng.below(2) == 0 {
+                        var
+                    } else {
+                        -var
+                    }
+                })
+                .collect();
+            cnf.add_clause(clause);
+        }
+
+        match Cdcl::new().solve(&cnf).unwrap() {
+            SatResult::Satisfiable(model) => assert!(cnf.is_satisfied_by(&model)),
+            SatResult::Unsatisfiable => assert!(!brute_force(&cnf)),
+            SatResult::Unknown => panic!("Cdcl without limit gave up"),
+        }
+    }
+}
+
+#[test]
+fn test_cdcl_pigeonhole() {
+    assert_eq!(
+        Cdcl::new().solve(&pigeonhole(6)).unwrap(),
+        SatResult::Unsatisfiable
+    );
+}
+
+#[test]
+fn test_cdcl_conflict_limit() {
+    assert_eq!(
+        Cdcl::with_conflict_limit(1).solve(&pigeonhole(6)).unwrap(),
+        SatResult::Unknown
+    );
+}
+
+#[test]
+fn test_cdcl_cancelled_during_search() {
+    let token = CancellationToken::new();
+    let canceller = {
+        let token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        })
+    };
+
+    //Pigeonhole formulas of this size need far more than the test takes
+    let start = Instant::now();
+    let result = Cdcl::with_token(token).solve(&pigeonhole(12)).unwrap();
+
+    assert_eq!(result, SatResult::Unknown);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    canceller.join().unwrap();
+}
+
+#[test]
+fn test_parse_output() {
+    let output = "c comment\ns SATISFIABLE\nv -1 2\nv -3 0\n";
+
+    assert_eq!(
+        parse_output(Cursor::new(output), 3).unwrap(),
+        SatResult::Satisfiable(vec![false, true, false])
+    );
+    assert_eq!(
+        parse_output(Cursor::new("s UNSATISFIABLE\n"), 3).unwrap(),
+        SatResult::Unsatisfiable
+    );
+    assert!(matches!(
+        parse_output(Cursor::new("c nothing\n"), 3),
+        Err(SatError::InvalidOutput(_))
+    ));
+    assert!(matches!(
+        parse_output(Cursor::new("s SATISFIABLE\nv 4 0\n"), 3),
+        Err(SatError::InvalidOutput(_))
+    ));
+    //A satisfiable answer without a model can not be decoded
+    assert!(matches!(
+        parse_output(Cursor::new("s SATISFIABLE\n"), 3),
+        Err(SatError::InvalidOutput(_))
+    ));
+}
+
+#[test]
+fn test_external_solver_missing_binary() {
+    let mut solver = ExternalSolver::new("this-sat-solver-does-not-exist", Vec::new());
+
+    assert!(matches!(solver.solve(&Cnf::new()), Err(SatError::Io(_))));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_external_solver_cancelled() {
+    //sleep neither reads the formula nor answers, so only killing it ends the solve
+    let token = CancellationToken::new().with_deadline(Instant::now() + Duration::from_millis(50));
+    let mut solver = ExternalSolver::new("sleep", vec!["10".to_string()]).with_token(token);
+
+    let start = Instant::now();
+    let result = solver.solve(&pigeonhole(3)).unwrap();
+
+    assert_eq!(result, SatResult::Unknown);
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,202 @@
+//! This module contains the contraction sequence and its .tww representation
+use std::{error::Error, fmt, fmt::Write, ops::Deref};
+
+use crate::graph::{is_comment, tokens};
+
+/// Reasons why a string is not a valid .tww contraction sequence.
+/// Lines and columns start at 1.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TwwParseError {
+    /// A token is not a valid vertex
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+    /// A contraction line consists of a single vertex
+    IncompleteContraction { line: usize },
+    /// A line contains more than two vertices
+    TrailingGarbage { line: usize, column: usize },
+}
+
+impl fmt::Display for TwwParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwwParseError::InvalidNumber {
+                line,
+                column,
+                token,
+            } => write!(f, "line {line}, column {column}: '{token}' is not a vertex"),
+            TwwParseError::IncompleteContraction { line } => {
+                write!(f, "line {line}: a contraction needs two vertices")
+            }
+            TwwParseError::TrailingGarbage { line, column } => {
+                write!(f, "line {line}, column {column}: unexpected trailing input")
+            }
+        }
+    }
+}
+
+impl Error for TwwParseError {}
+
+/// A sequence of contractions `(a, b)`, where `b` is merged into `a`.
+///
+/// The sequence dereferences to a slice of pairs, so it can be passed to `verify::verify` directly.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct ContractionSequence {
+    contractions: Vec<(u32, u32)>,
+}
+
+impl ContractionSequence {
+    /// Creates an empty `ContractionSequence`.
+    ///
+    /// # Examples
+    /// ```
+    /// use twinwidth::sequence::ContractionSequence;
+    /// let sequence = ContractionSequence::new();
+    /// ```
+    pub fn new() -> Self {
+        ContractionSequence {
+            contractions: Vec::new(),
+        }
+    }
+
+    /// Appends a contraction to the end of the sequence
+    ///
+    /// # Parameters
+    /// * node_a: The vertex which remains
+    /// * node_b: The vertex which is merged into node_a
+    pub fn push(&mut self, node_a: u32, node_b: u32) {
+        self.contractions.push((node_a, node_b));
+    }
+
+    /// Reads a contraction sequence from a string following the .tww format.
+    /// See [Pace IO Definition](https://pacechallenge.org/2023/io/) for more information.
+    ///
+    /// Comment lines start with `c`, blank lines are ignored.
+    /// Every other line has to consist of exactly two vertices.
+    ///
+    /// # Parameter
+    /// * tww: string slice containing the contraction sequence
+    ///
+    /// # Returns
+    /// * The contraction sequence in the order of the lines
+    /// * A `TwwParseError` describing the first malformed line
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::sequence::ContractionSequence;
+    /// let tww = "c solution\n\
+    ///            2 1\n\
+    ///            2 3\n";
+    /// let sequence = ContractionSequence::from_tww(tww).unwrap();
+    /// assert_eq!(sequence.to_vec(), vec![(2, 1), (2, 3)]);
+    /// ```
+    pub fn from_tww(tww: &str) -> Result<Self, TwwParseError> {
+        let mut sequence = Self::new();
+
+        for (index, line) in tww.lines().enumerate() {
+            let line_number = index + 1;
+            if is_comment(line) {
+                continue;
+            }
+            let tokens = tokens(line);
+
+            let (node_a, node_b) = match tokens.as_slice() {
+                [] => continue,
+                [a, b] => (*a, *b),
+                [_, _, (column, _), ..] => {
+                    return Err(TwwParseError::TrailingGarbage {
+                        line: line_number,
+                        column: *column,
+                    })
+                }
+                [_] => return Err(TwwParseError::IncompleteContraction { line: line_number }),
+            };
+
+            let mut contraction = [0; 2];
+            for (vertex, (column, token)) in contraction.iter_mut().zip([node_a, node_b]) {
+                *vertex = token.parse().map_err(|_| TwwParseError::InvalidNumber {
+                    line: line_number,
+                    column,
+                    token: token.to_string(),
+                })?;
+            }
+            sequence.push(contraction[0], contraction[1]);
+        }
+
+        Ok(sequence)
+    }
+
+    /// Constructs an string with resepect to the .tww format defined by the pace challenge
+    ///
+    /// # Returns
+    /// A new string with one contraction per line
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::sequence::ContractionSequence;
+    /// let sequence = ContractionSequence::from(vec![(2, 1), (2, 3)]);
+    /// assert_eq!(sequence.to_tww(), "2 1\n2 3\n");
+    /// ```
+    pub fn to_tww(&self) -> String {
+        let mut tww = String::new();
+        self.contractions.iter().for_each(|(node_a, node_b)| {
+            writeln!(&mut tww, "{} {}", node_a, node_b).unwrap();
+        });
+        tww
+    }
+
+    /// Finds the first position where two sequences differ
+    ///
+    /// # Parameters
+    /// * other: The sequence to compare with
+    ///
+    /// # Returns
+    /// * The index of the first differing contraction, or the length of the shorter sequence
+    ///   if one is a prefix of the other
+    /// * None if both sequences are equal
+    ///
+    /// # Example
+    /// ```
+    /// use twinwidth::sequence::ContractionSequence;
+    /// let ours = ContractionSequence::from(vec![(2, 1), (2, 3)]);
+    /// let theirs = ContractionSequence::from(vec![(2, 1), (3, 2)]);
+    /// assert_eq!(ours.first_difference(&theirs), Some(1));
+    /// ```
+    pub fn first_difference(&self, other: &ContractionSequence) -> Option<usize> {
+        match self
+            .contractions
+            .iter()
+            .zip(&other.contractions)
+            .position(|(ours, theirs)| ours != theirs)
+        {
+            Some(index) => Some(index),
+            None if self.len() != other.len() => Some(self.len().min(other.len())),
+            None => None,
+        }
+    }
+}
+
+impl Deref for ContractionSequence {
+    type Target = [(u32, u32)];
+
+    fn deref(&self) -> &Self::Target {
+        &self.contractions
+    }
+}
+
+impl From<Vec<(u32, u32)>> for ContractionSequence {
+    fn from(contractions: Vec<(u32, u32)>) -> Self {
+        ContractionSequence { contractions }
+    }
+}
+
+impl From<ContractionSequence> for Vec<(u32, u32)> {
+    fn from(sequence: ContractionSequence) -> Self {
+        sequence.contractions
+    }
+}
+
+#[cfg(test)]
+mod tests;
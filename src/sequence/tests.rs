@@ -0,0 +1,100 @@
+use super::{ContractionSequence, TwwParseError};
+use crate::{graph::Graph, verify::verify};
+
+#[test]
+fn test_from_tww() {
+    let tww = "c computed by another solver\n\
+               \n\
+               2 1\n\
+               c in between\n\
+               3   2\n";
+
+    let sequence = ContractionSequence::from_tww(tww).unwrap();
+
+    assert_eq!(sequence.to_vec(), vec![(2, 1), (3, 2)]);
+}
+
+#[test]
+fn test_from_tww_comment_line() {
+    //Every line which starts with a c is a comment, like in the official verifier
+    let sequence = ContractionSequence::from_tww("comment\n1 2\n").unwrap();
+    assert_eq!(sequence.to_vec(), vec![(1, 2)]);
+
+    assert_eq!(
+        ContractionSequence::from_tww(" c 1\n"),
+        Err(TwwParseError::InvalidNumber {
+            line: 1,
+            column: 2,
+            token: "c".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_from_tww_empty() {
+    assert_eq!(
+        ContractionSequence::from_tww(""),
+        Ok(ContractionSequence::new())
+    );
+}
+
+#[test]
+fn test_from_tww_invalid_number() {
+    assert_eq!(
+        ContractionSequence::from_tww("1 2\n3 -4\n"),
+        Err(TwwParseError::InvalidNumber {
+            line: 2,
+            column: 3,
+            token: "-4".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_from_tww_incomplete_contraction() {
+    assert_eq!(
+        ContractionSequence::from_tww("1\n"),
+        Err(TwwParseError::IncompleteContraction { line: 1 })
+    );
+}
+
+#[test]
+fn test_from_tww_trailing_garbage() {
+    assert_eq!(
+        ContractionSequence::from_tww("1 2 3\n"),
+        Err(TwwParseError::TrailingGarbage { line: 1, column: 5 })
+    );
+}
+
+#[test]
+fn test_to_tww_round_trip() {
+    let sequence = ContractionSequence::from(vec![(4, 3), (1, 2), (1, 4)]);
+
+    assert_eq!(
+        ContractionSequence::from_tww(&sequence.to_tww()),
+        Ok(sequence)
+    );
+}
+
+#[test]
+fn test_first_difference() {
+    let sequence = ContractionSequence::from(vec![(1, 2), (1, 3)]);
+
+    assert_eq!(sequence.first_difference(&sequence.clone()), None);
+    assert_eq!(
+        sequence.first_difference(&ContractionSequence::from(vec![(1, 2), (3, 1)])),
+        Some(1)
+    );
+    assert_eq!(
+        sequence.first_difference(&ContractionSequence::from(vec![(1, 2)])),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_verify_sequence() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+    let sequence = ContractionSequence::from_tww("2 1\n3 2\n4 3\n").unwrap();
+
+    assert_eq!(verify(&graph, &sequence), Ok(1));
+}
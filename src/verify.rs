@@ -0,0 +1,117 @@
+//! This module contains a verifier for contraction sequences
+//!
+//! It replays a contraction sequence on a graph while keeping track of black and red edges
+//! and evaluates the twin-width of the sequence.
+//! See [Pace Verifier](https://pacechallenge.org/2023/verifier/) for the reference implementation.
+use std::{cmp, collections::HashSet, error::Error, fmt};
+
+use crate::graph::{Graph, Trigraph};
+
+/// Reasons why a contraction sequence is not valid for a graph
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum VerifyError {
+    /// The contraction at `index` names a vertex which was never part of the graph
+    UnknownVertex { index: usize, vertex: u32 },
+    /// The contraction at `index` names a vertex which was already contracted away
+    RemovedVertex { index: usize, vertex: u32 },
+    /// The contraction at `index` contracts a vertex with itself
+    SelfContraction { index: usize, vertex: u32 },
+    /// The sequence ended while more than one vertex is left
+    SequenceTooShort { remaining: usize },
+    /// The graph did not end up as a single vertex (e.g. it was empty in the first place)
+    NotSingleVertex { remaining: usize },
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::UnknownVertex { index, vertex } => {
+                write!(
+                    f,
+                    "contraction {index}: vertex {vertex} is not part of the graph"
+                )
+            }
+            VerifyError::RemovedVertex { index, vertex } => {
+                write!(
+                    f,
+                    "contraction {index}: vertex {vertex} was already contracted"
+                )
+            }
+            VerifyError::SelfContraction { index, vertex } => {
+                write!(
+                    f,
+                    "contraction {index}: vertex {vertex} cannot be contracted with itself"
+                )
+            }
+            VerifyError::SequenceTooShort { remaining } => {
+                write!(
+                    f,
+                    "the graph was not completely contracted, {remaining} vertices are left"
+                )
+            }
+            VerifyError::NotSingleVertex { remaining } => {
+                write!(
+                    f,
+                    "the contracted graph has {remaining} vertices instead of one"
+                )
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+/// Replays a contraction sequence on a graph and computes its twin-width.
+///
+/// Each contraction `(a, b)` merges `b` into `a`, which follows the .tww format of the pace challenge.
+///
+/// # Parameters
+/// * graph: The graph the contraction sequence belongs to
+/// * sequence: The contraction sequence, e.g. as returned by `Algo::solve`
+///
+/// # Returns
+/// * The maximum red degree which occurs while replaying the sequence
+/// * A `VerifyError` if the sequence is not a valid contraction sequence of the graph
+///
+/// # Examples
+/// ```
+/// use twinwidth::graph::Graph;
+/// use twinwidth::verify::verify;
+/// let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+/// let width = verify(&graph, &[(2, 1), (3, 2), (4, 3)]);
+/// assert_eq!(width, Ok(1));
+/// ```
+pub fn verify(graph: &Graph, sequence: &[(u32, u32)]) -> Result<usize, VerifyError> {
+    let mut trigraph = Trigraph::from(graph);
+    let mut removed: HashSet<u32> = HashSet::new();
+    let mut width = 0;
+
+    for (index, &(node_a, node_b)) in sequence.iter().enumerate() {
+        for vertex in [node_a, node_b] {
+            if removed.contains(&vertex) {
+                return Err(VerifyError::RemovedVertex { index, vertex });
+            }
+            if !trigraph.contains_node(vertex) {
+                return Err(VerifyError::UnknownVertex { index, vertex });
+            }
+        }
+        if node_a == node_b {
+            return Err(VerifyError::SelfContraction {
+                index,
+                vertex: node_a,
+            });
+        }
+
+        width = cmp::max(width, trigraph.contract(node_a, node_b));
+        removed.insert(node_b);
+    }
+
+    match trigraph.get_node_count() {
+        1 => Ok(width),
+        remaining if remaining > 1 => Err(VerifyError::SequenceTooShort { remaining }),
+        remaining => Err(VerifyError::NotSingleVertex { remaining }),
+    }
+}
+
+#[cfg(test)]
+mod tests;
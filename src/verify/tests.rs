@@ -0,0 +1,90 @@
+use super::{verify, VerifyError};
+use crate::graph::Graph;
+
+#[test]
+fn test_verify_path() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4)]);
+
+    assert_eq!(verify(&graph, &[(2, 1), (3, 2), (4, 3)]), Ok(1));
+}
+
+#[test]
+fn test_verify_twins() {
+    let graph = Graph::from_edges(vec![(1, 2), (1, 3), (1, 4)]);
+
+    assert_eq!(verify(&graph, &[(2, 3), (2, 4), (1, 2)]), Ok(0));
+}
+
+#[test]
+fn test_verify_red_edges_are_inherited() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+
+    assert_eq!(verify(&graph, &[(1, 2), (1, 3), (4, 5), (1, 4)]), Ok(2));
+}
+
+#[test]
+fn test_verify_single_vertex() {
+    let mut graph = Graph::new();
+    graph.add_node(1);
+
+    assert_eq!(verify(&graph, &[]), Ok(0));
+}
+
+#[test]
+fn test_verify_unknown_vertex() {
+    let graph = Graph::from_edges(vec![(1, 2)]);
+
+    assert_eq!(
+        verify(&graph, &[(1, 3)]),
+        Err(VerifyError::UnknownVertex {
+            index: 0,
+            vertex: 3
+        })
+    );
+}
+
+#[test]
+fn test_verify_removed_vertex() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+
+    assert_eq!(
+        verify(&graph, &[(1, 2), (2, 3)]),
+        Err(VerifyError::RemovedVertex {
+            index: 1,
+            vertex: 2
+        })
+    );
+}
+
+#[test]
+fn test_verify_self_contraction() {
+    let graph = Graph::from_edges(vec![(1, 2)]);
+
+    assert_eq!(
+        verify(&graph, &[(1, 1)]),
+        Err(VerifyError::SelfContraction {
+            index: 0,
+            vertex: 1
+        })
+    );
+}
+
+#[test]
+fn test_verify_sequence_too_short() {
+    let graph = Graph::from_edges(vec![(1, 2), (2, 3)]);
+
+    assert_eq!(
+        verify(&graph, &[(1, 2)]),
+        Err(VerifyError::SequenceTooShort { remaining: 2 })
+    );
+}
+
+#[test]
+fn test_verify_empty_graph() {
+    let graph = Graph::new();
+
+    assert_eq!(
+        verify(&graph, &[]),
+        Err(VerifyError::NotSingleVertex { remaining: 0 })
+    );
+}
@@ -1,8 +1,20 @@
-use std::{collections::HashMap, fs, path::PathBuf, process::Command};
+use std::{collections::HashMap, fs, path::PathBuf};
 
 use twinwidth::{
-    algo::{greedy::Greedy, Algo},
+    algo::{
+        branch_and_bound::{BranchAndBound, Budget},
+        classes::Classes,
+        greedy::Greedy,
+        greedy_v2::GreedyV2,
+        preprocessed::Preprocessed,
+        randomized_greedy::{RandomizedConfig, RandomizedGreedy},
+        sat::Sat,
+        Algo,
+    },
     graph::Graph,
+    local_search::{LocalSearch, LocalSearchConfig},
+    sat::Cdcl,
+    verify::verify,
 };
 
 //TODO: Refactor this or create a second test. One can unse Analyzer now.
@@ -13,7 +25,7 @@ pub fn test_tiny_set() {
         ("tiny002.gr", 2),
         ("tiny003.gr", 0),
         ("tiny004.gr", 0),
-        ("tiny005.gr", 4), //TODO: Debug! Solution is 3.
+        ("tiny005.gr", 4), //Greedy is not optimal, the twin-width is 3. See test_tiny_set_exact.
         ("tiny006.gr", 0),
         ("tiny007.gr", 2),
         ("tiny008.gr", 4),
@@ -27,61 +39,187 @@ pub fn test_tiny_set() {
     let files = fs::read_dir(d).unwrap();
     for file in files {
         let path = file.unwrap();
+        let filename = path.file_name().to_str().unwrap().to_string();
 
         let content = fs::read_to_string(path.path()).unwrap();
 
         let graph = Graph::from_gr(&content);
-        let mut greedy = Greedy::new_with_graph(graph);
+        let mut greedy = Greedy::new_with_graph(graph.clone());
 
-        let (_, tw) = greedy.solve();
+        let (sequence, tw) = greedy.solve();
 
-        let solution = solutions
-            .get(path.path().file_name().unwrap().to_str().unwrap())
-            .unwrap();
+        let solution = solutions.get(filename.as_str()).unwrap();
 
         //check against pre defined expected results
+        assert_eq!(tw, *solution, "Graph: {}", filename);
+
+        //check against the verifier by replaying the contraction sequence
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+    }
+}
+
+#[test]
+pub fn test_tiny_set_greedy_v2() {
+    for_each_tiny_instance(|filename, graph| {
+        //GreedyV2 has to take exactly the same decisions as Greedy
+        let expected = Greedy::new_with_graph(graph.clone()).solve();
+        let result = GreedyV2::new_with_graph(graph.clone()).solve();
+
+        assert_eq!(result, expected, "Graph: {}", filename);
         assert_eq!(
-            tw,
-            *solution,
+            verify(&graph, &result.0),
+            Ok(result.1),
             "Graph: {}",
-            path.path().file_name().unwrap().to_str().unwrap()
+            filename
         );
+    });
+}
+
+#[test]
+pub fn test_tiny_set_exact() {
+    let solutions: HashMap<&str, usize> = HashMap::from([
+        ("tiny001.gr", 1),
+        ("tiny002.gr", 2),
+        ("tiny003.gr", 0),
+        ("tiny004.gr", 0),
+        ("tiny005.gr", 3),
+        ("tiny006.gr", 0),
+        ("tiny007.gr", 2),
+        ("tiny008.gr", 4),
+        ("tiny009.gr", 1),
+        ("tiny010.gr", 2),
+    ]);
 
-        //check against the verfier provided by the pace challenge
-        write_tww_to_fs(&greedy.output_tww_str());
-        let verifier_result =
-            execute_python_verifier(path.path().file_name().unwrap().to_str().unwrap());
+    for_each_tiny_instance(|filename, graph| {
+        //A node budget keeps the test independent of the speed of the machine. The others are
+        //proven within a few hundred nodes, the tree tiny007 needs about 20000.
+        let budget = Budget {
+            nodes: Some(1_000),
+            time: None,
+        };
+        let mut search = BranchAndBound::new_with_budget(graph.clone(), budget);
+        let (sequence, tw) = search.solve();
+
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+        if filename == "tiny007.gr" {
+            assert!(tw >= solutions[filename], "Graph: {}", filename);
+        } else {
+            assert!(search.is_optimal(), "Graph: {}", filename);
+            assert_eq!(tw, solutions[filename], "Graph: {}", filename);
+        }
+    });
+}
 
-        assert_eq!(tw, verifier_result);
-    }
+#[test]
+pub fn test_tiny_set_sat() {
+    let solutions: HashMap<&str, usize> = HashMap::from([
+        ("tiny001.gr", 1),
+        ("tiny002.gr", 2),
+        ("tiny003.gr", 0),
+        ("tiny004.gr", 0),
+        ("tiny005.gr", 3),
+        ("tiny006.gr", 0),
+        ("tiny007.gr", 2),
+        ("tiny008.gr", 4),
+        ("tiny009.gr", 1),
+        ("tiny010.gr", 2),
+    ]);
+
+    for_each_tiny_instance(|filename, graph| {
+        //The embedded solver needs minutes for the graphs with 25 vertices (tiny005 and tiny007)
+        if graph.get_all_nodes().len() > 20 {
+            return;
+        }
+
+        let mut search: Sat<Cdcl> = Sat::new_with_graph(graph.clone());
+        let (sequence, tw) = search.solve();
+
+        assert!(search.is_optimal(), "Graph: {}", filename);
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+        assert_eq!(tw, solutions[filename], "Graph: {}", filename);
+    });
 }
 
-//This function expects that the right result is placed in result.tww before calling.
-fn execute_python_verifier(filename: &str) -> usize {
-    let mut script = env!("CARGO_MANIFEST_DIR").to_string();
-    script.push_str("/pacechallenge/verifier.py");
+#[test]
+pub fn test_tiny_set_preprocessed() {
+    for_each_tiny_instance(|filename, graph| {
+        let mut algo: Preprocessed<Greedy> = Preprocessed::new_with_graph(graph.clone());
+        let (sequence, tw) = algo.solve();
+
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+    });
+}
+
+#[test]
+pub fn test_tiny_set_classes() {
+    //Only the graphs in a class with an optimal sequence, the others are solved by Greedy
+    let solutions: HashMap<&str, usize> = HashMap::from([
+        ("tiny001.gr", 1),
+        ("tiny002.gr", 2),
+        ("tiny004.gr", 0),
+        ("tiny007.gr", 2),
+    ]);
 
-    let mut graph = env!("CARGO_MANIFEST_DIR").to_string();
-    graph.push_str("/pacechallenge/tiny-set/");
-    graph.push_str(filename);
+    for_each_tiny_instance(|filename, graph| {
+        let mut algo: Classes<Greedy> = Classes::new_with_graph(graph.clone());
+        let (sequence, tw) = algo.solve();
 
-    let mut result = env!("CARGO_MANIFEST_DIR").to_string();
-    result.push_str("/pacechallenge/result.tww");
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+        assert_eq!(
+            algo.is_optimal(),
+            solutions.contains_key(filename),
+            "Graph: {}",
+            filename
+        );
+        if let Some(solution) = solutions.get(filename) {
+            assert_eq!(tw, *solution, "Graph: {}", filename);
+        }
+    });
+}
 
-    let output = Command::new("python")
-        .args([script, graph, result])
-        .output()
-        .unwrap();
+#[test]
+pub fn test_tiny_set_randomized_greedy() {
+    for_each_tiny_instance(|filename, graph| {
+        let config = RandomizedConfig {
+            restarts: Some(5),
+            time: None,
+            ..RandomizedConfig::default()
+        };
+        let mut algo = RandomizedGreedy::new_with_config(graph.clone(), config);
+        let (sequence, tw) = algo.solve();
+
+        assert_eq!(verify(&graph, &sequence), Ok(tw), "Graph: {}", filename);
+    });
+}
 
-    let width = String::from_utf8(output.stdout).unwrap();
-    let width = width.split(":").collect::<Vec<&str>>()[1].trim();
-    let width: usize = width.parse().expect("Unable to parse number");
-    width
+#[test]
+pub fn test_tiny_set_local_search() {
+    for_each_tiny_instance(|filename, graph| {
+        let (sequence, greedy_tw) = Greedy::new_with_graph(graph.clone()).solve();
+
+        let config = LocalSearchConfig {
+            iterations: Some(200),
+            time: None,
+            ..LocalSearchConfig::default()
+        };
+        let mut search = LocalSearch::new(graph.clone(), &sequence, config).unwrap();
+        let (improved, tw) = search.improve();
+
+        assert!(tw <= greedy_tw, "Graph: {}", filename);
+        assert_eq!(verify(&graph, &improved), Ok(tw), "Graph: {}", filename);
+    });
 }
 
-fn write_tww_to_fs(tww: &str) {
-    let mut path = env!("CARGO_MANIFEST_DIR").to_string();
-    path.push_str("/pacechallenge/result.tww");
+/// Calls the test with the file name and the graph of every instance of the tiny set
+fn for_each_tiny_instance(mut test: impl FnMut(&str, Graph)) {
+    let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    d.push("pacechallenge/tiny-set");
 
-    fs::write(path, tww).unwrap();
+    for file in fs::read_dir(d).unwrap() {
+        let path = file.unwrap();
+        let filename = path.file_name().to_str().unwrap().to_string();
+
+        let graph = Graph::from_gr(&fs::read_to_string(path.path()).unwrap());
+        test(&filename, graph);
+    }
 }